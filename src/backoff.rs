@@ -0,0 +1,239 @@
+//! Retry policies whose delays are measurements of time, so the base
+//! delay, the cap and the total budget of the retries can be given in
+//! any unit, e.g a base of 100 ms capped to 30 s with a budget of
+//! 5 min.
+//!
+//! ```
+//! use strong_units::{
+//!     backoff::Backoff,
+//!     units::{Millisecond, Second},
+//!     Measurement,
+//! };
+//!
+//! let mut backoff = Backoff::exponential(Measurement::<Millisecond>::new(1000.0))
+//!     .cap(Measurement::<Second>::new(3.0))
+//!     .budget(Measurement::<Second>::new(10.0));
+//!
+//! let delays: Vec<f64> = backoff.by_ref().map(|delay| delay.value()).collect();
+//! assert_eq!(vec![1.0, 2.0, 3.0, 3.0, 1.0], delays);
+//! assert_eq!(5, backoff.attempts());
+//! ```
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use crate::{units::Second, FromUnit, MeasureUnit, Measurement};
+
+/// Randomization applied to the delays of a [`Backoff`], so that many
+/// clients failing at once don't retry in lockstep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Jitter {
+    /// Delays are not randomized.
+    #[default]
+    None,
+    /// Delays are random between zero and the computed delay.
+    Full,
+    /// Delays are random between half the computed delay and the
+    /// computed delay.
+    Equal,
+}
+
+/// Policy for spacing out the retries of a failing operation. Each call
+/// to [`next_delay`](Self::next_delay) returns the time to wait before
+/// the next attempt, until the budget of the retries is spent. Also
+/// iterable, yielding the same delays.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: f64,
+    factor: f64,
+    cap: f64,
+    budget: f64,
+    jitter: Jitter,
+    seed: u64,
+    attempts: u32,
+    spent: f64,
+}
+
+impl Backoff {
+    fn new(base: f64, factor: f64) -> Self {
+        Self {
+            base,
+            factor,
+            cap: f64::INFINITY,
+            budget: f64::INFINITY,
+            jitter: Jitter::None,
+            seed: RandomState::new().build_hasher().finish(),
+            attempts: 0,
+            spent: 0.0,
+        }
+    }
+
+    /// Creates a policy that always waits the given delay.
+    pub fn fixed<T: MeasureUnit>(delay: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        Self::new(delay.into_unit::<Second>().value(), 1.0)
+    }
+
+    /// Creates a policy that waits the given delay first, and doubles
+    /// it on each attempt.
+    pub fn exponential<T: MeasureUnit>(base: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        Self::new(base.into_unit::<Second>().value(), 2.0)
+    }
+
+    /// Sets the factor the delay is multiplied by on each attempt.
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Sets the longest delay, before applying any jitter.
+    pub fn cap<T: MeasureUnit>(mut self, cap: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        self.cap = cap.into_unit::<Second>().value();
+        self
+    }
+
+    /// Sets the total time that can be spent waiting between attempts.
+    /// The last delay is shortened to fit in it.
+    pub fn budget<T: MeasureUnit>(mut self, budget: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        self.budget = budget.into_unit::<Second>().value();
+        self
+    }
+
+    /// Sets the randomization applied to the delays.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the seed of the randomization, instead of a random one, so
+    /// that the delays are reproducible.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns a random number between 0 and 1 (SplitMix64).
+    fn random(&mut self) -> f64 {
+        self.seed = self.seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns the time to wait before the next attempt, or None if the
+    /// budget is already spent.
+    pub fn next_delay(&mut self) -> Option<Measurement<Second>> {
+        let remaining = self.budget - self.spent;
+        if remaining <= 0.0 {
+            return None;
+        }
+
+        let delay = (self.base * self.factor.powi(self.attempts as i32)).min(self.cap);
+        let delay = match self.jitter {
+            Jitter::None => delay,
+            Jitter::Full => delay * self.random(),
+            Jitter::Equal => delay * (0.5 + self.random() / 2.0),
+        };
+        let delay = delay.max(0.0).min(remaining);
+
+        self.attempts = self.attempts.saturating_add(1);
+        self.spent += delay;
+        Some(Measurement::new(delay))
+    }
+
+    /// Returns the number of delays returned so far.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns the sum of the delays returned so far.
+    pub fn spent(&self) -> Measurement<Second> {
+        Measurement::new(self.spent)
+    }
+
+    /// Starts over, e.g after the operation succeeded.
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+        self.spent = 0.0;
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Measurement<Second>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_delay()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Backoff, Jitter};
+    use crate::{
+        units::{Millisecond, Minute, Second},
+        Measurement,
+    };
+
+    #[test]
+    fn test_fixed() {
+        let mut backoff =
+            Backoff::fixed(Measurement::<Second>::new(2.0)).budget(Measurement::<Second>::new(5.0));
+
+        let delays: Vec<f64> = backoff.by_ref().map(|delay| delay.value()).collect();
+        assert_eq!(vec![2.0, 2.0, 1.0], delays);
+        assert_eq!(Measurement::<Second>::new(5.0), backoff.spent());
+
+        backoff.reset();
+        assert_eq!(Some(2.0), backoff.next_delay().map(|delay| delay.value()));
+    }
+
+    #[test]
+    fn test_exponential() {
+        let mut backoff = Backoff::exponential(Measurement::<Second>::new(1.0))
+            .factor(3.0)
+            .cap(Measurement::<Minute>::new(1.0));
+
+        let delays: Vec<f64> = backoff
+            .by_ref()
+            .take(6)
+            .map(|delay| delay.value())
+            .collect();
+        assert_eq!(vec![1.0, 3.0, 9.0, 27.0, 60.0, 60.0], delays);
+        assert_eq!(6, backoff.attempts());
+    }
+
+    #[test]
+    fn test_jitter() {
+        let backoff = Backoff::exponential(Measurement::<Millisecond>::new(100.0))
+            .cap(Measurement::<Second>::new(1.0))
+            .seed(42);
+
+        let full: Vec<_> = backoff.clone().jitter(Jitter::Full).take(20).collect();
+        let equal: Vec<_> = backoff.clone().jitter(Jitter::Equal).take(20).collect();
+        let plain: Vec<_> = backoff.clone().take(20).collect();
+
+        for ((full, equal), plain) in full.iter().zip(&equal).zip(&plain) {
+            assert!((0.0..=plain.value()).contains(&full.value()));
+            assert!((plain.value() / 2.0..=plain.value()).contains(&equal.value()));
+        }
+
+        let again: Vec<_> = backoff.jitter(Jitter::Full).take(20).collect();
+        assert_eq!(full, again);
+    }
+}
@@ -0,0 +1,147 @@
+//! Quick timing of closures, reporting the elapsed time and the
+//! throughput as typed measurements. Meant for rough numbers during
+//! development, not as a replacement of a full benchmarking harness.
+//!
+//! ```
+//! use strong_units::{bench::MeasuredTimer, units::Millisecond};
+//!
+//! let timing = MeasuredTimer::new()
+//!     .warmup(10)
+//!     .iterations(1000)
+//!     .run(|| (0..100u64).sum::<u64>());
+//!
+//! assert_eq!(1000, timing.iterations());
+//! println!(
+//!     "{:.3} per call, {:.0}",
+//!     timing.per_iteration().into_unit::<Millisecond>(),
+//!     timing.ops_per_second()
+//! );
+//! ```
+
+use std::{hint::black_box, time::Instant};
+
+use crate::{
+    units::{Nanosecond, OpsPerSecond, Second},
+    Measurement,
+};
+
+/// Runs a closure a number of times, after some warmup runs that are
+/// not measured. By default the closure runs once, without warmup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeasuredTimer {
+    warmup: u32,
+    iterations: u32,
+}
+
+impl MeasuredTimer {
+    /// Creates a timer that runs the closure once, without warmup.
+    pub const fn new() -> Self {
+        Self {
+            warmup: 0,
+            iterations: 1,
+        }
+    }
+
+    /// Sets the number of runs done before starting to measure.
+    pub const fn warmup(mut self, runs: u32) -> Self {
+        self.warmup = runs;
+        self
+    }
+
+    /// Sets the number of measured runs. At least one run is always
+    /// measured.
+    pub const fn iterations(mut self, runs: u32) -> Self {
+        self.iterations = if runs > 0 { runs } else { 1 };
+        self
+    }
+
+    /// Runs the given closure, first the warmup runs and then the
+    /// measured ones, and returns the time they took. The results of
+    /// the closure are discarded, without letting the compiler
+    /// optimize the runs away.
+    pub fn run<R>(&self, mut f: impl FnMut() -> R) -> Timing {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+
+        let start = Instant::now();
+        for _ in 0..self.iterations {
+            black_box(f());
+        }
+
+        Timing {
+            total: Measurement::new(start.elapsed().as_nanos() as f64),
+            iterations: self.iterations,
+        }
+    }
+}
+
+impl Default for MeasuredTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Time taken by the measured runs of a [`MeasuredTimer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    total: Measurement<Nanosecond>,
+    iterations: u32,
+}
+
+impl Timing {
+    /// Returns the total time taken by all the measured runs.
+    pub fn total(&self) -> Measurement<Nanosecond> {
+        self.total
+    }
+
+    /// Returns the number of measured runs.
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Returns the average time taken by each run.
+    pub fn per_iteration(&self) -> Measurement<Nanosecond> {
+        self.total / self.iterations as f64
+    }
+
+    /// Returns the number of runs per second.
+    pub fn ops_per_second(&self) -> Measurement<OpsPerSecond> {
+        Measurement::new(self.iterations as f64 / self.total.into_unit::<Second>().value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::MeasuredTimer;
+    use crate::units::{Millisecond, Second};
+
+    #[test]
+    fn test_measured_timer() {
+        let mut calls = 0;
+        let timing = MeasuredTimer::new().warmup(2).iterations(3).run(|| {
+            calls += 1;
+            thread::sleep(Duration::from_millis(2));
+        });
+
+        assert_eq!(5, calls);
+        assert_eq!(3, timing.iterations());
+        assert!(timing.total().into_unit::<Millisecond>().value() >= 6.0);
+        assert!(timing.per_iteration().into_unit::<Millisecond>().value() >= 2.0);
+        assert!(timing.ops_per_second().value() <= 500.0);
+
+        let per_iteration = timing.per_iteration().into_unit::<Second>().value();
+        assert!((timing.ops_per_second().value() * per_iteration - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_at_least_one_iteration() {
+        let mut calls = 0;
+        let timing = MeasuredTimer::new().iterations(0).run(|| calls += 1);
+
+        assert_eq!(1, calls);
+        assert_eq!(1, timing.iterations());
+    }
+}
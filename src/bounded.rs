@@ -0,0 +1,136 @@
+//! Measurements constrained to a range of valid values, defined through
+//! [`define_bounded_measurement!`](crate::define_bounded_measurement),
+//! so the constraints of a domain live next to the unit instead of
+//! being checked all over the code.
+//!
+//! ```
+//! # #[cfg(feature = "data")] {
+//! use strong_units::{
+//!     define_bounded_measurement,
+//!     units::{Byte, Kibibyte, Mebibyte},
+//!     Measurement,
+//! };
+//!
+//! define_bounded_measurement!(
+//!     /// Size of the chunks files are split in.
+//!     ChunkSize: Byte in 4 Kibibyte ..= 16 Mebibyte
+//! );
+//!
+//! let size = ChunkSize::new(Measurement::<Kibibyte>::new(64.0)).unwrap();
+//! assert_eq!(65_536.0, size.get().value());
+//!
+//! let error = "1 KiB".parse::<ChunkSize>().unwrap_err();
+//! assert_eq!("1024 B is out of the range 4 KiB..=16 MiB", error.to_string());
+//! # }
+//! ```
+//!
+//! Bounded measurements implement `FromStr`, so they can be parsed by
+//! command line parsers like clap, and with the `serde` feature they
+//! can be serialized and deserialized, checking the range.
+
+use std::{error::Error, fmt::Display};
+
+use crate::parse::ParseError;
+
+/// Error returned when a bounded measurement cannot be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundedError {
+    /// The input is not a valid measurement.
+    Parse(ParseError),
+    /// The measurement is out of the valid range.
+    OutOfBounds {
+        value: String,
+        min: String,
+        max: String,
+    },
+}
+
+impl Display for BoundedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoundedError::Parse(error) => error.fmt(f),
+            BoundedError::OutOfBounds { value, min, max } => {
+                write!(f, "{} is out of the range {}..={}", value, min, max)
+            }
+        }
+    }
+}
+
+impl Error for BoundedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BoundedError::Parse(error) => Some(error),
+            BoundedError::OutOfBounds { .. } => None,
+        }
+    }
+}
+
+impl From<ParseError> for BoundedError {
+    fn from(error: ParseError) -> Self {
+        BoundedError::Parse(error)
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use crate::{
+        bounded::BoundedError,
+        define_bounded_measurement,
+        parse::ParseError,
+        units::{Hour, Millisecond, Minute, Second},
+        Measurement,
+    };
+
+    define_bounded_measurement!(Timeout: Second in 100 Millisecond ..= 1.5 Hour);
+
+    #[test]
+    fn test_bounded_measurement() {
+        assert_eq!(0.1, Timeout::min().value());
+        assert_eq!(5400.0, Timeout::max().value());
+
+        let timeout = Timeout::new(Measurement::<Minute>::new(2.0)).unwrap();
+        assert_eq!(120.0, timeout.get().value());
+        assert_eq!("120 s", timeout.to_string());
+        assert_eq!(Ok(timeout), "2 min".parse());
+        assert_eq!(
+            Ok(timeout),
+            Timeout::try_from(Measurement::<Second>::new(120.0))
+        );
+
+        assert!(Timeout::new(Measurement::<Hour>::new(1.5)).is_ok());
+        assert_eq!(
+            Err(BoundedError::OutOfBounds {
+                value: "2 h".to_owned(),
+                min: "100 ms".to_owned(),
+                max: "1.5 h".to_owned(),
+            }),
+            Timeout::new(Measurement::<Hour>::new(2.0))
+        );
+        assert!(Timeout::new(Measurement::<Second>::new(f64::NAN)).is_err());
+        assert_eq!(
+            Err(BoundedError::Parse(ParseError::UnknownUnit("x".to_owned()))),
+            "3 x".parse::<Timeout>()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bounded_serde() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            timeout: Timeout,
+        }
+
+        let config: Config = toml::from_str(r#"timeout = "30 s""#).unwrap();
+        assert_eq!(30.0, config.timeout.get().value());
+        assert_eq!(
+            config,
+            toml::from_str(&toml::to_string(&config).unwrap()).unwrap()
+        );
+
+        let error = toml::from_str::<Config>(r#"timeout = "3 h""#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("10800 s is out of the range 100 ms..=1.5 h"));
+    }
+}
@@ -0,0 +1,81 @@
+//! Capacity planning for storage that fills up over time, like disks
+//! or quotas.
+
+use crate::{
+    units::{Bit, Second},
+    DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+/// Returns how long it takes for storage of the given capacity, of
+/// which the given amount is already used, to fill up if usage keeps
+/// growing at the given rate, e.g in bytes per day. Returns zero if it
+/// is already full, and None if usage is not growing, or any of the
+/// inputs is not a finite number.
+pub fn time_to_full<C, U, R>(
+    capacity: Measurement<C>,
+    used: Measurement<U>,
+    growth: Measurement<R>,
+) -> Option<Measurement<Second>>
+where
+    C: MeasureUnit,
+    U: MeasureUnit,
+    R: MeasureUnit,
+    Bit: FromUnit<C> + FromUnit<U>,
+    DivUnit<Bit, Second>: FromUnit<R>,
+{
+    let capacity = capacity.into_unit::<Bit>().value();
+    let used = used.into_unit::<Bit>().value();
+    let growth = growth.into_unit::<DivUnit<Bit, Second>>().value();
+
+    if !(capacity.is_finite() && used.is_finite() && growth.is_finite()) {
+        return None;
+    }
+
+    if used >= capacity {
+        return Some(Measurement::new(0.0));
+    }
+
+    (growth > 0.0).then(|| Measurement::new((capacity - used) / growth))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::time_to_full;
+    use crate::{
+        units::{Byte, Day, Gigabyte, Hour, Megabyte, Terabyte},
+        DivUnit, Measurement,
+    };
+
+    #[test]
+    fn test_time_to_full() {
+        let capacity = Measurement::<Terabyte>::new(2.0);
+        let growth = Measurement::<DivUnit<Gigabyte, Day>>::new(25.0);
+
+        let left = time_to_full(capacity, Measurement::<Gigabyte>::new(1500.0), growth).unwrap();
+        assert_eq!(20.0, left.into_unit::<Day>().value());
+
+        let growth = Measurement::<DivUnit<Megabyte, Hour>>::new(100.0);
+        let left = time_to_full(
+            Measurement::<Byte>::new(1e9),
+            Measurement::<Byte>::new(0.0),
+            growth,
+        );
+        assert_eq!(
+            Some(10.0),
+            left.map(|left| left.into_unit::<Hour>().value())
+        );
+    }
+
+    #[test]
+    fn test_never_or_already_full() {
+        let capacity = Measurement::<Gigabyte>::new(100.0);
+        let shrinking = Measurement::<DivUnit<Gigabyte, Day>>::new(-1.0);
+
+        assert!(time_to_full(capacity, Measurement::<Gigabyte>::new(50.0), shrinking).is_none());
+        assert_eq!(
+            Some(0.0),
+            time_to_full(capacity, Measurement::<Gigabyte>::new(120.0), shrinking)
+                .map(Measurement::value)
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! Probes for checking the code generated for arithmetic between
+//! measurements of different units. They are only compiled with
+//! `--cfg codegen`, which the test below passes when emitting the
+//! assembly of the crate, to assert that a cross-unit add is compiled
+//! down to one multiplication by the conversion factor and one addition,
+//! with no calls nor divisions left.
+
+#[cfg(codegen)]
+use crate::{
+    units::{Hour, Kbps, Megabit, Second},
+    DivUnit, Measurement,
+};
+
+#[cfg(codegen)]
+#[no_mangle]
+pub fn codegen_add_hours_seconds(hours: f64, seconds: f64) -> f64 {
+    (Measurement::<Hour>::new(hours) + Measurement::<Second>::new(seconds)).value()
+}
+
+#[cfg(codegen)]
+#[no_mangle]
+pub fn codegen_add_rates(megabits_per_hour: f64, kbps: f64) -> f64 {
+    (Measurement::<DivUnit<Megabit, Hour>>::new(megabits_per_hour) + Measurement::<Kbps>::new(kbps))
+        .value()
+}
+
+#[cfg(all(test, target_arch = "x86_64"))]
+mod tests {
+    use std::{fs, path::Path, process::Command};
+
+    /// Builds the crate in release mode along with the probes, and
+    /// returns the assembly generated for it.
+    fn emit_assembly() -> String {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let target_dir = manifest_dir.join("target").join("codegen");
+        let output = target_dir.join("strong_units.s");
+
+        let build = Command::new(env!("CARGO"))
+            .current_dir(manifest_dir)
+            .args(["rustc", "--release", "--lib", "--no-default-features"])
+            .args(["--features", "rate", "--target-dir"])
+            .arg(&target_dir)
+            .args(["--", "--cfg", "codegen", "-C", "codegen-units=1", "--emit"])
+            .arg(format!("asm={}", output.display()))
+            .output()
+            .expect("cargo could not be run");
+        assert!(
+            build.status.success(),
+            "the assembly could not be emitted: {}",
+            String::from_utf8_lossy(&build.stderr)
+        );
+
+        fs::read_to_string(output).unwrap()
+    }
+
+    /// Returns the instructions of the given function in the assembly.
+    fn instructions<'a>(assembly: &'a str, function: &str) -> Vec<&'a str> {
+        let label = format!("{}:", function);
+        assembly
+            .lines()
+            .skip_while(|line| line.trim() != label)
+            .skip(1)
+            .map(str::trim)
+            .take_while(|line| !line.starts_with(".Lfunc_end"))
+            .filter(|line| !line.is_empty() && !line.starts_with(['.', '#']))
+            .collect()
+    }
+
+    #[test]
+    fn test_cross_unit_add_codegen() {
+        let assembly = emit_assembly();
+
+        for function in ["codegen_add_hours_seconds", "codegen_add_rates"] {
+            let instructions = instructions(&assembly, function);
+            let count = |mnemonic: &str| {
+                instructions
+                    .iter()
+                    .filter(|instruction| instruction.starts_with(mnemonic))
+                    .count()
+            };
+
+            assert!(!instructions.is_empty(), "{} not found", function);
+            assert_eq!(1, count("mulsd"), "{}: {:?}", function, instructions);
+            assert_eq!(1, count("addsd"), "{}: {:?}", function, instructions);
+            assert_eq!(0, count("divsd"), "{}: {:?}", function, instructions);
+            assert_eq!(0, count("call"), "{}: {:?}", function, instructions);
+        }
+    }
+}
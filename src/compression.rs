@@ -0,0 +1,132 @@
+//! Ratios between the sizes of data before and after compressing it.
+
+use std::fmt::Display;
+
+use crate::{units::Bit, FromUnit, MeasureUnit, Measurement};
+
+/// Ratio between the original size of some data and its size once
+/// compressed, e.g 2.5 when 10 MB compress into 4 MB. Displayed as
+/// "2.5:1".
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CompressionRatio(f64);
+
+impl CompressionRatio {
+    /// Creates a compression ratio from its value, i.e the original
+    /// size divided by the compressed one.
+    pub fn new(ratio: f64) -> Self {
+        Self(ratio)
+    }
+
+    /// Returns the compression ratio of data of the given original
+    /// size that compresses into the given size. Returns None if any
+    /// of the sizes is not a positive number.
+    pub fn of<O, C>(original: Measurement<O>, compressed: Measurement<C>) -> Option<Self>
+    where
+        O: MeasureUnit,
+        C: MeasureUnit,
+        Bit: FromUnit<O> + FromUnit<C>,
+    {
+        let original = original.into_unit::<Bit>().value();
+        let compressed = compressed.into_unit::<Bit>().value();
+        let valid = |x: f64| x.is_finite() && x > 0.0;
+
+        (valid(original) && valid(compressed)).then(|| Self(original / compressed))
+    }
+
+    /// Returns the value of the ratio.
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the fraction of the original size saved by compressing,
+    /// e.g 0.6 for a ratio of 2.5:1. It is negative if the compressed
+    /// data is larger than the original.
+    pub fn space_savings(self) -> f64 {
+        1.0 - 1.0 / self.0
+    }
+
+    /// Returns an object that displays the space savings of this
+    /// ratio as a percentage, e.g "60% saved".
+    pub fn savings(self) -> SpaceSavings {
+        SpaceSavings(self.space_savings())
+    }
+
+    /// Estimates the size of data of the given original size once
+    /// compressed with this ratio.
+    pub fn compressed_size<U>(self, original: Measurement<U>) -> Measurement<U> {
+        original / self.0
+    }
+
+    /// Estimates the original size of data that compressed into the
+    /// given size with this ratio.
+    pub fn original_size<U>(self, compressed: Measurement<U>) -> Measurement<U> {
+        compressed * self.0
+    }
+}
+
+impl Display for CompressionRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <f64 as Display>::fmt(&self.0, f)?;
+        f.write_str(":1")
+    }
+}
+
+/// Displays the space savings of a [`CompressionRatio`] as a
+/// percentage, e.g "60% saved". Created through
+/// [`CompressionRatio::savings`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpaceSavings(f64);
+
+impl Display for SpaceSavings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <f64 as Display>::fmt(&(self.0 * 100.0), f)?;
+        f.write_str("% saved")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompressionRatio;
+    use crate::{
+        units::{Gibibyte, Mebibyte, Megabyte},
+        Measurement,
+    };
+
+    #[test]
+    fn test_ratio() {
+        let ratio = CompressionRatio::of(
+            Measurement::<Megabyte>::new(10.0),
+            Measurement::<Megabyte>::new(4.0),
+        )
+        .unwrap();
+
+        assert_eq!(2.5, ratio.value());
+        assert_eq!("2.5:1", ratio.to_string());
+        assert_eq!("60% saved", ratio.savings().to_string());
+        assert_eq!("60.0% saved", format!("{:.1}", ratio.savings()));
+        assert!(CompressionRatio::of(
+            Measurement::<Megabyte>::new(10.0),
+            Measurement::<Megabyte>::new(0.0)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_estimates() {
+        let ratio = CompressionRatio::new(4.0);
+
+        assert_eq!(
+            256.0,
+            ratio
+                .compressed_size(Measurement::<Gibibyte>::new(1.0))
+                .into_unit::<Mebibyte>()
+                .value()
+        );
+        assert_eq!(
+            4.0,
+            ratio
+                .original_size(Measurement::<Gibibyte>::new(1.0))
+                .value()
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Deserialization of measurements written with their unit in
+//! configuration files, e.g `max_upload = "10 MiB/s"`, through serde's
+//! `deserialize_with` attribute:
+//!
+//! ```
+//! # #[cfg(feature = "rate")] {
+//! use serde::Deserialize;
+//! use strong_units::{
+//!     units::{Kbps, Second},
+//!     Measurement,
+//! };
+//!
+//! #[derive(Deserialize)]
+//! struct Limits {
+//!     #[serde(deserialize_with = "strong_units::config::deserialize")]
+//!     max_upload: Measurement<Kbps>,
+//!     #[serde(default, deserialize_with = "strong_units::config::option::deserialize")]
+//!     timeout: Option<Measurement<Second>>,
+//! }
+//!
+//! let limits: Limits = toml::from_str(r#"max_upload = "10 Mbps""#).unwrap();
+//! assert_eq!(10_000.0, limits.max_upload.value());
+//! # }
+//! ```
+//!
+//! Values may be expressed in any unit of the expected quantity, and
+//! are converted into the unit of the field. Plain numbers are taken as
+//! expressed in the unit of the field. Values of other quantities are
+//! rejected, with an error that the format reports along with the
+//! location of the field.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, Visitor},
+    Deserializer,
+};
+
+use crate::{dynamic::dyn_unit, parse::parse_as, BaseConversion, MeasureUnit, Measurement};
+
+struct MeasurementVisitor<U>(PhantomData<U>);
+
+impl<U: MeasureUnit + 'static> Visitor<'_> for MeasurementVisitor<U>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    type Value = Measurement<U>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a measurement of {}, like \"10 {}\"",
+            dyn_unit::<U>().quantity(),
+            U::symbol()
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse_as(value)
+            .map_err(|e| E::custom(format_args!("invalid measurement {:?}: {}", value, e)))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Measurement::new(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Measurement::new(value as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Measurement::new(value as f64))
+    }
+}
+
+/// Deserializes a measurement written either as a string with its unit,
+/// or as a plain number expressed in U.
+pub fn deserialize<'de, D, U>(deserializer: D) -> Result<Measurement<U>, D::Error>
+where
+    D: Deserializer<'de>,
+    U: MeasureUnit + 'static,
+    U::AliasedUnit: BaseConversion,
+{
+    deserializer.deserialize_any(MeasurementVisitor(PhantomData))
+}
+
+/// Same as [`deserialize`](super::config::deserialize), but for
+/// optional fields.
+pub mod option {
+    use std::{fmt, marker::PhantomData};
+
+    use serde::{de::Visitor, Deserializer};
+
+    use crate::{BaseConversion, MeasureUnit, Measurement};
+
+    struct OptionVisitor<U>(PhantomData<U>);
+
+    impl<'de, U: MeasureUnit + 'static> Visitor<'de> for OptionVisitor<U>
+    where
+        U::AliasedUnit: BaseConversion,
+    {
+        type Value = Option<Measurement<U>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an optional measurement")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            super::deserialize(deserializer).map(Some)
+        }
+    }
+
+    /// Deserializes an optional measurement, written either as a string
+    /// with its unit, or as a plain number expressed in U.
+    pub fn deserialize<'de, D, U>(deserializer: D) -> Result<Option<Measurement<U>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        U: MeasureUnit + 'static,
+        U::AliasedUnit: BaseConversion,
+    {
+        deserializer.deserialize_option(OptionVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    #[cfg(feature = "rate")]
+    use crate::units::{Kbps, Megabyte, Second};
+    use crate::Measurement;
+
+    crate::define_unit!(Step, "step");
+    crate::define_unit!(Stride, "stride");
+
+    crate::define_linear_conversions! {
+        quantity: "pace";
+
+        (Step, 1),
+        (Stride, 2 Step)
+    }
+
+    #[derive(Deserialize)]
+    struct Walk {
+        #[serde(deserialize_with = "super::deserialize")]
+        length: Measurement<Stride>,
+        #[serde(default, deserialize_with = "super::option::deserialize")]
+        rest: Option<Measurement<Step>>,
+    }
+
+    #[test]
+    fn test_deserialize_numbers() {
+        let walk: Walk = toml::from_str("length = 25\nrest = 4.5").unwrap();
+        assert_eq!(25.0, walk.length.value());
+        assert_eq!(Some(4.5), walk.rest.map(Measurement::value));
+
+        let walk: Walk = toml::from_str("length = 1.5").unwrap();
+        assert!(walk.rest.is_none());
+
+        let error = |input: &str| toml::from_str::<Walk>(input).err().unwrap().to_string();
+        assert!(error("length = true").contains("a measurement of pace, like \"10 stride\""));
+        assert!(error("length = \"3 parsecs\"").contains("unknown unit: \"parsecs\""));
+    }
+
+    #[cfg(feature = "rate")]
+    #[derive(Deserialize)]
+    struct Limits {
+        #[serde(deserialize_with = "super::deserialize")]
+        max_upload: Measurement<Kbps>,
+        #[serde(deserialize_with = "super::deserialize")]
+        max_size: Measurement<Megabyte>,
+        #[serde(default, deserialize_with = "super::option::deserialize")]
+        timeout: Option<Measurement<Second>>,
+    }
+
+    #[cfg(feature = "rate")]
+    #[test]
+    fn test_deserialize() {
+        let limits: Limits = toml::from_str(
+            r#"
+            max_upload = "10 Mbps"
+            max_size = 250
+            timeout = "2 min"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(10_000.0, limits.max_upload.value());
+        assert_eq!(250.0, limits.max_size.value());
+        assert_eq!(Some(120.0), limits.timeout.map(Measurement::value));
+
+        let limits: Limits = toml::from_str("max_upload = 1\nmax_size = \"1 GB\"").unwrap();
+        assert_eq!(1000.0, limits.max_size.value());
+        assert!(limits.timeout.is_none());
+    }
+
+    #[cfg(feature = "rate")]
+    #[test]
+    fn test_deserialize_errors() {
+        let error = toml::from_str::<Limits>("max_upload = \"10 MiB\"\nmax_size = 1")
+            .err()
+            .unwrap()
+            .to_string();
+
+        assert!(error.contains("max_upload"));
+        assert!(error.contains("expected a measurement of data/time, found data"));
+    }
+}
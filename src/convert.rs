@@ -0,0 +1,120 @@
+//! Conversion of all the measurements of a struct at once, e.g to the
+//! units required at an API boundary.
+//!
+//! With the `derive` feature, [`ConvertUnits`] can be derived for
+//! structs that are generic over the units of their measurements. The
+//! derive also adds an `into_units::<Target>()` method, where the
+//! target is the same struct with other units, usually named through a
+//! type alias that acts as a profile of units:
+//!
+//! ```
+//! # #[cfg(all(feature = "derive", feature = "rate"))] {
+//! use strong_units::{
+//!     convert::ConvertUnits,
+//!     units::{Bps, Byte, Kibibyte, Mbps, Millisecond, Second},
+//!     Measurement,
+//! };
+//!
+//! #[derive(ConvertUnits)]
+//! struct TransferStats<D, T, R> {
+//!     name: String,
+//!     bytes: Measurement<D>,
+//!     elapsed: Measurement<T>,
+//!     rate: Measurement<R>,
+//! }
+//!
+//! /// Units used by the public API.
+//! type ApiStats = TransferStats<Byte, Second, Bps>;
+//!
+//! let stats = TransferStats {
+//!     name: "backup".to_owned(),
+//!     bytes: Measurement::<Kibibyte>::new(2.0),
+//!     elapsed: Measurement::<Millisecond>::new(1500.0),
+//!     rate: Measurement::<Mbps>::new(3.0),
+//! };
+//!
+//! let stats = stats.into_units::<ApiStats>();
+//! assert_eq!(2048.0, stats.bytes.value());
+//! assert_eq!(1.5, stats.elapsed.value());
+//! assert_eq!(3_000_000.0, stats.rate.value());
+//! # }
+//! ```
+
+#[cfg(feature = "derive")]
+pub use strong_units_derive::ConvertUnits;
+
+/// Implemented by the types whose measurements can be converted into
+/// the units of the type T, which usually is the same type with other
+/// units.
+pub trait ConvertUnits<T> {
+    fn convert_units(self) -> T;
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::ConvertUnits;
+    use crate::{MeasureUnit, Measurement};
+
+    crate::define_unit!(Byte, "B");
+    crate::define_unit!(Kilobyte, "KB");
+    crate::define_unit!(Megabyte, "MB");
+    crate::define_unit!(Second, "s");
+    crate::define_unit!(Minute, "min");
+    crate::define_unit!(Hour, "h");
+
+    crate::define_linear_conversions! {
+        quantity: "data";
+
+        (Byte, 1),
+        (Kilobyte, 1000 Byte),
+        (Megabyte, 1000 Kilobyte)
+    }
+
+    crate::define_linear_conversions! {
+        quantity: "time";
+
+        (Second, 1),
+        (Minute, 60 Second),
+        (Hour, 60 Minute)
+    }
+
+    #[derive(ConvertUnits)]
+    struct Sample<'a, D: MeasureUnit, T, const N: usize>(
+        &'a str,
+        Measurement<D>,
+        [u8; N],
+        Measurement<T>,
+    )
+    where
+        T: MeasureUnit;
+
+    #[derive(ConvertUnits)]
+    struct Fixed<T> {
+        size: Measurement<Byte>,
+        elapsed: Measurement<T>,
+    }
+
+    #[test]
+    fn test_derive_convert_units() {
+        let sample = Sample::<Kilobyte, Minute, 2>(
+            "a",
+            Measurement::new(1500.0),
+            [1, 2],
+            Measurement::new(90.0),
+        );
+        let sample: Sample<Megabyte, Hour, 2> = sample.into_units();
+
+        assert_eq!("a", sample.0);
+        assert_eq!(1.5, sample.1.value());
+        assert_eq!([1, 2], sample.2);
+        assert_eq!(1.5, sample.3.value());
+
+        let fixed = Fixed {
+            size: Measurement::new(10.0),
+            elapsed: Measurement::<Minute>::new(2.0),
+        };
+        let fixed = fixed.into_units::<Fixed<Second>>();
+        assert_eq!(10.0, fixed.size.value());
+        assert_eq!(120.0, fixed.elapsed.value());
+    }
+}
@@ -0,0 +1,214 @@
+//! Dimensions of the quantities measured by units, checked at compile
+//! time. Each unit declares its [dimension](crate::MeasureUnit::Dimension),
+//! and conversions are only possible between units of the same one, so
+//! a `FromUnit` implementation between units of different dimensions is
+//! rejected:
+//!
+//! ```compile_fail
+//! use strong_units::{
+//!     dimension::{Data, Time},
+//!     define_unit, FromUnit, Measurement,
+//! };
+//!
+//! define_unit!(Tick, "tick", dimension: Time);
+//! define_unit!(Word, "word", dimension: Data);
+//!
+//! impl FromUnit<Tick> for Word {
+//!     fn from_value(input: Measurement<Tick>) -> Measurement<Self> {
+//!         Measurement::new(input.value())
+//!     }
+//! }
+//! ```
+//!
+//! Dimensions also allow writing generic code over any unit of a given
+//! one, e.g:
+//!
+//! ```
+//! # #[cfg(feature = "time")] {
+//! use strong_units::{
+//!     dimension::Time,
+//!     units::{Millisecond, Minute, Second},
+//!     LinearUnit, MeasureUnit, Measurement,
+//! };
+//!
+//! fn total<U: LinearUnit + MeasureUnit<Dimension = Time>>(
+//!     durations: &[Measurement<U>],
+//! ) -> Measurement<Second> {
+//!     durations
+//!         .iter()
+//!         .fold(Measurement::ZERO, |total, duration| {
+//!             total + duration.rescale::<Second>()
+//!         })
+//! }
+//!
+//! assert_eq!(90.0, total(&[Measurement::<Minute>::new(1.5)]).value());
+//! assert_eq!(1.5, total(&[Measurement::<Millisecond>::new(1500.0)]).value());
+//! # }
+//! ```
+
+use std::{borrow::Cow, marker::PhantomData};
+
+use crate::{type_logic, MeasureUnit};
+
+/// Dimension of a quantity, like time or length. Base dimensions are
+/// defined through [`define_dimension!`](crate::define_dimension), and
+/// composed ones are built from the dimensions of the units composed.
+pub trait Dimension: 'static {
+    /// Identifies the dimension at compile time, so it can be
+    /// [compared](type_logic::Compare) with others, e.g for cancelling
+    /// out the units of measurements of the same dimension when dividing
+    /// them. The base dimensions of this crate are identified by their
+    /// position, composed ones by their parts, and the rest are
+    /// [unidentified](type_logic::Unidentified).
+    type Id;
+
+    /// Returns the name of the dimension, e.g "length".
+    fn name() -> Cow<'static, str>;
+}
+
+/// Implemented by the units with the same dimension as the unit U.
+/// Required by [`FromUnit`](crate::FromUnit), so generic code bound by
+/// a conversion can rely on both units having the same dimension.
+pub trait SameDimension<U: MeasureUnit>: MeasureUnit {}
+
+impl<U: MeasureUnit, V: MeasureUnit<Dimension = U::Dimension>> SameDimension<U> for V {}
+
+crate::define_dimension!(
+    /// Dimension of the units that don't declare any.
+    Unspecified,
+    "unspecified"
+);
+
+/// Defines the base dimensions of this crate, identifying each one by
+/// its position in the list, so they can be compared at compile time.
+macro_rules! base_dimensions {
+    ($position:ty; $id:ident, $name:literal; $($rest:tt)*) => {
+        crate::define_dimension!(@id type_logic::BaseId<$position>; $id, $name);
+        base_dimensions!(type_logic::Succ<$position>; $($rest)*);
+    };
+    ($position:ty;) => {};
+}
+
+base_dimensions! {
+    type_logic::Zero;
+    Time, "time";
+    Data, "data";
+    SymbolRate, "symbol rate";
+    Money, "money";
+    LuminousIntensity, "luminous intensity";
+    LuminousFlux, "luminous flux";
+    Radioactivity, "radioactivity";
+    AbsorbedDose, "absorbed dose";
+    EquivalentDose, "equivalent dose";
+    Mass, "mass";
+    Volume, "volume";
+    AmountOfSubstance, "amount of substance";
+    Samples, "samples";
+    Length, "length";
+    Pixels, "pixels";
+    Force, "force";
+    Cycles, "cycles";
+    Operations, "operations";
+    Requests, "requests";
+    Temperature, "temperature";
+}
+
+/// Dimension of a [`DivUnit`](crate::DivUnit), e.g data/time.
+pub struct Quotient<N, D> {
+    _n: PhantomData<N>,
+    _d: PhantomData<D>,
+}
+
+impl<N: Dimension, D: Dimension> Dimension for Quotient<N, D> {
+    type Id = type_logic::QuotientId<N::Id, D::Id>;
+
+    fn name() -> Cow<'static, str> {
+        [&N::name(), "/", &D::name()].concat().into()
+    }
+}
+
+/// Dimension of illuminance, which is luminous flux per area, so units
+/// of illuminance share it with quotients like lm/m².
+pub type Illuminance = Quotient<LuminousFlux, Power<Length, 2>>;
+
+/// Dimension of a [`MulUnit`](crate::MulUnit), e.g force·length.
+pub struct Product<L, R> {
+    _l: PhantomData<L>,
+    _r: PhantomData<R>,
+}
+
+impl<L: Dimension, R: Dimension> Dimension for Product<L, R> {
+    type Id = type_logic::ProductId<L::Id, R::Id>;
+
+    fn name() -> Cow<'static, str> {
+        [&L::name(), "·", &R::name()].concat().into()
+    }
+}
+
+/// Dimension of a [`ReciprocalUnit`](crate::ReciprocalUnit), e.g
+/// 1/time.
+pub struct Reciprocal<D> {
+    _d: PhantomData<D>,
+}
+
+impl<D: Dimension> Dimension for Reciprocal<D> {
+    type Id = type_logic::ReciprocalId<D::Id>;
+
+    fn name() -> Cow<'static, str> {
+        ["1/", &D::name()].concat().into()
+    }
+}
+
+/// Dimension of a [`PowUnit`](crate::PowUnit), e.g length².
+pub struct Power<D, const N: i32> {
+    _d: PhantomData<D>,
+}
+
+impl<D: Dimension, const N: i32> Dimension for Power<D, N> {
+    // Exponents can't be compared at compile time, so powers are
+    // never taken as the same dimension as another.
+    type Id = type_logic::Unidentified;
+
+    fn name() -> Cow<'static, str> {
+        crate::pow_symbol(&D::name(), N).into()
+    }
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use super::{Data, Dimension, Length, Power, Time};
+    use crate::{
+        units::{Gbps, Gigabit, Gigabyte, Minute, Second},
+        DivUnit, LinearUnit, MeasureUnit, Measurement, PowUnit,
+    };
+
+    fn dimension_name<U: MeasureUnit>() -> String {
+        <U::Dimension as Dimension>::name().into_owned()
+    }
+
+    #[test]
+    fn test_dimensions() {
+        assert_eq!("time", dimension_name::<Minute>());
+        assert_eq!("data/time", dimension_name::<Gbps>());
+        assert_eq!("time²", dimension_name::<PowUnit<Minute, 2>>());
+        assert_eq!("length³", Power::<Length, 3>::name());
+        assert_eq!("data", Data::name());
+    }
+
+    #[test]
+    fn test_rescale() {
+        fn in_seconds<U: LinearUnit + MeasureUnit<Dimension = Time>>(
+            duration: Measurement<U>,
+        ) -> f64 {
+            duration.rescale::<Second>().value()
+        }
+
+        assert_eq!(90.0, in_seconds(Measurement::<Minute>::new(1.5)));
+        assert_eq!(
+            0.125,
+            Measurement::<DivUnit<Gigabit, Second>>::new(1.0)
+                .rescale::<DivUnit<Gigabyte, Second>>()
+                .value()
+        );
+    }
+}
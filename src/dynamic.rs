@@ -0,0 +1,536 @@
+//! Runtime representation of units, for the cases where the unit of a
+//! measurement is not known at compile time (plugin systems, runtime
+//! registries, heterogeneous collections...).
+
+use std::{
+    any::Any,
+    borrow::Cow,
+    cmp::Ordering,
+    error::Error,
+    fmt::{Debug, Display},
+    marker::PhantomData,
+    ops::{Add, Sub},
+};
+
+use crate::{
+    format, parse,
+    symbols::{self, SymbolStyle},
+    BaseConversion, MeasureUnit, Measurement,
+};
+
+/// Object-safe counterpart of [`MeasureUnit`], which allows handling
+/// units through `Box<dyn DynUnit>` or `&dyn DynUnit`.
+pub trait DynUnit: Any + Send + Sync {
+    /// Returns the symbol of the unit.
+    fn symbol(&self) -> Cow<'static, str>;
+
+    /// Returns the ASCII fallback of the symbol of the unit. By
+    /// default, it is transliterated from the symbol.
+    fn ascii_symbol(&self) -> Cow<'static, str> {
+        let symbol = self.symbol();
+        match symbols::to_ascii(&symbol) {
+            Cow::Borrowed(_) => symbol,
+            Cow::Owned(ascii) => ascii.into(),
+        }
+    }
+
+    /// Returns the long name of the unit in singular, e.g "hour". By
+    /// default, it is the symbol of the unit.
+    fn name(&self) -> Cow<'static, str> {
+        self.symbol()
+    }
+
+    /// Returns the long name of the unit in plural, e.g "hours". By
+    /// default, it is the name followed by an "s", unless the unit has
+    /// no name other than its symbol.
+    fn plural_name(&self) -> Cow<'static, str> {
+        let name = self.name();
+        if name == self.symbol() {
+            name
+        } else {
+            [&name, "s"].concat().into()
+        }
+    }
+
+    /// Returns the number of decimal digits values of the unit are
+    /// displayed with when no precision is given. By default, there is
+    /// none.
+    fn default_precision(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the symbol of the unit in the given style.
+    fn symbol_in(&self, style: SymbolStyle) -> Cow<'static, str> {
+        match style {
+            SymbolStyle::Unicode => self.symbol(),
+            SymbolStyle::Ascii => self.ascii_symbol(),
+        }
+    }
+
+    /// Returns the name of the quantity measured by the unit.
+    fn quantity(&self) -> Cow<'static, str>;
+
+    /// Converts a value expressed in this unit into the base unit of
+    /// its quantity.
+    fn value_to_base(&self, value: f64) -> f64;
+
+    /// Converts a value expressed in the base unit of the quantity
+    /// into this unit.
+    fn value_from_base(&self, value: f64) -> f64;
+
+    /// Returns whether values of the unit are converted into the base
+    /// unit by a factor alone, which is the case of all units but the
+    /// ones related to it by an offset, like degrees Celsius. Only
+    /// linear units can be composed into quotients, or converted
+    /// through a [factor](crate::registry::conversion_factor).
+    fn is_linear(&self) -> bool {
+        self.value_to_base(0.0) == 0.0
+    }
+}
+
+impl Debug for dyn DynUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynUnit")
+            .field("symbol", &self.symbol())
+            .field("quantity", &self.quantity())
+            .finish()
+    }
+}
+
+/// Zero-sized handle that implements [`DynUnit`] for the unit U,
+/// resolving aliases into the unit they represent.
+pub struct StaticUnit<U> {
+    _marker: PhantomData<fn() -> U>,
+}
+
+impl<U> StaticUnit<U> {
+    /// Creates a new handle of the unit U.
+    pub const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<U> Default for StaticUnit<U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U> Copy for StaticUnit<U> {}
+
+impl<U> Clone for StaticUnit<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: MeasureUnit + 'static> DynUnit for StaticUnit<U>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    fn symbol(&self) -> Cow<'static, str> {
+        U::symbol()
+    }
+
+    fn ascii_symbol(&self) -> Cow<'static, str> {
+        U::ascii_symbol()
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        U::name()
+    }
+
+    fn plural_name(&self) -> Cow<'static, str> {
+        U::plural_name()
+    }
+
+    fn default_precision(&self) -> Option<usize> {
+        U::default_precision()
+    }
+
+    fn quantity(&self) -> Cow<'static, str> {
+        U::AliasedUnit::quantity_name()
+    }
+
+    fn value_to_base(&self, value: f64) -> f64 {
+        U::AliasedUnit::to_base(value)
+    }
+
+    fn value_from_base(&self, value: f64) -> f64 {
+        U::AliasedUnit::from_base(value)
+    }
+}
+
+/// Returns a static [`DynUnit`] handle of the unit U.
+pub fn dyn_unit<U: MeasureUnit + 'static>() -> &'static dyn DynUnit
+where
+    U::AliasedUnit: BaseConversion,
+{
+    &StaticUnit::<U> {
+        _marker: PhantomData,
+    }
+}
+
+/// Measurement whose unit is only known at runtime. It allows storing
+/// measurements of different units in the same collection, and
+/// recovering the statically typed measurement afterwards through
+/// [`AnyMeasurement::downcast`], or converted into any unit of the
+/// same quantity through `TryFrom`.
+#[doc(alias = "DynMeasurement")]
+#[derive(Clone, Copy)]
+pub struct AnyMeasurement {
+    unit: &'static dyn DynUnit,
+    value: f64,
+}
+
+/// Implemented by the types an [`AnyMeasurement`] can be downcasted into.
+pub trait FromAnyMeasurement: Sized {
+    fn from_any(input: &AnyMeasurement) -> Option<Self>;
+}
+
+impl AnyMeasurement {
+    /// Creates a new measurement from the given numerical value and unit.
+    pub fn new(value: f64, unit: &'static dyn DynUnit) -> AnyMeasurement {
+        Self { unit, value }
+    }
+
+    /// Creates a new measurement of the built-in or
+    /// [registered](crate::registry::register_unit) unit with the given
+    /// symbol, or of a quotient of them like "KiB/min". Returns None if
+    /// the symbol is unknown.
+    pub fn with_symbol(value: f64, symbol: &str) -> Option<AnyMeasurement> {
+        parse::detect_unit(symbol, None).map(|unit| Self::new(value, unit))
+    }
+
+    /// Returns the current numerical value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the unit of the current value.
+    pub fn unit(&self) -> &'static dyn DynUnit {
+        self.unit
+    }
+
+    /// Returns the current value, expressed in the base unit of its quantity.
+    pub fn base_value(&self) -> f64 {
+        self.unit.value_to_base(self.value)
+    }
+
+    /// Returns the name of the quantity measured, e.g "time".
+    pub fn quantity(&self) -> Cow<'static, str> {
+        self.unit.quantity()
+    }
+
+    /// Returns whether both measurements measure the same quantity,
+    /// and therefore can be compared.
+    pub fn same_quantity(&self, other: &AnyMeasurement) -> bool {
+        self.unit.quantity() == other.unit.quantity()
+    }
+
+    fn check_quantity(&self, other: &AnyMeasurement) -> Result<(), QuantityMismatch> {
+        if self.same_quantity(other) {
+            Ok(())
+        } else {
+            Err(QuantityMismatch {
+                left: self.quantity().into_owned(),
+                right: other.quantity().into_owned(),
+            })
+        }
+    }
+
+    /// Compares both measurements, or returns an error if they don't
+    /// measure the same quantity. Unlike [`PartialOrd`], it is a total
+    /// order, following [`f64::total_cmp`] for NaN values.
+    pub fn try_cmp(&self, other: &AnyMeasurement) -> Result<Ordering, QuantityMismatch> {
+        self.check_quantity(other)?;
+        Ok(self.base_value().total_cmp(&other.base_value()))
+    }
+
+    /// Recovers the statically typed measurement (e.g
+    /// `Measurement<Hour>`) this measurement was created from. Returns
+    /// None if its unit doesn't match the requested one.
+    pub fn downcast<M: FromAnyMeasurement>(&self) -> Option<M> {
+        M::from_any(self)
+    }
+
+    /// Converts this measurement into the unit U. Returns None if U
+    /// doesn't measure the same quantity.
+    pub fn convert<U: MeasureUnit + 'static>(&self) -> Option<Measurement<U>>
+    where
+        U::AliasedUnit: BaseConversion,
+    {
+        let unit = dyn_unit::<U>();
+        (unit.quantity() == self.unit.quantity())
+            .then(|| Measurement::new(unit.value_from_base(self.base_value())))
+    }
+}
+
+impl<U: MeasureUnit + 'static> FromAnyMeasurement for Measurement<U> {
+    fn from_any(input: &AnyMeasurement) -> Option<Self> {
+        let unit: &dyn Any = input.unit;
+        unit.downcast_ref::<StaticUnit<U>>()
+            .map(|_| Measurement::new(input.value))
+    }
+}
+
+/// Converts the measurement into the unit U, or returns an error if U
+/// doesn't measure the same quantity. See [`AnyMeasurement::convert`].
+impl<U: MeasureUnit + 'static> TryFrom<AnyMeasurement> for Measurement<U>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    type Error = QuantityMismatch;
+
+    fn try_from(input: AnyMeasurement) -> Result<Self, Self::Error> {
+        input.convert().ok_or_else(|| QuantityMismatch {
+            left: input.quantity().into_owned(),
+            right: dyn_unit::<U>().quantity().into_owned(),
+        })
+    }
+}
+
+impl<U: MeasureUnit + 'static> From<Measurement<U>> for AnyMeasurement
+where
+    U::AliasedUnit: BaseConversion,
+{
+    fn from(input: Measurement<U>) -> Self {
+        AnyMeasurement::new(input.value(), dyn_unit::<U>())
+    }
+}
+
+impl Debug for AnyMeasurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!("AnyMeasurement<{}>", self.unit.symbol()))
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Display for AnyMeasurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.format(format::default_format()), f)
+    }
+}
+
+impl PartialOrd for AnyMeasurement {
+    /// Compares both measurements, if they measure the same quantity.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !self.same_quantity(other) {
+            return None;
+        }
+
+        self.base_value().partial_cmp(&other.base_value())
+    }
+}
+
+impl PartialEq for AnyMeasurement {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_quantity(other) && self.base_value() == other.base_value()
+    }
+}
+
+/// Sum of two measurements. The result is expressed in the unit of
+/// the left one, or is an error if they don't measure the same
+/// quantity.
+impl Add for AnyMeasurement {
+    type Output = Result<AnyMeasurement, QuantityMismatch>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_quantity(&rhs)?;
+        let rhs = self.unit.value_from_base(rhs.base_value());
+        Ok(AnyMeasurement::new(self.value + rhs, self.unit))
+    }
+}
+
+/// Difference of two measurements. The result is expressed in the unit
+/// of the left one, or is an error if they don't measure the same
+/// quantity.
+impl Sub for AnyMeasurement {
+    type Output = Result<AnyMeasurement, QuantityMismatch>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_quantity(&rhs)?;
+        let rhs = self.unit.value_from_base(rhs.base_value());
+        Ok(AnyMeasurement::new(self.value - rhs, self.unit))
+    }
+}
+
+/// Error returned when combining [`AnyMeasurement`]s of different
+/// quantities, e.g adding bytes to seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuantityMismatch {
+    /// Quantity of the left operand.
+    pub left: String,
+    /// Quantity of the right operand.
+    pub right: String,
+}
+
+impl Display for QuantityMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot combine a measurement of {} with one of {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl Error for QuantityMismatch {}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use std::{borrow::Cow, cmp::Ordering};
+
+    use super::{dyn_unit, AnyMeasurement, DynUnit, QuantityMismatch, StaticUnit};
+    use crate::{
+        registry::register_unit,
+        units::{Byte, Hour, Kbps, Kilobit, Kilobyte, Megabyte, Minute, Second},
+        DivUnit, Measurement,
+    };
+
+    #[test]
+    fn test_boxed_units() {
+        let units: Vec<Box<dyn DynUnit>> = vec![
+            Box::new(StaticUnit::<Hour>::new()),
+            Box::new(StaticUnit::<Megabyte>::new()),
+        ];
+
+        assert_eq!("h", units[0].symbol());
+        assert_eq!("time", units[0].quantity());
+        assert_eq!(7200.0, units[0].value_to_base(2.0));
+        assert_eq!("data", units[1].quantity());
+        assert_eq!(1.0, units[1].value_from_base(8_000_000.0));
+    }
+
+    #[test]
+    fn test_default_names() {
+        struct Crate(Option<&'static str>);
+
+        impl DynUnit for Crate {
+            fn symbol(&self) -> Cow<'static, str> {
+                "crt".into()
+            }
+
+            fn name(&self) -> Cow<'static, str> {
+                self.0.unwrap_or("crt").into()
+            }
+
+            fn quantity(&self) -> Cow<'static, str> {
+                "data".into()
+            }
+
+            fn value_to_base(&self, value: f64) -> f64 {
+                value
+            }
+
+            fn value_from_base(&self, value: f64) -> f64 {
+                value
+            }
+        }
+
+        assert_eq!("crates", Crate(Some("crate")).plural_name());
+        assert_eq!("crt", Crate(None).plural_name());
+        assert_eq!("hours", dyn_unit::<Hour>().plural_name());
+    }
+
+    #[test]
+    fn test_alias_resolution() {
+        let alias = dyn_unit::<Kbps>();
+        let unit = dyn_unit::<DivUnit<Kilobit, Second>>();
+
+        assert_eq!("Kbps", alias.symbol());
+        assert_eq!("Kb/s", unit.symbol());
+        assert_eq!("data/time", alias.quantity());
+        assert_eq!(unit.quantity(), alias.quantity());
+        assert_eq!(unit.value_to_base(3.0), alias.value_to_base(3.0));
+    }
+
+    #[test]
+    fn test_convert_through_base() {
+        let from = dyn_unit::<Hour>();
+        let to = dyn_unit::<Minute>();
+
+        assert_eq!(90.0, to.value_from_base(from.value_to_base(1.5)));
+    }
+
+    #[test]
+    fn test_any_measurement_downcast() {
+        let values: Vec<AnyMeasurement> = vec![
+            Measurement::<Hour>::new(1.5).into(),
+            Measurement::<Kbps>::new(20.0).into(),
+        ];
+
+        assert_eq!(
+            Some(1.5),
+            values[0].downcast::<Measurement<Hour>>().map(|m| m.value())
+        );
+        assert!(values[0].downcast::<Measurement<Minute>>().is_none());
+        assert!(values[1]
+            .downcast::<Measurement<DivUnit<Kilobit, Second>>>()
+            .is_none());
+        assert_eq!("20 Kbps", values[1].to_string());
+    }
+
+    #[test]
+    fn test_any_measurement_cmp() {
+        let hour: AnyMeasurement = Measurement::<Hour>::new(1.0).into();
+        let minutes: AnyMeasurement = Measurement::<Minute>::new(90.0).into();
+        let bytes: AnyMeasurement = Measurement::<Byte>::new(1.0).into();
+
+        assert!(hour < minutes);
+        assert_eq!(hour, AnyMeasurement::from(Measurement::<Minute>::new(60.0)));
+        assert_eq!(None, hour.partial_cmp(&bytes));
+        assert_ne!(hour, bytes);
+    }
+
+    #[test]
+    fn test_any_measurement_arithmetic() {
+        let hour: AnyMeasurement = Measurement::<Hour>::new(1.0).into();
+        let minutes: AnyMeasurement = Measurement::<Minute>::new(30.0).into();
+        let bytes: AnyMeasurement = Measurement::<Byte>::new(1.0).into();
+
+        assert_eq!("1.5 h", (hour + minutes).unwrap().to_string());
+        assert_eq!("-30 min", (minutes - hour).unwrap().to_string());
+        assert_eq!("time", hour.quantity());
+
+        let mismatch = QuantityMismatch {
+            left: "time".to_owned(),
+            right: "data".to_owned(),
+        };
+        assert_eq!(Err(mismatch.clone()), (hour + bytes).map(|m| m.value()));
+        assert_eq!(Err(mismatch.clone()), (hour - bytes).map(|m| m.value()));
+        assert_eq!(Err(mismatch), hour.try_cmp(&bytes));
+        assert_eq!(
+            "cannot combine a measurement of data with one of time",
+            bytes.try_cmp(&hour).unwrap_err().to_string()
+        );
+        assert_eq!(Ok(Ordering::Greater), hour.try_cmp(&minutes));
+    }
+
+    #[test]
+    fn test_any_measurement_try_into() {
+        register_unit("parcel", Measurement::<Megabyte>::new(1.5)).unwrap();
+
+        let parcels = AnyMeasurement::with_symbol(2.0, "parcel").unwrap();
+        let size: Measurement<Kilobyte> = parcels.try_into().unwrap();
+        assert_eq!(3000.0, size.value());
+
+        let rate = AnyMeasurement::with_symbol(1.0, "parcel/s").unwrap();
+        assert_eq!(
+            Ok(12_000.0),
+            Measurement::<Kbps>::try_from(rate).map(|m| m.value())
+        );
+
+        assert_eq!(
+            Err(QuantityMismatch {
+                left: "data".to_owned(),
+                right: "time".to_owned()
+            }),
+            Measurement::<Second>::try_from(parcels)
+        );
+        assert!(AnyMeasurement::with_symbol(1.0, "parsec").is_none());
+    }
+}
@@ -0,0 +1,351 @@
+//! C interface, enabled by the `ffi` feature. Measurements cross the
+//! boundary as [`SuMeasurement`] structs, whose unit is identified by
+//! one of the `SU_UNIT_*` ids. All the functions return `SU_OK` on
+//! success, or one of the `SU_ERR_*` codes otherwise.
+//!
+//! A library to link from C is built with `cargo rustc --release
+//! --features ffi --crate-type cdylib` (or `staticlib`).
+
+use std::ffi::{c_char, CStr};
+
+use crate::{
+    dynamic::{dyn_unit, AnyMeasurement, DynUnit},
+    parse::{parse_any, ParseError},
+    units::*,
+};
+
+/// Measurement of a built-in unit, identified by its id.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuMeasurement {
+    pub value: f64,
+    pub unit: u32,
+}
+
+pub const SU_OK: i32 = 0;
+pub const SU_ERR_NULL_POINTER: i32 = 1;
+pub const SU_ERR_UNKNOWN_UNIT: i32 = 2;
+pub const SU_ERR_INCOMPATIBLE_UNITS: i32 = 3;
+pub const SU_ERR_PARSE: i32 = 4;
+pub const SU_ERR_BUFFER_TOO_SMALL: i32 = 5;
+
+macro_rules! unit_ids {
+    ($($(#[$attr:meta])* $const:ident = $id:literal => $unit:ty),* $(,)?) => {
+        $($(#[$attr])* pub const $const: u32 = $id;)*
+
+        fn unit_by_id(id: u32) -> Option<&'static dyn DynUnit> {
+            match id {
+                $($(#[$attr])* $id => Some(dyn_unit::<$unit>()),)*
+                _ => None,
+            }
+        }
+
+        fn id_of_unit(unit: &dyn DynUnit) -> Option<u32> {
+            let symbol = unit.symbol();
+            $(
+                $(#[$attr])*
+                if symbol == dyn_unit::<$unit>().symbol() {
+                    return Some($id);
+                }
+            )*
+            None
+        }
+    };
+}
+
+unit_ids! {
+    SU_UNIT_SECOND = 1 => Second,
+    SU_UNIT_MINUTE = 2 => Minute,
+    SU_UNIT_HOUR = 3 => Hour,
+    SU_UNIT_MILLISECOND = 4 => Millisecond,
+    SU_UNIT_MICROSECOND = 5 => Microsecond,
+    SU_UNIT_DAY = 6 => Day,
+    SU_UNIT_NANOSECOND = 7 => Nanosecond,
+
+    SU_UNIT_BIT = 100 => Bit,
+    SU_UNIT_KILOBIT = 101 => Kilobit,
+    SU_UNIT_MEGABIT = 102 => Megabit,
+    SU_UNIT_GIGABIT = 103 => Gigabit,
+    SU_UNIT_TERABIT = 104 => Terabit,
+    SU_UNIT_PETABIT = 105 => Petabit,
+    SU_UNIT_EXABIT = 106 => Exabit,
+    SU_UNIT_ZETTABIT = 107 => Zettabit,
+    SU_UNIT_YOTTABIT = 108 => Yottabit,
+
+    SU_UNIT_BYTE = 200 => Byte,
+    SU_UNIT_KILOBYTE = 201 => Kilobyte,
+    SU_UNIT_MEGABYTE = 202 => Megabyte,
+    SU_UNIT_GIGABYTE = 203 => Gigabyte,
+    SU_UNIT_TERABYTE = 204 => Terabyte,
+    SU_UNIT_PETABYTE = 205 => Petabyte,
+    SU_UNIT_EXABYTE = 206 => Exabyte,
+    SU_UNIT_ZETTABYTE = 207 => Zettabyte,
+    SU_UNIT_YOTTABYTE = 208 => Yottabyte,
+
+    SU_UNIT_KIBIBYTE = 301 => Kibibyte,
+    SU_UNIT_MEBIBYTE = 302 => Mebibyte,
+    SU_UNIT_GIBIBYTE = 303 => Gibibyte,
+    SU_UNIT_TEBIBYTE = 304 => Tebibyte,
+    SU_UNIT_PEBIBYTE = 305 => Pebibyte,
+    SU_UNIT_EXBIBYTE = 306 => Exbibyte,
+    SU_UNIT_ZEBIBYTE = 307 => Zebibyte,
+    SU_UNIT_YOBIBYTE = 308 => Yobibyte,
+
+    SU_UNIT_BPS = 400 => Bps,
+    SU_UNIT_KBPS = 401 => Kbps,
+    SU_UNIT_MBPS = 402 => Mbps,
+    SU_UNIT_GBPS = 403 => Gbps,
+    SU_UNIT_TBPS = 404 => Tbps,
+
+    #[cfg(feature = "temperature")]
+    SU_UNIT_KELVIN = 500 => Kelvin,
+    #[cfg(feature = "temperature")]
+    SU_UNIT_CELSIUS = 501 => Celsius,
+    #[cfg(feature = "temperature")]
+    SU_UNIT_FAHRENHEIT = 502 => Fahrenheit,
+    #[cfg(feature = "temperature")]
+    SU_UNIT_RANKINE = 503 => Rankine,
+}
+
+/// Converts the input measurement into the given unit, storing the
+/// result in `output`.
+///
+/// # Safety
+///
+/// `output` must be either null or a valid pointer to a writable
+/// `SuMeasurement`.
+#[no_mangle]
+pub unsafe extern "C" fn su_convert(
+    input: SuMeasurement,
+    unit: u32,
+    output: *mut SuMeasurement,
+) -> i32 {
+    let (Some(from), Some(to)) = (unit_by_id(input.unit), unit_by_id(unit)) else {
+        return SU_ERR_UNKNOWN_UNIT;
+    };
+
+    if from.quantity() != to.quantity() {
+        return SU_ERR_INCOMPATIBLE_UNITS;
+    }
+
+    match output.as_mut() {
+        Some(output) => {
+            *output = SuMeasurement {
+                value: to.value_from_base(from.value_to_base(input.value)),
+                unit,
+            };
+            SU_OK
+        }
+        None => SU_ERR_NULL_POINTER,
+    }
+}
+
+/// Formats the input measurement as a NUL-terminated string into the
+/// given buffer, using the given amount of decimals, or as many as
+/// needed if `precision` is negative. If `required` is not null, the
+/// size needed for the whole string, including the NUL terminator, is
+/// stored in it.
+///
+/// If the buffer is too small, `SU_ERR_BUFFER_TOO_SMALL` is returned
+/// and, unless `len` is zero, the buffer holds as much of the string as
+/// fits, still NUL-terminated.
+///
+/// # Safety
+///
+/// `buffer` must be either null or a valid pointer to a writable
+/// region of at least `len` bytes, and `required` either null or a
+/// valid pointer to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn su_format(
+    input: SuMeasurement,
+    precision: i32,
+    buffer: *mut c_char,
+    len: usize,
+    required: *mut usize,
+) -> i32 {
+    let Some(unit) = unit_by_id(input.unit) else {
+        return SU_ERR_UNKNOWN_UNIT;
+    };
+
+    if buffer.is_null() {
+        return SU_ERR_NULL_POINTER;
+    }
+
+    let measurement = AnyMeasurement::new(input.value, unit);
+    let formatted = match usize::try_from(precision) {
+        Ok(precision) => format!("{:.*}", precision, measurement),
+        Err(_) => measurement.to_string(),
+    };
+
+    if let Some(required) = required.as_mut() {
+        *required = formatted.len() + 1;
+    }
+
+    let buffer = std::slice::from_raw_parts_mut(buffer as *mut u8, len);
+    let Some(last) = len.checked_sub(1) else {
+        return SU_ERR_BUFFER_TOO_SMALL;
+    };
+
+    // Truncated on a character boundary, so the string stays valid
+    // UTF-8.
+    let mut copied = formatted.len().min(last);
+    while !formatted.is_char_boundary(copied) {
+        copied -= 1;
+    }
+
+    buffer[..copied].copy_from_slice(&formatted.as_bytes()[..copied]);
+    buffer[copied] = 0;
+
+    if copied < formatted.len() {
+        SU_ERR_BUFFER_TOO_SMALL
+    } else {
+        SU_OK
+    }
+}
+
+/// Parses a NUL-terminated string such as "1.5 MiB" into a
+/// measurement of any of the built-in units, storing it in `output`.
+/// Returns `SU_ERR_UNKNOWN_UNIT` if the unit is unknown or has no id,
+/// and `SU_ERR_PARSE` if the input is not a valid measurement.
+///
+/// # Safety
+///
+/// `input` must be either null or a valid pointer to a NUL-terminated
+/// string, and `output` either null or a valid pointer to a writable
+/// `SuMeasurement`.
+#[no_mangle]
+pub unsafe extern "C" fn su_parse(input: *const c_char, output: *mut SuMeasurement) -> i32 {
+    if input.is_null() || output.is_null() {
+        return SU_ERR_NULL_POINTER;
+    }
+
+    let Ok(input) = CStr::from_ptr(input).to_str() else {
+        return SU_ERR_PARSE;
+    };
+
+    let measurement = match parse_any(input) {
+        Ok(measurement) => measurement,
+        Err(ParseError::UnknownUnit(_)) => return SU_ERR_UNKNOWN_UNIT,
+        Err(_) => return SU_ERR_PARSE,
+    };
+
+    match id_of_unit(measurement.unit()) {
+        Some(unit) => {
+            *output = SuMeasurement {
+                value: measurement.value(),
+                unit,
+            };
+            SU_OK
+        }
+        None => SU_ERR_UNKNOWN_UNIT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{c_char, CStr};
+
+    use super::*;
+
+    #[test]
+    fn test_convert() {
+        let mut output = SuMeasurement {
+            value: 0.0,
+            unit: 0,
+        };
+        let input = SuMeasurement {
+            value: 1.5,
+            unit: SU_UNIT_HOUR,
+        };
+
+        unsafe {
+            assert_eq!(SU_OK, su_convert(input, SU_UNIT_MINUTE, &mut output));
+            assert_eq!(
+                SU_ERR_INCOMPATIBLE_UNITS,
+                su_convert(input, SU_UNIT_BYTE, &mut output)
+            );
+            assert_eq!(SU_ERR_UNKNOWN_UNIT, su_convert(input, 9999, &mut output));
+        }
+
+        assert_eq!(90.0, output.value);
+        assert_eq!(SU_UNIT_MINUTE, output.unit);
+    }
+
+    #[test]
+    fn test_format() {
+        let mut buffer = [0 as c_char; 16];
+        let input = SuMeasurement {
+            value: 42.4242,
+            unit: SU_UNIT_KBPS,
+        };
+
+        let mut required = 0;
+
+        unsafe {
+            assert_eq!(
+                SU_OK,
+                su_format(input, 2, buffer.as_mut_ptr(), 16, &mut required)
+            );
+            assert_eq!(
+                "42.42 Kbps",
+                CStr::from_ptr(buffer.as_ptr()).to_str().unwrap()
+            );
+            assert_eq!(11, required);
+
+            assert_eq!(
+                SU_ERR_BUFFER_TOO_SMALL,
+                su_format(input, -1, buffer.as_mut_ptr(), 4, &mut required)
+            );
+            assert_eq!("42.", CStr::from_ptr(buffer.as_ptr()).to_str().unwrap());
+            assert_eq!(13, required);
+
+            assert_eq!(
+                SU_ERR_BUFFER_TOO_SMALL,
+                su_format(input, 2, buffer.as_mut_ptr(), 0, std::ptr::null_mut())
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut output = SuMeasurement {
+            value: 0.0,
+            unit: 0,
+        };
+
+        unsafe {
+            assert_eq!(SU_OK, su_parse(c"1.5 MiB".as_ptr(), &mut output));
+            assert_eq!(
+                SU_ERR_UNKNOWN_UNIT,
+                su_parse(c"1.5 parsecs".as_ptr(), &mut output)
+            );
+            assert_eq!(SU_ERR_PARSE, su_parse(c"one MiB".as_ptr(), &mut output));
+        }
+
+        assert_eq!(1.5, output.value);
+        assert_eq!(SU_UNIT_MEBIBYTE, output.unit);
+    }
+
+    #[cfg(feature = "temperature")]
+    #[test]
+    fn test_temperature() {
+        let mut output = SuMeasurement {
+            value: 0.0,
+            unit: 0,
+        };
+        let input = SuMeasurement {
+            value: 100.0,
+            unit: SU_UNIT_CELSIUS,
+        };
+
+        unsafe {
+            assert_eq!(SU_OK, su_convert(input, SU_UNIT_KELVIN, &mut output));
+            assert!((output.value - 373.15).abs() < 1e-9);
+
+            assert_eq!(SU_OK, su_parse(c"451 °F".as_ptr(), &mut output));
+        }
+
+        assert_eq!(451.0, output.value);
+        assert_eq!(SU_UNIT_FAHRENHEIT, output.unit);
+    }
+}
@@ -0,0 +1,313 @@
+//! Fixed-point numbers, for holding measurements on targets without a
+//! floating point unit, where f64 arithmetic is emulated and slow.
+//! Measurements of [`Fixed`] values are added, subtracted and
+//! [rescaled](crate::Measurement::rescale) between linear units with
+//! integer arithmetic only, so the results are the same on every
+//! target.
+//!
+//! ```
+//! # #[cfg(feature = "rate")] {
+//! use strong_units::{
+//!     fixed::Fixed,
+//!     units::{Bit, Kilobit, Kilobyte, Millisecond, Second},
+//!     DivUnit, Measurement,
+//! };
+//!
+//! let rate = Measurement::<DivUnit<Kilobit, Second>, Fixed<16>>::new(Fixed::from_int(12));
+//! let elapsed = Measurement::<Millisecond, Fixed<16>>::new(Fixed::from_int(250));
+//!
+//! let sent = rate * elapsed.rescale::<Second>();
+//! assert_eq!(Fixed::from_int(3000), sent.rescale::<Bit>().value());
+//! assert_eq!("0.375 KB", sent.rescale::<Kilobyte>().to_string());
+//! # }
+//! ```
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display, LowerExp},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+use crate::{mul_ratio_i128, Measurement, Number, Scalar};
+
+/// Signed fixed-point number with FRAC fractional bits, stored in an
+/// `i64`, e.g `Fixed<16>` holds values up to ±2^47 with a resolution
+/// of 2^-16.
+///
+/// As with integers, additions and subtractions overflowing the range
+/// panic in debug builds; see [`Number::checked_add`] for the checked
+/// ones. Products and quotients are computed in `i128`, truncating
+/// their fractional bits, and saturated to the range.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const FRAC: u32> {
+    bits: i64,
+}
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    const ONE: i64 = 1 << FRAC;
+
+    /// Creates a number from its raw representation, the value
+    /// multiplied by 2^FRAC.
+    #[inline]
+    pub const fn from_bits(bits: i64) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the raw representation of the number, its value
+    /// multiplied by 2^FRAC.
+    #[inline]
+    pub const fn to_bits(self) -> i64 {
+        self.bits
+    }
+
+    /// Creates a number from an integer, saturating it to the range.
+    #[inline]
+    pub const fn from_int(value: i64) -> Self {
+        Self::from_bits(value.saturating_mul(Self::ONE))
+    }
+
+    /// Creates a number from the fraction num/den, rounded to the
+    /// nearest value and saturated to the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if den is zero.
+    pub fn from_ratio(num: i64, den: u64) -> Self {
+        assert!(den != 0, "denominator of a fixed-point number is zero");
+        Self::from_int(num).mul_ratio(1, den)
+    }
+
+    fn saturate(bits: i128) -> Self {
+        Self::from_bits(i64::try_from(bits).unwrap_or(if bits < 0 { i64::MIN } else { i64::MAX }))
+    }
+}
+
+impl<const FRAC: u32> Scalar for Fixed<FRAC> {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.bits as f64 / Self::ONE as f64
+    }
+}
+
+impl<const FRAC: u32> Number for Fixed<FRAC> {
+    const ZERO: Self = Self::from_bits(0);
+    const MIN: Self = Self::from_bits(i64::MIN);
+    const MAX: Self = Self::from_bits(i64::MAX);
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        Self::from_bits((value * Self::ONE as f64).round() as i64)
+    }
+
+    #[inline]
+    fn checked_from_f64(value: f64) -> Option<Self> {
+        let bits = (value * Self::ONE as f64).round();
+        (bits >= i64::MIN as f64 && bits < i64::MAX as f64 + 1.0)
+            .then(|| Self::from_bits(bits as i64))
+    }
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.bits.checked_add(rhs.bits).map(Self::from_bits)
+    }
+
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.bits.checked_sub(rhs.bits).map(Self::from_bits)
+    }
+
+    #[inline]
+    fn mul_ratio(self, num: u64, den: u64) -> Self {
+        match mul_ratio_i128(i128::from(self.bits), num, den) {
+            Some(bits) => Self::saturate(bits),
+            // Only reachable with a ratio beyond the range of i64.
+            None => Self::saturate(if self.bits < 0 { i128::MIN } else { i128::MAX }),
+        }
+    }
+}
+
+impl<const FRAC: u32> Add for Fixed<FRAC> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits + rhs.bits)
+    }
+}
+
+impl<const FRAC: u32> AddAssign for Fixed<FRAC> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.bits += rhs.bits;
+    }
+}
+
+impl<const FRAC: u32> Sub for Fixed<FRAC> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_bits(self.bits - rhs.bits)
+    }
+}
+
+impl<const FRAC: u32> SubAssign for Fixed<FRAC> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.bits -= rhs.bits;
+    }
+}
+
+impl<const FRAC: u32> Mul for Fixed<FRAC> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::saturate((i128::from(self.bits) * i128::from(rhs.bits)) >> FRAC)
+    }
+}
+
+/// Division by zero gives the largest value of the sign of the
+/// dividend, as floats give an infinity, or zero if it is zero.
+impl<const FRAC: u32> Div for Fixed<FRAC> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        match (rhs.bits, self.bits.cmp(&0)) {
+            (0, Ordering::Less) => Self::MIN,
+            (0, Ordering::Equal) => Self::ZERO,
+            (0, Ordering::Greater) => Self::MAX,
+            _ => Self::saturate((i128::from(self.bits) << FRAC) / i128::from(rhs.bits)),
+        }
+    }
+}
+
+impl<const FRAC: u32> Debug for Fixed<FRAC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+/// Without a precision, numbers are displayed with as many decimal
+/// digits as needed to tell consecutive values apart, trailing zeros
+/// aside, so e.g 0.3 isn't displayed as the value closest to it.
+impl<const FRAC: u32> Display for Fixed<FRAC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.precision().is_some() {
+            return Display::fmt(&self.to_f64(), f);
+        }
+
+        // log10(2) decimal digits for each fractional bit.
+        let digits = (FRAC as usize * 30_103).div_ceil(100_000);
+        let text = format!("{:.*}", digits, self.to_f64());
+        if text.contains('.') {
+            f.pad(text.trim_end_matches('0').trim_end_matches('.'))
+        } else {
+            f.pad(&text)
+        }
+    }
+}
+
+impl<const FRAC: u32> LowerExp for Fixed<FRAC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        LowerExp::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<U, const FRAC: u32> Mul<Fixed<FRAC>> for Measurement<U, Fixed<FRAC>> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Fixed<FRAC>) -> Self {
+        Measurement::new(self.value() * rhs)
+    }
+}
+
+impl<U, const FRAC: u32> MulAssign<Fixed<FRAC>> for Measurement<U, Fixed<FRAC>> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Fixed<FRAC>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<U, const FRAC: u32> Div<Fixed<FRAC>> for Measurement<U, Fixed<FRAC>> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Fixed<FRAC>) -> Self {
+        Measurement::new(self.value() / rhs)
+    }
+}
+
+impl<U, const FRAC: u32> DivAssign<Fixed<FRAC>> for Measurement<U, Fixed<FRAC>> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Fixed<FRAC>) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use super::Fixed;
+    use crate::{
+        units::{
+            Bit, Byte, Gigabit, Hour, Kilobit, Kilobyte, Megabit, Microsecond, Millisecond, Minute,
+            Second,
+        },
+        DivUnit, Measurement, Number,
+    };
+
+    type Q16 = Fixed<16>;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Q16::from_ratio(3, 2);
+        let b = Q16::from_int(-4);
+
+        assert_eq!(Q16::from_ratio(-5, 2), a + b);
+        assert_eq!(Q16::from_int(-6), a * b);
+        assert_eq!(Q16::from_ratio(-3, 8), a / b);
+        assert_eq!(Q16::MAX, a / Q16::ZERO);
+        assert_eq!(None, Q16::MAX.checked_add(a));
+        assert_eq!(Q16::MIN, Q16::MAX * b);
+        assert_eq!("1.5", a.to_string());
+        assert_eq!("0.3", Q16::from_ratio(3, 10).to_string());
+        assert_eq!("-4", b.to_string());
+        assert_eq!("-4.00", format!("{:.2}", b));
+    }
+
+    #[test]
+    fn test_rescale() {
+        let rate = Measurement::<DivUnit<Gigabit, Second>, Q16>::new(Q16::from_ratio(5, 4));
+        assert_eq!(
+            Q16::from_int(1_250_000),
+            rate.rescale::<DivUnit<Kilobit, Second>>().value()
+        );
+        assert_eq!(
+            Q16::from_int(9_375),
+            rate.rescale::<DivUnit<Megabit, Minute>>().value() / Q16::from_int(8)
+        );
+
+        let latency = Measurement::<Microsecond, Q16>::new(Q16::from_int(1500));
+        assert_eq!(
+            Q16::from_ratio(3, 2),
+            latency.rescale::<Millisecond>().value()
+        );
+        assert_eq!(Q16::from_bits(98), latency.rescale::<Second>().value());
+
+        let day = Measurement::<Hour, Q16>::new(Q16::from_int(24));
+        assert_eq!(Q16::from_int(86_400), day.rescale::<Second>().value());
+    }
+
+    #[test]
+    fn test_measurements() {
+        let sent = Measurement::<DivUnit<Bit, Second>, Q16>::new(Q16::from_int(8_000))
+            * Measurement::<Second, Q16>::new(Q16::from_ratio(1, 4));
+        let sent = sent.rescale::<Byte>() + Measurement::<Byte, Q16>::new(Q16::from_int(50));
+
+        assert_eq!(Q16::from_int(300), sent.value());
+        assert_eq!("0.3 KB", sent.rescale::<Kilobyte>().to_string());
+        assert_eq!(Q16::from_int(150), (sent / Q16::from_int(2)).value());
+    }
+}
@@ -0,0 +1,908 @@
+//! Configurable formatting of measurements, for the cases where the
+//! default `Display` implementation ("1.5 MB") doesn't fit, e.g
+//! user-facing messages that need the long name of the units.
+//!
+//! ```
+//! # #[cfg(feature = "time")] {
+//! use strong_units::{format::MeasurementFormat, units::Hour, Measurement};
+//!
+//! let long = MeasurementFormat::long();
+//! assert_eq!("1 hour", Measurement::<Hour>::new(1.0).format(long).to_string());
+//! assert_eq!("2 hours", Measurement::<Hour>::new(2.0).format(long).to_string());
+//! # }
+//! ```
+
+use std::{
+    borrow::Cow,
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display, Write},
+    marker::PhantomData,
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use crate::{
+    dynamic::{AnyMeasurement, DynUnit},
+    symbols::{self, SymbolStyle},
+    FromUnit, MeasureUnit, Measurement, Number,
+};
+
+/// How the unit of a measurement is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    /// The symbol of the unit, e.g "1.5 MB".
+    #[default]
+    Symbol,
+    /// The long name of the unit, pluralized according to the value,
+    /// e.g "1.5 megabytes".
+    Name,
+}
+
+/// Options for formatting measurements. The default options produce
+/// "1.5 MB"-like output, which is also what the `Display`
+/// implementation of measurements produces unless a different
+/// [default format](default_format) is configured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeasurementFormat {
+    unit_style: UnitStyle,
+    symbol_style: Option<SymbolStyle>,
+    precision: Option<usize>,
+    separator: &'static str,
+    round_trip: bool,
+}
+
+impl MeasurementFormat {
+    /// Creates a new format with the default options.
+    pub const fn new() -> Self {
+        Self {
+            unit_style: UnitStyle::Symbol,
+            symbol_style: None,
+            precision: None,
+            separator: " ",
+            round_trip: false,
+        }
+    }
+
+    /// Creates a new format that writes the long name of the units.
+    pub const fn long() -> Self {
+        Self::new().unit_style(UnitStyle::Name)
+    }
+
+    /// Creates a new format that writes the unit right after the value,
+    /// without a space in between (e.g "42KiB" or "3ms"), as usual in
+    /// logs and narrow UIs.
+    pub const fn compact() -> Self {
+        Self::new().separator("")
+    }
+
+    /// Sets how the unit is written.
+    pub const fn unit_style(mut self, style: UnitStyle) -> Self {
+        self.unit_style = style;
+        self
+    }
+
+    /// Sets the style of the symbols, instead of using the global one.
+    pub const fn symbol_style(mut self, style: SymbolStyle) -> Self {
+        self.symbol_style = Some(style);
+        self
+    }
+
+    /// Sets the number of decimal digits of the value. If not set, the
+    /// precision of the formatter is used, if any, or otherwise the
+    /// [default precision](MeasureUnit::default_precision) of the unit.
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the text written between the value and the unit, which is
+    /// a single space by default.
+    pub const fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Writes values using the shortest representation that parses
+    /// back into exactly the same number, ignoring any precision.
+    /// Values that are very large or very close to zero are written in
+    /// scientific notation (e.g "1e-7"), which is shorter.
+    ///
+    /// Note that values resulting from inexact operations may still
+    /// need many digits to round-trip, like 0.1 + 0.2, which is written
+    /// as "0.30000000000000004".
+    pub const fn round_trip(mut self) -> Self {
+        self.round_trip = true;
+        self
+    }
+
+    fn write_value<T: Number>(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        value: T,
+        precision: Option<usize>,
+        unit: &dyn UnitText,
+    ) -> std::fmt::Result {
+        if self.round_trip {
+            // Both representations are the shortest that round-trip.
+            let magnitude = value.to_f64().abs();
+            return if magnitude != 0.0 && !(1e-5..1e16).contains(&magnitude) {
+                write!(f, "{:e}", value)
+            } else {
+                write!(f, "{}", value)
+            };
+        }
+
+        let precision = precision
+            .or(self.precision)
+            .or(f.precision())
+            .or_else(|| unit.default_precision());
+
+        match precision {
+            Some(precision) => write!(f, "{:.*}", precision, value),
+            None => write!(f, "{}", value),
+        }
+    }
+
+    fn write_unit<T: Number>(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        value: T,
+        unit: &dyn UnitText,
+        style: UnitStyle,
+    ) -> std::fmt::Result {
+        let text = match style {
+            UnitStyle::Symbol => {
+                unit.symbol_in(self.symbol_style.unwrap_or_else(symbols::symbol_style))
+            }
+            UnitStyle::Name if value.to_f64().abs() == 1.0 => unit.name(),
+            UnitStyle::Name => unit.plural_name(),
+        };
+
+        f.write_str(&text)
+    }
+
+    fn write<T: Number>(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        value: T,
+        unit: &dyn UnitText,
+    ) -> std::fmt::Result {
+        self.write_value(f, value, None, unit)?;
+        f.write_str(self.separator)?;
+        self.write_unit(f, value, unit, self.unit_style)
+    }
+}
+
+impl Default for MeasurementFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static DEFAULT_FORMAT: OnceLock<MeasurementFormat> = OnceLock::new();
+
+thread_local! {
+    static THREAD_FORMAT: Cell<Option<MeasurementFormat>> = const { Cell::new(None) };
+}
+
+/// Sets the format used by the `Display` implementation of
+/// measurements in the whole program. It can only be set once, so
+/// it's meant to be configured at startup; if it was already set, the
+/// given format is returned back as an error.
+pub fn set_default_format(format: MeasurementFormat) -> Result<(), MeasurementFormat> {
+    DEFAULT_FORMAT.set(format)
+}
+
+/// Sets the format used by the `Display` implementation of
+/// measurements in the current thread, overriding the one of the
+/// program. Setting None removes the override.
+pub fn set_thread_format(format: Option<MeasurementFormat>) {
+    THREAD_FORMAT.with(|current| current.set(format));
+}
+
+/// Runs the given closure with the given format as the one used by
+/// the `Display` implementation of measurements in the current thread,
+/// restoring the previous one afterwards.
+pub fn with_thread_format<F: FnOnce() -> R, R>(format: MeasurementFormat, f: F) -> R {
+    /// Restores the previous format when dropped, even if the closure
+    /// panics.
+    struct RestoreGuard(Option<MeasurementFormat>);
+
+    impl Drop for RestoreGuard {
+        fn drop(&mut self) {
+            set_thread_format(self.0);
+        }
+    }
+
+    let _guard = RestoreGuard(THREAD_FORMAT.with(|current| current.replace(Some(format))));
+    f()
+}
+
+/// Returns the format used by the `Display` implementation of
+/// measurements in the current thread: the one of the thread if set,
+/// otherwise the one of the program, otherwise the default options.
+pub fn default_format() -> MeasurementFormat {
+    THREAD_FORMAT
+        .with(Cell::get)
+        .or_else(|| DEFAULT_FORMAT.get().copied())
+        .unwrap_or_default()
+}
+
+/// Error returned when a [`Template`] cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// The template contains a placeholder that is not supported.
+    UnknownPlaceholder(String),
+    /// A placeholder is opened but never closed.
+    UnclosedPlaceholder,
+    /// A closing brace is not paired with an opening one. Literal
+    /// braces must be escaped as "{{" and "}}".
+    UnmatchedBrace,
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::UnknownPlaceholder(placeholder) => {
+                write!(f, "unknown placeholder: {:?}", placeholder)
+            }
+            TemplateError::UnclosedPlaceholder => f.write_str("unclosed placeholder"),
+            TemplateError::UnmatchedBrace => f.write_str("unmatched closing brace"),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Value(Option<usize>),
+    Separator,
+    Symbol,
+    Name,
+}
+
+/// Template describing how to display measurements, such as
+/// "{value:.2}{sep}{symbol}" or "{value} {name}". The supported
+/// placeholders are:
+///
+/// - `{value}`: the numerical value, optionally with a precision
+///   (`{value:.2}`).
+/// - `{sep}`: the separator of the format, a space by default.
+/// - `{symbol}`: the symbol of the unit.
+/// - `{name}`: the long name of the unit, pluralized according to the
+///   value.
+///
+/// Literal braces are written as "{{" and "}}". The rest of the
+/// options, like the style of the symbols, are taken from the
+/// [`MeasurementFormat`] of the template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    segments: Vec<Segment>,
+    format: MeasurementFormat,
+}
+
+impl Template {
+    /// Parses the given template.
+    pub fn parse(template: &str) -> Result<Template, TemplateError> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace),
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => return Err(TemplateError::UnclosedPlaceholder),
+                        }
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Self::parse_placeholder(placeholder)?);
+                }
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+
+        Ok(Template {
+            segments,
+            format: MeasurementFormat::new(),
+        })
+    }
+
+    fn parse_placeholder(placeholder: String) -> Result<Segment, TemplateError> {
+        let precision = placeholder
+            .strip_prefix("value:.")
+            .and_then(|precision| precision.parse().ok());
+
+        match (placeholder.as_str(), precision) {
+            ("value", _) => Ok(Segment::Value(None)),
+            (_, Some(precision)) => Ok(Segment::Value(Some(precision))),
+            ("sep", _) => Ok(Segment::Separator),
+            ("symbol", _) => Ok(Segment::Symbol),
+            ("name", _) => Ok(Segment::Name),
+            _ => Err(TemplateError::UnknownPlaceholder(placeholder)),
+        }
+    }
+
+    /// Sets the format whose options are used for rendering the
+    /// placeholders.
+    pub fn with_format(mut self, format: MeasurementFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    fn write<T: Number>(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        value: T,
+        unit: &dyn UnitText,
+    ) -> std::fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => f.write_str(literal)?,
+                Segment::Value(precision) => self.format.write_value(f, value, *precision, unit)?,
+                Segment::Separator => f.write_str(self.format.separator)?,
+                Segment::Symbol => self.format.write_unit(f, value, unit, UnitStyle::Symbol)?,
+                Segment::Name => self.format.write_unit(f, value, unit, UnitStyle::Name)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::parse(s)
+    }
+}
+
+/// Texts describing a unit, either known at compile time or at runtime.
+trait UnitText {
+    fn symbol_in(&self, style: SymbolStyle) -> Cow<'static, str>;
+    fn name(&self) -> Cow<'static, str>;
+    fn plural_name(&self) -> Cow<'static, str>;
+    fn default_precision(&self) -> Option<usize>;
+}
+
+struct StaticText<U>(PhantomData<fn() -> U>);
+
+impl<U: MeasureUnit> UnitText for StaticText<U> {
+    fn symbol_in(&self, style: SymbolStyle) -> Cow<'static, str> {
+        symbols::symbol_of::<U>(style)
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        U::name()
+    }
+
+    fn plural_name(&self) -> Cow<'static, str> {
+        U::plural_name()
+    }
+
+    fn default_precision(&self) -> Option<usize> {
+        U::default_precision()
+    }
+}
+
+impl UnitText for &dyn DynUnit {
+    fn symbol_in(&self, style: SymbolStyle) -> Cow<'static, str> {
+        DynUnit::symbol_in(*self, style)
+    }
+
+    fn name(&self) -> Cow<'static, str> {
+        DynUnit::name(*self)
+    }
+
+    fn plural_name(&self) -> Cow<'static, str> {
+        DynUnit::plural_name(*self)
+    }
+
+    fn default_precision(&self) -> Option<usize> {
+        DynUnit::default_precision(*self)
+    }
+}
+
+/// Measurement displayed using a specific [`MeasurementFormat`].
+/// Created through [`Measurement::format`] or [`AnyMeasurement::format`].
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<M> {
+    measurement: M,
+    format: MeasurementFormat,
+}
+
+impl<U: MeasureUnit, T: Number> Display for Formatted<Measurement<U, T>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format
+            .write(f, self.measurement.value(), &StaticText::<U>(PhantomData))
+    }
+}
+
+impl Display for Formatted<AnyMeasurement> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format
+            .write(f, self.measurement.value(), &self.measurement.unit())
+    }
+}
+
+/// Measurement displayed using a [`Template`]. Created through
+/// [`Measurement::template`] or [`AnyMeasurement::template`].
+#[derive(Debug, Clone, Copy)]
+pub struct Templated<'a, M> {
+    measurement: M,
+    template: &'a Template,
+}
+
+impl<U: MeasureUnit, T: Number> Display for Templated<'_, Measurement<U, T>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.template
+            .write(f, self.measurement.value(), &StaticText::<U>(PhantomData))
+    }
+}
+
+impl Display for Templated<'_, AnyMeasurement> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.template
+            .write(f, self.measurement.value(), &self.measurement.unit())
+    }
+}
+
+/// Measurement displayed in two units at once, e.g "90 min (1.5 h)".
+/// Created through [`Measurement::with_secondary`]. The secondary unit
+/// is always displayed as a f64, so integers aren't rounded into it.
+pub struct WithSecondary<U, V, T = f64> {
+    primary: Measurement<U, T>,
+    secondary: Measurement<V>,
+    formats: Option<(MeasurementFormat, MeasurementFormat)>,
+}
+
+impl<U, V, T> WithSecondary<U, V, T> {
+    /// Sets the formats of the primary and secondary units. If not
+    /// set, both use the [default format](default_format).
+    pub fn formats(mut self, primary: MeasurementFormat, secondary: MeasurementFormat) -> Self {
+        self.formats = Some((primary, secondary));
+        self
+    }
+}
+
+impl<U: MeasureUnit, V: MeasureUnit, T: Number> Display for WithSecondary<U, V, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (primary, secondary) = self
+            .formats
+            .unwrap_or_else(|| (default_format(), default_format()));
+
+        Display::fmt(&self.primary.format(primary), f)?;
+        f.write_str(" (")?;
+        Display::fmt(&self.secondary.format(secondary), f)?;
+        f.write_str(")")
+    }
+}
+
+impl<U, T: Number> Measurement<U, T> {
+    /// Returns an object that displays this measurement both in its
+    /// own unit and in the unit V, e.g "1 GiB (1.07 GB)".
+    pub fn with_secondary<V: MeasureUnit>(self) -> WithSecondary<U, V, T>
+    where
+        U: MeasureUnit,
+        V::AliasedUnit: FromUnit<U>,
+    {
+        WithSecondary {
+            primary: self,
+            secondary: self.cast::<f64>().into_unit(),
+            formats: None,
+        }
+    }
+
+    /// Returns an object that displays this measurement using the
+    /// given format.
+    pub fn format(self, format: MeasurementFormat) -> Formatted<Self> {
+        Formatted {
+            measurement: self,
+            format,
+        }
+    }
+
+    /// Returns an object that displays this measurement using the
+    /// given template.
+    pub fn template(self, template: &Template) -> Templated<'_, Self> {
+        Templated {
+            measurement: self,
+            template,
+        }
+    }
+
+    /// Writes this measurement into the given writer, as its `Display`
+    /// implementation does, without going through a `String`. See
+    /// [`FormatBuffer`] for writing into a fixed buffer.
+    pub fn format_into<W: Write + ?Sized>(self, out: &mut W) -> fmt::Result
+    where
+        U: MeasureUnit,
+    {
+        write!(out, "{}", self)
+    }
+}
+
+impl AnyMeasurement {
+    /// Returns an object that displays this measurement using the
+    /// given format.
+    pub fn format(self, format: MeasurementFormat) -> Formatted<Self> {
+        Formatted {
+            measurement: self,
+            format,
+        }
+    }
+
+    /// Returns an object that displays this measurement using the
+    /// given template.
+    pub fn template(self, template: &Template) -> Templated<'_, Self> {
+        Templated {
+            measurement: self,
+            template,
+        }
+    }
+
+    /// Writes this measurement into the given writer, as its `Display`
+    /// implementation does, without going through a `String`.
+    pub fn format_into<W: Write + ?Sized>(self, out: &mut W) -> fmt::Result {
+        write!(out, "{}", self)
+    }
+}
+
+/// Fixed-size buffer of text implementing [`fmt::Write`], for
+/// formatting measurements without allocating, e.g in hot logging
+/// paths:
+///
+/// ```
+/// # #[cfg(feature = "time")] {
+/// use std::fmt::Write;
+/// use strong_units::{format::FormatBuffer, units::Millisecond, Measurement};
+///
+/// let mut buffer = FormatBuffer::<32>::new();
+/// write!(buffer, "took {:.1}", Measurement::<Millisecond>::new(12.34)).unwrap();
+/// assert_eq!("took 12.3 ms", buffer.as_str());
+/// # }
+/// ```
+///
+/// Writing text that doesn't fit fails, and the buffer may be left
+/// with the part of it written before reaching its capacity. Symbols
+/// of built-in units are written without allocating, as well as the
+/// ones of composed units once they are cached; long names and ASCII
+/// fallbacks may still allocate.
+#[derive(Clone, Copy)]
+pub struct FormatBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FormatBuffer<N> {
+    /// Creates an empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the text written so far.
+    pub fn as_str(&self) -> &str {
+        // Only whole strings are copied into the buffer.
+        std::str::from_utf8(&self.bytes[..self.len]).expect("the buffer holds valid UTF-8")
+    }
+
+    /// Returns the length in bytes of the text written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether nothing was written so far.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes the text written so far.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for FormatBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for FormatBuffer<N> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let end = self.len + text.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+
+        self.bytes[self.len..end].copy_from_slice(text.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FormatBuffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Display for FormatBuffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use std::fmt::Write;
+
+    use super::{
+        set_thread_format, with_thread_format, FormatBuffer, MeasurementFormat, Template,
+        TemplateError,
+    };
+    use crate::{
+        dynamic::AnyMeasurement,
+        parse::parse_any,
+        symbols::SymbolStyle,
+        units::{
+            Byte, Gibibyte, Gigabyte, Hour, Kibibyte, Mbps, Megabyte, Microsecond, Millisecond,
+            Minute, Second,
+        },
+        DivUnit, Measurement, PowUnit,
+    };
+
+    #[test]
+    fn test_long_names() {
+        let long = MeasurementFormat::long();
+
+        assert_eq!(
+            "1 hour",
+            Measurement::<Hour>::new(1.0).format(long).to_string()
+        );
+        assert_eq!(
+            "-1 hour",
+            Measurement::<Hour>::new(-1.0).format(long).to_string()
+        );
+        assert_eq!(
+            "0 hours",
+            Measurement::<Hour>::new(0.0).format(long).to_string()
+        );
+        assert_eq!(
+            "1.5 megabytes",
+            Measurement::<Megabyte>::new(1.5).format(long).to_string()
+        );
+        assert_eq!(
+            "2 megabits per second",
+            Measurement::<Mbps>::new(2.0).format(long).to_string()
+        );
+        assert_eq!(
+            "3 bytes per square second",
+            Measurement::<DivUnit<Byte, PowUnit<Second, 2>>>::new(3.0)
+                .format(long)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_options() {
+        let m = Measurement::<Microsecond>::new(1.0 / 3.0);
+        let format = MeasurementFormat::new().precision(2);
+
+        assert_eq!("0.33 µs", m.format(format).to_string());
+        assert_eq!(
+            "0.33 us",
+            m.format(format.symbol_style(SymbolStyle::Ascii))
+                .to_string()
+        );
+        assert_eq!(
+            "0.333 µs",
+            format!("{:.3}", m.format(MeasurementFormat::new()))
+        );
+        assert_eq!(
+            m.to_string(),
+            m.format(MeasurementFormat::new()).to_string()
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let compact = MeasurementFormat::compact();
+
+        assert_eq!(
+            "42KiB",
+            Measurement::<Kibibyte>::new(42.0)
+                .format(compact)
+                .to_string()
+        );
+        assert_eq!(
+            "3ms",
+            Measurement::<Millisecond>::new(3.0)
+                .format(compact)
+                .to_string()
+        );
+        assert_eq!(
+            "3\u{2009}ms",
+            Measurement::<Millisecond>::new(3.0)
+                .format(MeasurementFormat::new().separator("\u{2009}"))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let format = MeasurementFormat::new().precision(2).round_trip();
+        let values = [0.3, 0.1 + 0.2, 1.0 / 3.0, -2.5e-7, 1.5e20, 0.0, 12345.678];
+
+        for value in values {
+            let text = Measurement::<Second>::new(value).format(format).to_string();
+            let parsed = parse_any(&text).unwrap();
+            assert_eq!(value, parsed.value(), "{} didn't round-trip", text);
+        }
+
+        let format = |value| Measurement::<Second>::new(value).format(format).to_string();
+        assert_eq!("0.3 s", format(0.3));
+        assert_eq!("-2.5e-7 s", format(-2.5e-7));
+        assert_eq!("1.5e20 s", format(1.5e20));
+    }
+
+    #[test]
+    fn test_thread_format() {
+        let m = Measurement::<Megabyte>::new(1.0 / 3.0);
+        let compact = MeasurementFormat::compact().precision(1);
+
+        let (formatted, any) = with_thread_format(compact, || {
+            (m.to_string(), AnyMeasurement::from(m).to_string())
+        });
+        assert_eq!("0.3MB", formatted);
+        assert_eq!("0.3MB", any);
+        assert_eq!("0.3333333333333333 MB", m.to_string());
+
+        set_thread_format(Some(MeasurementFormat::long()));
+        assert_eq!("2 megabytes", Measurement::<Megabyte>::new(2.0).to_string());
+        set_thread_format(None);
+        assert_eq!("2 MB", Measurement::<Megabyte>::new(2.0).to_string());
+    }
+
+    #[test]
+    fn test_with_secondary() {
+        let minutes = Measurement::<Minute>::new(90.0);
+        assert_eq!(
+            "90 min (1.5 h)",
+            minutes.with_secondary::<Hour>().to_string()
+        );
+
+        let size = Measurement::<Gibibyte>::new(1.0).with_secondary::<Gigabyte>();
+        assert_eq!("1.00 GiB (1.07 GB)", format!("{:.2}", size));
+
+        let size = size.formats(
+            MeasurementFormat::new().precision(1),
+            MeasurementFormat::long().precision(2),
+        );
+        assert_eq!("1.0 GiB (1.07 gigabytes)", size.to_string());
+    }
+
+    #[test]
+    fn test_template() {
+        let m = Measurement::<Megabyte>::new(1.0 / 3.0);
+
+        let template = Template::parse("{value:.2}{sep}{symbol}").unwrap();
+        assert_eq!("0.33 MB", m.template(&template).to_string());
+
+        let template = template.with_format(MeasurementFormat::compact());
+        assert_eq!("0.33MB", m.template(&template).to_string());
+
+        let template: Template = "{{{value}}} {name}".parse().unwrap();
+        assert_eq!(
+            "{2} hours",
+            Measurement::<Hour>::new(2.0)
+                .template(&template)
+                .to_string()
+        );
+
+        let m: AnyMeasurement = Measurement::<Hour>::new(1.0).into();
+        assert_eq!("{1} hour", m.template(&template).to_string());
+    }
+
+    #[test]
+    fn test_template_errors() {
+        assert_eq!(
+            Err(TemplateError::UnknownPlaceholder("unit".to_owned())),
+            Template::parse("{value} {unit}")
+        );
+        assert_eq!(
+            Err(TemplateError::UnknownPlaceholder("value:.x".to_owned())),
+            Template::parse("{value:.x}")
+        );
+        assert_eq!(
+            Err(TemplateError::UnclosedPlaceholder),
+            Template::parse("{value")
+        );
+        assert_eq!(
+            Err(TemplateError::UnmatchedBrace),
+            Template::parse("value}")
+        );
+    }
+
+    #[test]
+    fn test_format_any() {
+        let m: AnyMeasurement = Measurement::<Hour>::new(2.5).into();
+        assert_eq!("2.5 hours", m.format(MeasurementFormat::long()).to_string());
+    }
+
+    #[test]
+    fn test_default_precision() {
+        crate::define_unit!(Dose, "dose", precision: 2);
+        crate::define_alias!(Dose as Shot, "shot");
+        crate::define_alias!(Dose as Sip, "sip", precision: 0);
+
+        let dose = Measurement::<Dose>::new(1.5);
+        assert_eq!("1.50 dose", dose.to_string());
+        assert_eq!("1.5 dose", format!("{:.1}", dose));
+        assert_eq!(
+            "1.500 dose",
+            dose.format(MeasurementFormat::new().precision(3))
+                .to_string()
+        );
+        assert_eq!(
+            "1.5 dose",
+            dose.format(MeasurementFormat::new().round_trip())
+                .to_string()
+        );
+        assert_eq!("2.25 shot", Measurement::<Shot>::new(2.25).to_string());
+        assert_eq!("2 sip", Measurement::<Sip>::new(2.25).to_string());
+        assert_eq!("1.5 s", Measurement::<Second>::new(1.5).to_string());
+    }
+
+    #[test]
+    fn test_format_into() {
+        let mut buffer = FormatBuffer::<16>::new();
+        Measurement::<Mbps>::new(2.5)
+            .format_into(&mut buffer)
+            .unwrap();
+        assert_eq!("2.5 Mbps", buffer.as_str());
+
+        write!(buffer, " / {:.0}", Measurement::<Second>::new(1.25)).unwrap();
+        assert_eq!("2.5 Mbps / 1 s", buffer.as_str());
+
+        assert!(Measurement::<Second>::new(1.0)
+            .format_into(&mut buffer)
+            .is_err());
+        assert!(buffer.as_str().starts_with("2.5 Mbps / 1 s"));
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        let mut text = String::new();
+        Measurement::<DivUnit<Megabyte, Second>>::new(1.0)
+            .format_into(&mut text)
+            .unwrap();
+        assert_eq!("1 MB/s", text);
+    }
+}
@@ -0,0 +1,130 @@
+//! Instrumentation of HTTP bodies, as used by hyper, reqwest or axum,
+//! measuring the size and the rate of the data sent or received
+//! through them.
+//!
+//! ```
+//! use futures::executor::block_on;
+//! use http_body_util::{BodyExt, Full};
+//! use strong_units::http::MeasuredBody;
+//!
+//! let body = MeasuredBody::new(Full::new(&b"hello world"[..]));
+//! let handle = body.handle();
+//! assert_eq!(Some(11.0), body.expected_size().map(|size| size.value()));
+//!
+//! block_on(body.collect()).unwrap();
+//! assert_eq!(11.0, handle.total().value());
+//! ```
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Buf;
+use http_body::{Body, Frame, SizeHint};
+use pin_project_lite::pin_project;
+
+use crate::{meter::ThroughputHandle, units::Byte, Measurement};
+
+pin_project! {
+    /// Body that records the size of the data frames of another one
+    /// into a [`ThroughputHandle`], as they are polled.
+    #[derive(Debug)]
+    pub struct MeasuredBody<B> {
+        #[pin]
+        body: B,
+        handle: ThroughputHandle,
+    }
+}
+
+impl<B> MeasuredBody<B> {
+    /// Wraps the given body, recording into a new handle.
+    pub fn new(body: B) -> Self {
+        Self::with_handle(body, ThroughputHandle::new())
+    }
+
+    /// Wraps the given body, recording into the given handle, e.g one
+    /// shared by all the bodies of a connection.
+    pub fn with_handle(body: B, handle: ThroughputHandle) -> Self {
+        Self { body, handle }
+    }
+
+    /// Returns a handle to the throughput of the body, which can be
+    /// kept after the body is handed to the HTTP client or server.
+    pub fn handle(&self) -> ThroughputHandle {
+        self.handle.clone()
+    }
+
+    /// Returns the underlying body.
+    pub fn into_inner(self) -> B {
+        self.body
+    }
+}
+
+impl<B: Body> MeasuredBody<B> {
+    /// Returns the size of the body, if known in advance, e.g from its
+    /// `Content-Length`.
+    pub fn expected_size(&self) -> Option<Measurement<Byte>> {
+        self.body
+            .size_hint()
+            .exact()
+            .map(|size| Measurement::new(size as f64))
+    }
+}
+
+impl<B: Body> Body for MeasuredBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = this.body.poll_frame(cx);
+
+        if let Poll::Ready(Some(Ok(frame))) = &frame {
+            if let Some(data) = frame.data_ref() {
+                this.handle
+                    .record(Measurement::<Byte>::new(data.remaining() as f64));
+            }
+        }
+
+        frame
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.body.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use http_body_util::{BodyExt, StreamBody};
+
+    use super::MeasuredBody;
+    use crate::{meter::ThroughputHandle, units::Kilobyte};
+
+    #[test]
+    fn test_measured_body() {
+        let frames = vec![
+            Ok::<_, ()>(http_body::Frame::data(&[0u8; 1500][..])),
+            Ok(http_body::Frame::trailers(Default::default())),
+            Ok(http_body::Frame::data(&[0u8; 500][..])),
+        ];
+        let handle = ThroughputHandle::new();
+        let body = MeasuredBody::with_handle(
+            StreamBody::new(futures::stream::iter(frames)),
+            handle.clone(),
+        );
+
+        assert_eq!(None, body.expected_size());
+        block_on(body.collect()).unwrap();
+        assert_eq!(2.0, handle.total().into_unit::<Kilobyte>().value());
+    }
+}
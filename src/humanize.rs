@@ -0,0 +1,86 @@
+//! Selection of the most readable unit for displaying a measurement.
+
+use crate::{
+    dynamic::{dyn_unit, AnyMeasurement, DynUnit},
+    BaseConversion, MeasureUnit, Measurement,
+};
+
+/// Expresses the given measurement in the largest of the given units
+/// in which its absolute value is still at least 1, falling back to
+/// the smallest one otherwise. The units must be sorted from smallest
+/// to largest, and measure the same quantity as the measurement.
+pub fn humanize(measurement: AnyMeasurement, units: &[&'static dyn DynUnit]) -> AnyMeasurement {
+    let base = measurement.base_value();
+    let unit = units
+        .iter()
+        .rev()
+        .find(|unit| unit.value_from_base(base).abs() >= 1.0)
+        .or_else(|| units.first());
+
+    match unit {
+        Some(unit) => AnyMeasurement::new(unit.value_from_base(base), *unit),
+        None => measurement,
+    }
+}
+
+impl<U: MeasureUnit + 'static> Measurement<U>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    /// Expresses the current measurement in the most readable of the
+    /// given units. See [`humanize`].
+    pub fn humanize(self, units: &[&'static dyn DynUnit]) -> AnyMeasurement {
+        humanize(AnyMeasurement::new(self.value(), dyn_unit::<U>()), units)
+    }
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use crate::{
+        units::{
+            Bps, Byte, Kibibyte, Microsecond, Nanosecond, BINARY_BYTE_UNITS, BYTE_UNITS,
+            RATE_UNITS, TIME_UNITS,
+        },
+        Measurement,
+    };
+
+    #[test]
+    fn test_humanize() {
+        let size = Measurement::<Byte>::new(1_500_000.0);
+
+        assert_eq!("1.50 MB", format!("{:.2}", size.humanize(BYTE_UNITS)));
+        assert_eq!(
+            "1.43 MiB",
+            format!("{:.2}", size.humanize(BINARY_BYTE_UNITS))
+        );
+        assert_eq!(
+            "1.5 Mbps",
+            Measurement::<Bps>::new(1_500_000.0)
+                .humanize(RATE_UNITS)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_humanize_small_values() {
+        assert_eq!(
+            "500 ns",
+            format!(
+                "{:.0}",
+                Measurement::<Microsecond>::new(0.5).humanize(TIME_UNITS)
+            )
+        );
+        assert_eq!(
+            "0.5 ns",
+            Measurement::<Nanosecond>::new(0.5)
+                .humanize(TIME_UNITS)
+                .to_string()
+        );
+        assert_eq!(
+            "-2 KiB",
+            Measurement::<Kibibyte>::new(-2.0)
+                .humanize(BINARY_BYTE_UNITS)
+                .to_string()
+        );
+    }
+}
@@ -1,39 +1,206 @@
-#![feature(associated_type_defaults)]
-#![feature(auto_traits)]
-#![feature(negative_impls)]
+#![cfg_attr(feature = "nightly", feature(associated_type_defaults))]
+
+// Lets the code generated by the derive macros, which refers to
+// `::strong_units`, be used inside this crate too.
+extern crate self as strong_units;
 
 mod macros;
-mod type_logic;
 
+/// Crates used by the exported macros.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
+#[cfg(feature = "time")]
+pub mod backoff;
+#[cfg(feature = "count")]
+pub mod bench;
+pub mod bounded;
+#[cfg(all(feature = "time", feature = "data"))]
+pub mod capacity;
+#[cfg(feature = "rate")]
+mod codegen;
+#[cfg(feature = "data")]
+pub mod compression;
+#[cfg(feature = "serde")]
+pub mod config;
+pub mod convert;
+pub mod dimension;
+pub mod dynamic;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fixed;
+pub mod format;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod humanize;
+#[cfg(feature = "nalgebra")]
+pub mod linalg;
+#[cfg(feature = "time")]
+pub mod meter;
+#[cfg(feature = "rate")]
+pub mod network;
+pub mod parse;
+pub mod prelude;
+#[cfg(feature = "python")]
+mod python;
+pub mod quantile;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(feature = "time")]
+pub mod series;
+pub mod settings;
+pub mod sort;
+#[cfg(feature = "time")]
+pub mod stopwatch;
+#[cfg(feature = "futures")]
+pub mod stream;
+pub mod summary;
+pub mod symbols;
+pub mod testing;
+#[cfg(feature = "time")]
+pub mod throttle;
+#[cfg(feature = "time")]
+pub mod ticker;
+#[cfg(feature = "tower")]
+pub mod tower;
+#[cfg(feature = "trace")]
+pub mod trace;
+pub mod type_logic;
 pub mod units;
+pub mod vector;
+#[cfg(kani)]
+mod verification;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 pub use macros::*;
 
 use std::{
+    any::TypeId,
     borrow::Cow,
-    fmt::Display,
+    fmt::{Debug, Display, LowerExp},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
+use dimension::SameDimension;
+use symbols::SymbolStyle;
+use type_logic::{
+    Compare, CompareNumerator, Comparison, DimensionId, Kind, NumeratorComparison, UnitProduct,
+    UnitQuotient,
+};
+
+/// Declares an associated type of a trait, with the given default only
+/// when the `nightly` feature is enabled, since defaults of associated
+/// types are not stable yet.
+macro_rules! associated_type {
+    ($(#[$attr:meta])* type $id:ident $(: $bound:path)? = $default:ty;) => {
+        $(#[$attr])*
+        #[cfg(feature = "nightly")]
+        type $id $(: $bound)? = $default;
+
+        $(#[$attr])*
+        #[cfg(not(feature = "nightly"))]
+        type $id $(: $bound)?;
+    };
+}
+
 /// Reprensents a measure unit of a physical magnitude.
-pub trait MeasureUnit: Sized {
-    /// Represents the Unit type that Self is an alias of.
-    /// By default is assigned to Self, effectively indicating that
-    /// the current unit is not an alias of any other unit.
-    ///
-    /// E. g if the current instance represents the unit "Gbps", it
-    /// would be an alias of Div<Gigabit, Second>, and therefore
-    /// `AliasedUnit = Div<Gigabit, Second>`.
-    type AliasedUnit: MeasureUnit = Self;
+///
+/// Units are usually defined through `define_unit!` or `define_alias!`.
+/// Units implementing it by hand must set all its associated types,
+/// unless the `nightly` feature is enabled, which gives them defaults
+/// for plain units.
+pub trait MeasureUnit: Sized + 'static {
+    associated_type! {
+        /// Represents the Unit type that Self is an alias of. Assigning
+        /// it to Self, which is the default with the `nightly` feature,
+        /// indicates that the current unit is not an alias of any other
+        /// unit.
+        ///
+        /// E. g if the current instance represents the unit "Gbps", it
+        /// would be an alias of Div<Gigabit, Second>, and therefore
+        /// `AliasedUnit = Div<Gigabit, Second>`.
+        ///
+        /// It must be the [canonical](Canonical) form of the unit: aliases
+        /// of aliases point to the unit at the end of the chain, and
+        /// composed units are composed of the canonical forms of their
+        /// parts. Units defined through `define_alias!` and the composed
+        /// units of this crate take care of it.
+        type AliasedUnit: MeasureUnit = Self;
+    }
+
+    associated_type! {
+        /// Whether the unit is a [quotient](QuotientUnit) of two units,
+        /// which determines the unit of the product of its measurements
+        /// with others. Only quotient units set it to
+        /// [`type_logic::Quotient`], and the rest to [`type_logic::Plain`],
+        /// which is the default with the `nightly` feature.
+        type Kind = type_logic::Plain;
+    }
+
+    associated_type! {
+        /// [Dimension](dimension) of the quantity measured by this unit,
+        /// e.g `dimension::Time` for seconds. Conversions are only possible
+        /// between units of the same dimension. Composed units take it from
+        /// the units they are composed of, and aliases from the unit they
+        /// stand for. Units of no dimension in particular set it to
+        /// `dimension::Unspecified`, which is the default with the `nightly`
+        /// feature.
+        type Dimension: dimension::Dimension = dimension::Unspecified;
+    }
 
     /// Returns the symbol that accompanies the value of this unit
     /// when printed, and identifies it.
     fn symbol() -> Cow<'static, str>;
+
+    /// Returns the ASCII fallback of the symbol of this unit. By
+    /// default, it is transliterated from the symbol.
+    fn ascii_symbol() -> Cow<'static, str> {
+        let symbol = Self::symbol();
+        match symbols::to_ascii(&symbol) {
+            Cow::Borrowed(_) => symbol,
+            Cow::Owned(ascii) => ascii.into(),
+        }
+    }
+
+    /// Returns the long name of this unit in singular, e.g "hour". By
+    /// default, it is the symbol of the unit.
+    fn name() -> Cow<'static, str> {
+        Self::symbol()
+    }
+
+    /// Returns the long name of this unit in plural, e.g "hours". By
+    /// default, it is the name followed by an "s", unless the unit has
+    /// no name other than its symbol.
+    fn plural_name() -> Cow<'static, str> {
+        let name = Self::name();
+        if name == Self::symbol() {
+            name
+        } else {
+            [&name, "s"].concat().into()
+        }
+    }
+
+    /// Returns the number of decimal digits values of this unit are
+    /// displayed with when no precision is given, neither by the
+    /// [format](format::MeasurementFormat::precision) nor by the
+    /// formatter (as in `{:.2}`). By default, there is none, and values
+    /// are displayed with as many digits as needed.
+    fn default_precision() -> Option<usize> {
+        None
+    }
 }
 
-/// Trait that defines conversions between measurements of different units.
-pub trait FromUnit<U>: Sized {
+/// Trait that defines conversions between measurements of different
+/// units. Both units must have the same [dimension](MeasureUnit::Dimension).
+pub trait FromUnit<U: MeasureUnit>: SameDimension<U> {
     fn from_value(input: Measurement<U>) -> Measurement<Self>;
 }
 /// Marker trait that indicates that a relationship of a unit U with
@@ -41,7 +208,357 @@ pub trait FromUnit<U>: Sized {
 /// responsability of the developer to ensure that the underlying
 /// implementation of FromUnit ensures so. (Probably there's a better
 /// way to do this, but choosing this one from now as it is simple.)
-pub unsafe trait FromUnitLinear<U>: FromUnit<U> {}
+pub unsafe trait FromUnitLinear<U: MeasureUnit>: FromUnit<U> {}
+
+/// Implemented by units that are related linearly with the base unit
+/// of the quantity they measure, which is the one whose factor is 1
+/// (e. g Second for time, or Bit for data).
+pub trait LinearUnit: MeasureUnit {
+    /// Factor that converts a value expressed in this unit into the
+    /// base unit of its quantity.
+    const FACTOR_TO_BASE: f64;
+
+    /// Returns the name of the quantity measured by this unit.
+    fn quantity() -> Cow<'static, str>;
+}
+
+/// Implemented by units related with the base unit of the quantity
+/// they measure by an affine function, `base = SCALE * value + OFFSET`,
+/// like degrees Celsius with kelvins. They are defined through
+/// [`define_affine_conversions!`]. As the zero of these units doesn't
+/// mean the absence of the quantity, they can't be converted when
+/// composed into other units:
+///
+/// ```compile_fail
+/// use strong_units::{
+///     define_affine_conversions, define_unit, units::Second, DivUnit, Measurement,
+/// };
+///
+/// define_unit!(Kelvin, "K");
+/// define_unit!(Celsius, "°C");
+///
+/// define_affine_conversions! {
+///     quantity: "temperature";
+///
+///     (Kelvin, 1, 0),
+///     (Celsius, 1, 273.15)
+/// }
+///
+/// Measurement::<DivUnit<Celsius, Second>>::new(1.0).into_unit::<DivUnit<Kelvin, Second>>();
+/// ```
+pub trait AffineUnit: MeasureUnit {
+    /// Factor of the function that converts a value expressed in this
+    /// unit into the base unit of its quantity.
+    const SCALE: f64;
+
+    /// Offset of the function that converts a value expressed in this
+    /// unit into the base unit of its quantity.
+    const OFFSET: f64;
+
+    /// Returns the name of the quantity measured by this unit.
+    fn quantity() -> Cow<'static, str>;
+}
+
+/// Implemented by the units whose values can be converted into the
+/// base unit of the quantity they measure, which are both the
+/// [linear](LinearUnit) and the [affine](AffineUnit) ones, so any of
+/// them can be handled at runtime through
+/// [`DynUnit`](dynamic::DynUnit).
+pub trait BaseConversion: MeasureUnit {
+    /// Returns the name of the quantity measured by this unit.
+    fn quantity_name() -> Cow<'static, str>;
+
+    /// Converts a value expressed in this unit into the base unit of
+    /// its quantity.
+    fn to_base(value: f64) -> f64;
+
+    /// Converts a value expressed in the base unit of the quantity
+    /// into this unit.
+    fn from_base(value: f64) -> f64;
+}
+
+impl<U: LinearUnit> BaseConversion for U {
+    fn quantity_name() -> Cow<'static, str> {
+        U::quantity()
+    }
+
+    #[inline]
+    fn to_base(value: f64) -> f64 {
+        value * U::FACTOR_TO_BASE
+    }
+
+    #[inline]
+    fn from_base(value: f64) -> f64 {
+        value / U::FACTOR_TO_BASE
+    }
+}
+
+/// Implemented by the linear units defined through
+/// [`define_linear_conversions!`], which are converted into any other
+/// unit of the same family through their factors to the base unit, by
+/// a single generic implementation instead of one per pair of units.
+///
+/// ```
+/// use strong_units::{define_linear_conversions, define_unit, Measurement};
+///
+/// define_unit!(Spoon, "tbsp");
+/// define_unit!(Cup, "cup");
+/// define_unit!(Pinch, "pinch");
+///
+/// define_linear_conversions! {
+///     quantity: "cooking volume";
+///
+///     (Spoon, 1),
+///     (Cup, 16 Spoon)
+/// }
+///
+/// define_linear_conversions! {
+///     quantity: "cooking volume";
+///     extends: Spoon;
+///
+///     (Pinch, 0.0625 Spoon)
+/// }
+///
+/// assert_eq!(256.0, Measurement::<Cup>::new(1.0).into_unit::<Pinch>().value());
+/// ```
+pub trait ScaledUnit: LinearUnit {
+    /// Unit identifying the family of units this one is converted
+    /// between, the first one defined along with them.
+    type Family: 'static;
+}
+
+/// Factor that converts values of the unit U into V, computed at
+/// compile time so conversions are just one multiplication.
+struct ScaleFactor<U, V>(PhantomData<(U, V)>);
+
+impl<U: LinearUnit, V: LinearUnit> ScaleFactor<U, V> {
+    const VALUE: f64 = U::FACTOR_TO_BASE / V::FACTOR_TO_BASE;
+}
+
+impl<U: ScaledUnit, V: ScaledUnit<Family = U::Family>> FromUnit<U> for V
+where
+    V: SameDimension<U>,
+{
+    #[inline]
+    fn from_value(input: Measurement<U>) -> Measurement<Self> {
+        Measurement::new(input.value() * ScaleFactor::<U, V>::VALUE)
+    }
+}
+
+unsafe impl<U: ScaledUnit, V: ScaledUnit<Family = U::Family>> FromUnitLinear<U> for V where
+    V: SameDimension<U>
+{
+}
+
+/// Implemented by units that are the quotient of two other units,
+/// giving access to both of them.
+pub trait QuotientUnit: MeasureUnit {
+    type Numerator: MeasureUnit;
+    type Denominator: MeasureUnit;
+}
+
+/// Numeric types that measurements can be multiplied and divided by.
+pub trait Scalar: Copy {
+    /// Converts the scalar into a f64, possibly losing precision.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Numeric types that can hold the value of a measurement: `f64` by
+/// default, `f32` where it is cheaper, or integers for exact
+/// accounting, e.g `Measurement<Byte, u64>`.
+///
+/// Conversions between units that aren't the same unit go through
+/// `f64`, so they may lose precision for integers beyond 2^53, and
+/// round integers to the nearest value.
+pub trait Number:
+    Scalar
+    + PartialEq
+    + PartialOrd
+    + Default
+    + Debug
+    + Display
+    + LowerExp
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Converts the given f64 into this type. Integers are rounded to
+    /// the nearest value and saturated to their range, and NaN is
+    /// turned into zero.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts the given f64 into this type, returning None if it
+    /// isn't finite, or if it is out of the range of the type.
+    fn checked_from_f64(value: f64) -> Option<Self>;
+
+    /// Adds two values, returning None if the result overflows for
+    /// integers, or isn't finite for floats.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtracts two values, returning None if the result overflows
+    /// for integers, or isn't finite for floats.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies the value by the fraction num/den, as done when
+    /// [rescaling](Measurement::rescale) values between linear units.
+    /// Integers and [fixed-point](fixed::Fixed) numbers do it with
+    /// integer arithmetic, rounding to the nearest value and saturating.
+    fn mul_ratio(self, num: u64, den: u64) -> Self;
+}
+
+/// Multiplies the given value by the fraction num/den, rounding half
+/// away from zero, or returns None if it overflows.
+fn mul_ratio_i128(value: i128, num: u64, den: u64) -> Option<i128> {
+    let product = value.checked_mul(i128::from(num))?;
+    let half = i128::from(den / 2);
+    let rounded = if product < 0 {
+        product.checked_sub(half)?
+    } else {
+        product.checked_add(half)?
+    };
+
+    Some(rounded / i128::from(den))
+}
+
+/// Integer types that can hold the value of a measurement, which
+/// support saturating and wrapping arithmetic.
+pub trait Integer: Number + Eq + Ord {
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_float_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0.0;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                #[inline]
+                fn checked_from_f64(value: f64) -> Option<Self> {
+                    Some(value as $t).filter(|value| value.is_finite())
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs).filter(|value| value.is_finite())
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    Some(self - rhs).filter(|value| value.is_finite())
+                }
+
+                #[inline]
+                fn mul_ratio(self, num: u64, den: u64) -> Self {
+                    self * (num as $t / den as $t)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_integer_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                const ZERO: Self = 0;
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $t
+                }
+
+                #[inline]
+                fn checked_from_f64(value: f64) -> Option<Self> {
+                    // The upper bound is exclusive, as MAX as f64 rounds
+                    // up past the range of the wider types.
+                    let value = value.round();
+                    (value >= <$t>::MIN as f64 && value < <$t>::MAX as f64 + 1.0)
+                        .then(|| value as $t)
+                }
+
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                #[inline]
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                #[inline]
+                fn mul_ratio(self, num: u64, den: u64) -> Self {
+                    // Only the widest integers may overflow i128, falling
+                    // back to f64 then.
+                    match i128::try_from(self).ok().and_then(|value| mul_ratio_i128(value, num, den)) {
+                        Some(value) => <$t>::try_from(value)
+                            .unwrap_or(if value < 0 { <$t>::MIN } else { <$t>::MAX }),
+                        None => Self::from_f64(self.to_f64() * (num as f64 / den as f64)),
+                    }
+                }
+            }
+
+            impl Integer for $t {
+                #[inline]
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$t>::saturating_add(self, rhs)
+                }
+
+                #[inline]
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    <$t>::saturating_sub(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                #[inline]
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_number!(f32, f64);
+impl_integer_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
 
 /// Represents a complex unit that is composed of a unit divided by another.
 /// E. g if the units Kilometer and Hour are already defined, the unit
@@ -51,14 +568,35 @@ pub struct DivUnit<N, D> {
     _d: PhantomData<D>,
 }
 
+/// Represents a complex unit that is composed of a unit multiplied by
+/// another. E. g if the units Newton and Meter are already defined, the
+/// unit of torque N·m can be defined with the unit `MulUnit<Newton, Meter>`.
+pub struct MulUnit<L, R> {
+    _l: PhantomData<L>,
+    _r: PhantomData<R>,
+}
+
+/// Represents the inverse of a unit, e.g `ReciprocalUnit<Second>` is
+/// the unit of frequency 1/s. It is the result of dividing a scalar by
+/// a measurement.
+pub struct ReciprocalUnit<U> {
+    _u: PhantomData<U>,
+}
+
+/// Represents a unit raised to the power N, e.g `PowUnit<Meter, 2>`
+/// is the unit of area m².
+pub struct PowUnit<U, const N: i32> {
+    _u: PhantomData<U>,
+}
+
 /// Represents the value of a physical property, measured using the
-/// unit U.
-pub struct Measurement<U> {
+/// unit U, held by the numeric type T (see [`Number`]).
+pub struct Measurement<U, T = f64> {
     _marker: PhantomData<U>,
-    value: f64,
+    value: T,
 }
 
-impl<U: MeasureUnit> std::fmt::Debug for Measurement<U> {
+impl<U: MeasureUnit, T: Debug> std::fmt::Debug for Measurement<U, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct(&format!("Measurement<{}>", &U::symbol()))
             .field("value", &self.value)
@@ -66,9 +604,9 @@ impl<U: MeasureUnit> std::fmt::Debug for Measurement<U> {
     }
 }
 
-impl<U> Copy for Measurement<U> {}
+impl<U, T: Copy> Copy for Measurement<U, T> {}
 
-impl<U> Clone for Measurement<U> {
+impl<U, T: Clone> Clone for Measurement<U, T> {
     fn clone(&self) -> Self {
         Self {
             _marker: self._marker.clone(),
@@ -77,7 +615,7 @@ impl<U> Clone for Measurement<U> {
     }
 }
 
-impl<U> Default for Measurement<U> {
+impl<U, T: Default> Default for Measurement<U, T> {
     fn default() -> Self {
         Self {
             _marker: Default::default(),
@@ -86,153 +624,919 @@ impl<U> Default for Measurement<U> {
     }
 }
 
+/// Canonical form of the unit U, that is, the unit that aliases
+/// resolve into: `Canonical<Gbps>` is `DivUnit<Gigabit, Second>`, and
+/// `Canonical<MulUnit<Gbps, Second>>` is
+/// `MulUnit<DivUnit<Gigabit, Second>, Second>`. Conversions,
+/// arithmetic and comparisons between measurements are resolved
+/// through the canonical form of their units, so aliases behave
+/// exactly as the units they stand for, while keeping their own
+/// symbols when displayed.
+pub type Canonical<U> = <U as MeasureUnit>::AliasedUnit;
+
 impl<N: MeasureUnit, D: MeasureUnit> MeasureUnit for DivUnit<N, D> {
+    type AliasedUnit = DivUnit<Canonical<N>, Canonical<D>>;
+    type Kind = type_logic::Quotient;
+    type Dimension = dimension::Quotient<N::Dimension, D::Dimension>;
+
     fn symbol() -> Cow<'static, str> {
-        [&N::symbol(), "/", &D::symbol()].concat().into()
+        symbols::composed_symbol::<Self>(SymbolStyle::Unicode, || {
+            [&N::symbol(), "/", &D::symbol()].concat()
+        })
+    }
+
+    fn ascii_symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Ascii, || {
+            [&N::ascii_symbol(), "/", &D::ascii_symbol()].concat()
+        })
+    }
+
+    fn name() -> Cow<'static, str> {
+        [&N::name(), " per ", &D::name()].concat().into()
+    }
+
+    fn plural_name() -> Cow<'static, str> {
+        [&N::plural_name(), " per ", &D::name()].concat().into()
+    }
+}
+
+impl<N: MeasureUnit, D: MeasureUnit> QuotientUnit for DivUnit<N, D> {
+    type Numerator = N;
+    type Denominator = D;
+}
+
+impl<N: LinearUnit, D: LinearUnit> LinearUnit for DivUnit<N, D> {
+    const FACTOR_TO_BASE: f64 = N::FACTOR_TO_BASE / D::FACTOR_TO_BASE;
+
+    fn quantity() -> Cow<'static, str> {
+        [&N::quantity(), "/", &D::quantity()].concat().into()
     }
 }
 
 impl<N: MeasureUnit, D: MeasureUnit, N1: MeasureUnit, D1: MeasureUnit> FromUnit<DivUnit<N, D>>
     for DivUnit<N1, D1>
 where
+    Self: SameDimension<DivUnit<N, D>>,
     N1: FromUnitLinear<N>,
     D1: FromUnitLinear<D>,
 {
+    #[inline]
     fn from_value(input: Measurement<DivUnit<N, D>>) -> Measurement<Self> {
-        let n = Measurement::<N>::new(input.value());
-        let n1 = N1::from_value(n);
-        let div = D1::from_value(Measurement::<D>::new(1.0));
-        Measurement::new(n1.value() / div.value())
+        // Both factors are constants once inlined, so the whole
+        // conversion folds into a single multiplication.
+        let n_factor = N1::from_value(Measurement::<N>::new(1.0)).value();
+        let d_factor = D1::from_value(Measurement::<D>::new(1.0)).value();
+        Measurement::new(input.value() * (n_factor / d_factor))
     }
 }
 
-impl<U> Measurement<U> {
-    /// Creates a new measurement from the given numerical value.
-    pub fn new(value: f64) -> Measurement<U> {
+unsafe impl<N: MeasureUnit, D: MeasureUnit, N1: MeasureUnit, D1: MeasureUnit>
+    FromUnitLinear<DivUnit<N, D>> for DivUnit<N1, D1>
+where
+    Self: SameDimension<DivUnit<N, D>>,
+    N1: FromUnitLinear<N>,
+    D1: FromUnitLinear<D>,
+{
+}
+
+impl<L: MeasureUnit, R: MeasureUnit> MeasureUnit for MulUnit<L, R> {
+    type AliasedUnit = MulUnit<Canonical<L>, Canonical<R>>;
+    type Kind = type_logic::Plain;
+    type Dimension = dimension::Product<L::Dimension, R::Dimension>;
+
+    fn symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Unicode, || {
+            [&L::symbol(), "·", &R::symbol()].concat()
+        })
+    }
+
+    fn ascii_symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Ascii, || {
+            [&L::ascii_symbol(), "*", &R::ascii_symbol()].concat()
+        })
+    }
+
+    fn name() -> Cow<'static, str> {
+        [&L::name(), " ", &R::name()].concat().into()
+    }
+
+    fn plural_name() -> Cow<'static, str> {
+        [&L::name(), " ", &R::plural_name()].concat().into()
+    }
+}
+
+impl<L: LinearUnit, R: LinearUnit> LinearUnit for MulUnit<L, R> {
+    const FACTOR_TO_BASE: f64 = L::FACTOR_TO_BASE * R::FACTOR_TO_BASE;
+
+    fn quantity() -> Cow<'static, str> {
+        [&L::quantity(), "·", &R::quantity()].concat().into()
+    }
+}
+
+impl<L: MeasureUnit, R: MeasureUnit, L1: MeasureUnit, R1: MeasureUnit> FromUnit<MulUnit<L, R>>
+    for MulUnit<L1, R1>
+where
+    Self: SameDimension<MulUnit<L, R>>,
+    L1: FromUnitLinear<L>,
+    R1: FromUnitLinear<R>,
+{
+    #[inline]
+    fn from_value(input: Measurement<MulUnit<L, R>>) -> Measurement<Self> {
+        let l_factor = L1::from_value(Measurement::<L>::new(1.0)).value();
+        let r_factor = R1::from_value(Measurement::<R>::new(1.0)).value();
+        Measurement::new(input.value() * (l_factor * r_factor))
+    }
+}
+
+unsafe impl<L: MeasureUnit, R: MeasureUnit, L1: MeasureUnit, R1: MeasureUnit>
+    FromUnitLinear<MulUnit<L, R>> for MulUnit<L1, R1>
+where
+    Self: SameDimension<MulUnit<L, R>>,
+    L1: FromUnitLinear<L>,
+    R1: FromUnitLinear<R>,
+{
+}
+
+impl<U: MeasureUnit> MeasureUnit for ReciprocalUnit<U> {
+    type AliasedUnit = ReciprocalUnit<Canonical<U>>;
+    type Kind = type_logic::Plain;
+    type Dimension = dimension::Reciprocal<U::Dimension>;
+
+    fn symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Unicode, || ["1/", &U::symbol()].concat())
+    }
+
+    fn ascii_symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Ascii, || ["1/", &U::ascii_symbol()].concat())
+    }
+
+    fn name() -> Cow<'static, str> {
+        ["per ", &U::name()].concat().into()
+    }
+}
+
+impl<U: LinearUnit> LinearUnit for ReciprocalUnit<U> {
+    const FACTOR_TO_BASE: f64 = 1.0 / U::FACTOR_TO_BASE;
+
+    fn quantity() -> Cow<'static, str> {
+        ["1/", &U::quantity()].concat().into()
+    }
+}
+
+impl<U: MeasureUnit, U1: MeasureUnit> FromUnit<ReciprocalUnit<U>> for ReciprocalUnit<U1>
+where
+    Self: SameDimension<ReciprocalUnit<U>>,
+    U1: FromUnitLinear<U>,
+{
+    #[inline]
+    fn from_value(input: Measurement<ReciprocalUnit<U>>) -> Measurement<Self> {
+        let factor = U1::from_value(Measurement::<U>::new(1.0)).value();
+        Measurement::new(input.value() / factor)
+    }
+}
+
+unsafe impl<U: MeasureUnit, U1: MeasureUnit> FromUnitLinear<ReciprocalUnit<U>>
+    for ReciprocalUnit<U1>
+where
+    Self: SameDimension<ReciprocalUnit<U>>,
+    U1: FromUnitLinear<U>,
+{
+}
+
+/// Returns the fraction closest to x with a numerator and denominator
+/// that fit in u64, for positive values of x. It is the first
+/// convergent of the continued fraction of x that is equal to it as
+/// f64, so factors like 0.001 give 1/1000.
+const fn to_fraction(x: f64) -> (u64, u64) {
+    const fn mul_add(a: u64, b: u64, c: u64) -> Option<u64> {
+        match a.checked_mul(b) {
+            Some(product) => product.checked_add(c),
+            None => None,
+        }
+    }
+
+    let (mut num, mut prev_num) = (1, 0);
+    let (mut den, mut prev_den) = (0, 1);
+    let mut rest = x;
+    let mut i = 0;
+
+    while i < 64 {
+        let term = rest as u64;
+        let (next_num, next_den) =
+            match (mul_add(term, num, prev_num), mul_add(term, den, prev_den)) {
+                (Some(next_num), Some(next_den)) => (next_num, next_den),
+                _ => break,
+            };
+
+        (prev_num, num) = (num, next_num);
+        (prev_den, den) = (den, next_den);
+
+        let fraction = rest - term as f64;
+        if num as f64 / den as f64 == x || fraction == 0.0 {
+            break;
+        }
+
+        rest = 1.0 / fraction;
+        i += 1;
+    }
+
+    (num, den)
+}
+
+/// Ratio between the units U and V, as the fraction that converts
+/// values of U into V. Computed at compile time, so rescaling values
+/// doesn't need floating point arithmetic.
+struct LinearRatio<U, V>(PhantomData<(U, V)>);
+
+impl<U: LinearUnit, V: LinearUnit> LinearRatio<U, V> {
+    const VALUE: (u64, u64) = to_fraction(U::FACTOR_TO_BASE / V::FACTOR_TO_BASE);
+}
+
+/// Raises x to the power n, usable in const contexts.
+const fn const_powi(x: f64, n: i32) -> f64 {
+    let mut result = 1.0;
+    let mut i = 0;
+    while i < n.unsigned_abs() {
+        result *= x;
+        i += 1;
+    }
+
+    if n < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+/// Renders the given symbol raised to the given exponent, e.g "m²".
+fn pow_symbol(symbol: &str, exponent: i32) -> String {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+    let mut result = if symbol.contains('/') {
+        format!("({})", symbol)
+    } else {
+        symbol.to_owned()
+    };
+
+    if exponent < 0 {
+        result.push('⁻');
+    }
+
+    for digit in exponent.unsigned_abs().to_string().bytes() {
+        result.push(SUPERSCRIPTS[usize::from(digit - b'0')]);
+    }
+
+    result
+}
+
+fn pow_name(name: &str, exponent: i32) -> String {
+    match exponent {
+        2 => format!("square {}", name),
+        3 => format!("cubic {}", name),
+        _ => format!("{} to the power of {}", name, exponent),
+    }
+}
+
+impl<U: MeasureUnit, const N: i32> MeasureUnit for PowUnit<U, N> {
+    type AliasedUnit = PowUnit<Canonical<U>, N>;
+    type Kind = type_logic::Plain;
+    type Dimension = dimension::Power<U::Dimension, N>;
+
+    fn symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Unicode, || pow_symbol(&U::symbol(), N))
+    }
+
+    fn ascii_symbol() -> Cow<'static, str> {
+        symbols::composed_symbol::<Self>(SymbolStyle::Ascii, || {
+            symbols::to_ascii(&pow_symbol(&U::ascii_symbol(), N)).into_owned()
+        })
+    }
+
+    fn name() -> Cow<'static, str> {
+        pow_name(&U::name(), N).into()
+    }
+
+    fn plural_name() -> Cow<'static, str> {
+        pow_name(&U::plural_name(), N).into()
+    }
+}
+
+impl<U: LinearUnit, const N: i32> LinearUnit for PowUnit<U, N> {
+    const FACTOR_TO_BASE: f64 = const_powi(U::FACTOR_TO_BASE, N);
+
+    fn quantity() -> Cow<'static, str> {
+        pow_symbol(&U::quantity(), N).into()
+    }
+}
+
+impl<U: MeasureUnit, U1: MeasureUnit, const N: i32> FromUnit<PowUnit<U, N>> for PowUnit<U1, N>
+where
+    Self: SameDimension<PowUnit<U, N>>,
+    U1: FromUnitLinear<U>,
+{
+    #[inline]
+    fn from_value(input: Measurement<PowUnit<U, N>>) -> Measurement<Self> {
+        let factor = U1::from_value(Measurement::<U>::new(1.0)).value();
+        Measurement::new(input.value() * factor.powi(N))
+    }
+}
+
+unsafe impl<U: MeasureUnit, U1: MeasureUnit, const N: i32> FromUnitLinear<PowUnit<U, N>>
+    for PowUnit<U1, N>
+where
+    Self: SameDimension<PowUnit<U, N>>,
+    U1: FromUnitLinear<U>,
+{
+}
+
+/// Returns whether values of the unit U are kept as they are when
+/// converted into the unit V, which is the case if both units have the
+/// same canonical form. Such conversions are skipped, so integers
+/// aren't rounded through f64 when e.g adding measurements of the same
+/// unit, and aren't traced, as nothing is actually converted.
+#[inline]
+fn is_identity<U: MeasureUnit, V: MeasureUnit>() -> bool {
+    TypeId::of::<Canonical<U>>() == TypeId::of::<Canonical<V>>()
+}
+
+/// Converts a value of the unit U into the unit V. See [`is_identity`].
+#[inline]
+fn convert_value<U: MeasureUnit, V: MeasureUnit, T: Number>(value: T) -> T
+where
+    Canonical<V>: FromUnit<U>,
+{
+    if is_identity::<U, V>() {
+        return value;
+    }
+
+    T::from_f64(convert_f64::<U, V>(value.to_f64()))
+}
+
+#[inline]
+fn convert_f64<U: MeasureUnit, V: MeasureUnit>(value: f64) -> f64
+where
+    Canonical<V>: FromUnit<U>,
+{
+    let converted = Canonical::<V>::from_value(Measurement::<U>::new(value)).value;
+
+    #[cfg(feature = "trace")]
+    trace::notify::<U, V>(value, converted);
+
+    converted
+}
+
+impl<U, T: Number> Measurement<U, T> {
+    /// Measurement whose value is zero.
+    pub const ZERO: Self = Self::new(T::ZERO);
+
+    /// Smallest finite measurement.
+    pub const MIN: Self = Self::new(T::MIN);
+
+    /// Largest finite measurement.
+    pub const MAX: Self = Self::new(T::MAX);
+
+    /// Creates a new measurement from the given numerical value. It can
+    /// be used in const contexts, e.g
+    /// `const TIMEOUT: Measurement<Second> = Measurement::new(30.0);`.
+    #[inline]
+    pub const fn new(value: T) -> Measurement<U, T> {
         Self {
-            _marker: PhantomData::default(),
+            _marker: PhantomData,
             value,
         }
     }
 
     /// Returns the current numerical value.
-    pub fn value(self) -> f64 {
+    ///
+    /// Code that wants the unit to be named whenever a raw value is
+    /// read can forbid this method in favor of [`raw_in`](Self::raw_in)
+    /// through the `disallowed-methods` lint of clippy, adding
+    /// `"strong_units::Measurement::value"` to it in `clippy.toml`.
+    #[inline]
+    pub const fn value(self) -> T {
+        self.value
+    }
+
+    /// Returns the numerical value of this measurement expressed in the
+    /// unit V, e.g `timeout.raw_in::<Millisecond>()`. Naming the unit
+    /// on extraction avoids misreading the raw number, or reading it in
+    /// the wrong unit after the type of the measurement changes.
+    #[inline]
+    pub fn raw_in<V: MeasureUnit>(self) -> T
+    where
+        U: MeasureUnit,
+        Canonical<V>: FromUnit<U>,
+    {
+        self.into_unit::<V>().value
+    }
+
+    /// Returns whether the value is zero, either positive or negative.
+    #[inline]
+    pub fn is_zero(self) -> bool {
+        self.value == T::ZERO
+    }
+
+    /// Returns whether the value is strictly greater than zero.
+    #[inline]
+    pub fn is_positive(self) -> bool {
+        self.value > T::ZERO
+    }
+
+    /// Returns whether the value is strictly less than zero.
+    #[inline]
+    pub fn is_negative(self) -> bool {
+        self.value < T::ZERO
+    }
+
+    /// Returns 1, 0 or -1 depending on whether the value is positive,
+    /// zero or negative, or NaN if the value is NaN.
+    #[inline]
+    pub fn signum(self) -> T {
+        if self.is_positive() {
+            T::from_f64(1.0)
+        } else if self.is_negative() {
+            T::from_f64(-1.0)
+        } else {
+            self.value
+        }
+    }
+
+    /// Converts the current measurement into the given unit V. See
+    /// [`Number`] for how values other than f64 are converted.
+    #[inline]
+    pub fn into_unit<V: MeasureUnit>(self) -> Measurement<V, T>
+    where
+        U: MeasureUnit,
+        Canonical<V>: FromUnit<U>,
+    {
+        Measurement::new(convert_value::<U, V, T>(self.value))
+    }
+
+    /// Converts the current measurement into the given unit V,
+    /// returning None if the result doesn't fit in T, e.g when
+    /// converting a large number of gigabytes held in a `u64` into
+    /// bytes.
+    #[inline]
+    pub fn checked_into_unit<V: MeasureUnit>(self) -> Option<Measurement<V, T>>
+    where
+        U: MeasureUnit,
+        Canonical<V>: FromUnit<U>,
+    {
+        if is_identity::<U, V>() {
+            return Some(Measurement::new(self.value));
+        }
+
+        T::checked_from_f64(convert_f64::<U, V>(self.value.to_f64())).map(Measurement::new)
+    }
+
+    /// Converts the current measurement into the given unit V of the
+    /// same [dimension](MeasureUnit::Dimension), through the factors of
+    /// both units to the base unit of their quantity. Unlike
+    /// [`into_unit`](Self::into_unit), it doesn't need a conversion
+    /// between both units, so it can be used in code generic over any
+    /// unit of a dimension. Units must declare their dimension, since
+    /// all the ones that don't share the same.
+    ///
+    /// The ratio between both units is computed at compile time as a
+    /// fraction, so integers and [fixed-point](fixed::Fixed) values are
+    /// converted with integer arithmetic only, see [`Number::mul_ratio`].
+    #[inline]
+    pub fn rescale<V: LinearUnit + SameDimension<U>>(self) -> Measurement<V, T>
+    where
+        U: LinearUnit,
+    {
+        if is_identity::<U, V>() {
+            return Measurement::new(self.value);
+        }
+
+        let (num, den) = LinearRatio::<U, V>::VALUE;
+        Measurement::new(self.value.mul_ratio(num, den))
+    }
+
+    /// Converts the value of this measurement into another numeric
+    /// type, e.g from `u64` into `f64`, keeping its unit. Integers are
+    /// rounded and saturated as described in [`Number::from_f64`].
+    #[inline]
+    pub fn cast<T1: Number>(self) -> Measurement<U, T1> {
+        Measurement::new(T1::from_f64(self.value.to_f64()))
+    }
+
+    /// Returns the ratio of this measurement to the given one of the
+    /// same quantity, converted into this unit first, e.g
+    /// `used.ratio(capacity)` for the fraction of a disk in use. It is
+    /// also what dividing measurements of the same dimension gives.
+    #[inline]
+    pub fn ratio<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> f64
+    where
+        U: MeasureUnit,
+        Canonical<U>: FromUnit<Rhs>,
+    {
+        self.value.to_f64() / convert_value::<Rhs, U, T>(rhs.value).to_f64()
+    }
+
+    /// Adds the given measurement, returning None if the result
+    /// overflows for integers, or is NaN or infinite for floats.
+    pub fn checked_add<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> Option<Self>
+    where
+        U: MeasureUnit,
+        Canonical<U>: FromUnit<Rhs>,
+    {
         self.value
+            .checked_add(convert_value::<Rhs, U, T>(rhs.value))
+            .map(Measurement::new)
     }
 
-    /// Converts the current measurement into the given unit V.
-    pub fn into_unit<V: MeasureUnit>(self) -> Measurement<V>
+    /// Subtracts the given measurement, returning None if the result
+    /// overflows for integers, or is NaN or infinite for floats.
+    pub fn checked_sub<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> Option<Self>
     where
-        V::AliasedUnit: FromUnit<U>,
+        U: MeasureUnit,
+        Canonical<U>: FromUnit<Rhs>,
     {
-        let value_non_aliased = V::AliasedUnit::from_value(self);
-        Measurement::new(value_non_aliased.value())
+        self.value
+            .checked_sub(convert_value::<Rhs, U, T>(rhs.value))
+            .map(Measurement::new)
     }
 }
 
-impl<Lhs: MeasureUnit, Rhs> Add<Measurement<Rhs>> for Measurement<Lhs>
+impl<U: MeasureUnit, T: Integer> Measurement<U, T> {
+    /// Adds the given measurement, saturating at the bounds of T
+    /// instead of overflowing.
+    pub fn saturating_add<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> Self
+    where
+        Canonical<U>: FromUnit<Rhs>,
+    {
+        Measurement::new(
+            self.value
+                .saturating_add(convert_value::<Rhs, U, T>(rhs.value)),
+        )
+    }
+
+    /// Subtracts the given measurement, saturating at the bounds of T
+    /// instead of overflowing.
+    pub fn saturating_sub<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> Self
+    where
+        Canonical<U>: FromUnit<Rhs>,
+    {
+        Measurement::new(
+            self.value
+                .saturating_sub(convert_value::<Rhs, U, T>(rhs.value)),
+        )
+    }
+
+    /// Adds the given measurement, wrapping around at the bounds of T,
+    /// e.g for counters that are expected to overflow.
+    pub fn wrapping_add<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> Self
+    where
+        Canonical<U>: FromUnit<Rhs>,
+    {
+        Measurement::new(
+            self.value
+                .wrapping_add(convert_value::<Rhs, U, T>(rhs.value)),
+        )
+    }
+
+    /// Subtracts the given measurement, wrapping around at the bounds
+    /// of T, e.g for computing the delta between two readings of a
+    /// counter that may have wrapped around.
+    pub fn wrapping_sub<Rhs: MeasureUnit>(self, rhs: Measurement<Rhs, T>) -> Self
+    where
+        Canonical<U>: FromUnit<Rhs>,
+    {
+        Measurement::new(
+            self.value
+                .wrapping_sub(convert_value::<Rhs, U, T>(rhs.value)),
+        )
+    }
+}
+
+macro_rules! impl_float_constants {
+    ($($t:ty),*) => {
+        $(
+            impl<U> Measurement<U, $t> {
+                /// Difference between 1 and the next representable value, in
+                /// this unit.
+                pub const EPSILON: Self = Self::new(<$t>::EPSILON);
+
+                /// Positive infinite measurement.
+                pub const INFINITY: Self = Self::new(<$t>::INFINITY);
+
+                /// Negative infinite measurement.
+                pub const NEG_INFINITY: Self = Self::new(<$t>::NEG_INFINITY);
+            }
+        )*
+    };
+}
+
+impl_float_constants!(f32, f64);
+
+impl<U> Measurement<U> {
+    /// Same as [`rescale`](Self::rescale), but usable in const
+    /// contexts, so conversions between fixed units are folded at
+    /// compile time, e.g
+    /// `const TIMEOUT_MS: Measurement<Millisecond> = TIMEOUT.const_rescale();`.
+    /// The factor between both units is the one of their
+    /// [`FACTOR_TO_BASE`](LinearUnit::FACTOR_TO_BASE).
+    #[inline]
+    pub const fn const_rescale<V: LinearUnit + SameDimension<U>>(self) -> Measurement<V>
+    where
+        U: LinearUnit,
+    {
+        Measurement::new(self.value * ScaleFactor::<U, V>::VALUE)
+    }
+}
+
+impl<U> Measurement<U> {
+    /// Raises the measurement to the power N, e.g turning a length
+    /// into an area.
+    #[inline]
+    pub fn powi<const N: i32>(self) -> Measurement<PowUnit<U, N>> {
+        Measurement::new(self.value.powi(N))
+    }
+}
+
+macro_rules! impl_sqrt {
+    ($($n:literal => $half:literal),*) => {
+        $(
+            impl<U> Measurement<PowUnit<U, $n>> {
+                /// Returns the square root of the measurement, halving the
+                /// power of its unit.
+                #[inline]
+                pub fn sqrt(self) -> Measurement<PowUnit<U, $half>> {
+                    Measurement::new(self.value.sqrt())
+                }
+            }
+        )*
+    };
+}
+
+impl<U> Measurement<PowUnit<U, 2>> {
+    /// Returns the square root of the measurement, e.g turning an area
+    /// into a length.
+    #[inline]
+    pub fn sqrt(self) -> Measurement<U> {
+        Measurement::new(self.value.sqrt())
+    }
+}
+
+impl<L: MeasureUnit, R: MeasureUnit, T: Number> Measurement<MulUnit<L, R>, T> {
+    /// Converts a product of two units of the same dimension into the
+    /// square of the unit U, e.g km·m into m². Products and powers have
+    /// different [dimensions](dimension), so it isn't done by
+    /// [`into_unit`](Self::into_unit).
+    pub fn into_square<U: MeasureUnit>(self) -> Measurement<PowUnit<U, 2>, T>
+    where
+        Canonical<U>: FromUnitLinear<L> + FromUnitLinear<R>,
+    {
+        let l_factor = Canonical::<U>::from_value(Measurement::<L>::new(1.0)).value;
+        let r_factor = Canonical::<U>::from_value(Measurement::<R>::new(1.0)).value;
+        Measurement::new(T::from_f64(self.value.to_f64() * (l_factor * r_factor)))
+    }
+}
+
+impl_sqrt!(4 => 2, 6 => 3, 8 => 4);
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Returns the current measurement if its value is finite, or
+    /// None if it is NaN or infinite.
+    fn finite(self) -> Option<Self> {
+        Some(self).filter(|m| m.value.is_finite())
+    }
+
+    /// Multiplies the measurement by the given scalar, returning None
+    /// if the result is NaN or infinite.
+    pub fn checked_mul_scalar<S: Scalar>(self, rhs: S) -> Option<Self> {
+        (self * rhs).finite()
+    }
+
+    /// Divides the measurement by the given scalar, returning None if
+    /// it is zero, or if the result is NaN or infinite, e.g when
+    /// computing a rate over an interval of zero length. Plain
+    /// division (`m / 0.0`) follows `f64` instead, giving an infinite
+    /// measurement, or NaN if the measurement is zero too.
+    pub fn checked_div<S: Scalar>(self, rhs: S) -> Option<Self> {
+        let rhs = rhs.to_f64();
+        if rhs == 0.0 {
+            return None;
+        }
+
+        (self / rhs).finite()
+    }
+}
+
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> Add<Measurement<Rhs, T>> for Measurement<Lhs, T>
 where
-    Lhs::AliasedUnit: FromUnit<Rhs>,
+    Canonical<Lhs>: FromUnit<Rhs>,
 {
     type Output = Self;
 
-    fn add(self, rhs: Measurement<Rhs>) -> Self::Output {
-        //
-        Measurement::new(self.value + rhs.into_unit::<Lhs>().value)
+    #[inline]
+    fn add(self, rhs: Measurement<Rhs, T>) -> Self::Output {
+        Measurement::new(self.value + convert_value::<Rhs, Lhs, T>(rhs.value))
     }
 }
 
-impl<Lhs: MeasureUnit, Rhs> AddAssign<Measurement<Rhs>> for Measurement<Lhs>
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> AddAssign<Measurement<Rhs, T>>
+    for Measurement<Lhs, T>
 where
-    Lhs::AliasedUnit: FromUnit<Rhs>,
+    Canonical<Lhs>: FromUnit<Rhs>,
 {
-    fn add_assign(&mut self, rhs: Measurement<Rhs>) {
-        self.value += rhs.into_unit::<Lhs>().value
+    #[inline]
+    fn add_assign(&mut self, rhs: Measurement<Rhs, T>) {
+        self.value += convert_value::<Rhs, Lhs, T>(rhs.value)
     }
 }
 
-impl<Lhs: MeasureUnit, Rhs> Sub<Measurement<Rhs>> for Measurement<Lhs>
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> Sub<Measurement<Rhs, T>> for Measurement<Lhs, T>
 where
-    Lhs::AliasedUnit: FromUnit<Rhs>,
+    Canonical<Lhs>: FromUnit<Rhs>,
 {
     type Output = Self;
 
-    fn sub(self, rhs: Measurement<Rhs>) -> Self::Output {
-        Measurement::new(self.value - rhs.into_unit::<Lhs>().value)
+    #[inline]
+    fn sub(self, rhs: Measurement<Rhs, T>) -> Self::Output {
+        Measurement::new(self.value - convert_value::<Rhs, Lhs, T>(rhs.value))
     }
 }
 
-impl<Lhs: MeasureUnit, Rhs> SubAssign<Measurement<Rhs>> for Measurement<Lhs>
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> SubAssign<Measurement<Rhs, T>>
+    for Measurement<Lhs, T>
 where
-    Lhs::AliasedUnit: FromUnit<Rhs>,
+    Canonical<Lhs>: FromUnit<Rhs>,
 {
-    fn sub_assign(&mut self, rhs: Measurement<Rhs>) {
-        self.value -= rhs.into_unit::<Lhs>().value
+    #[inline]
+    fn sub_assign(&mut self, rhs: Measurement<Rhs, T>) {
+        self.value -= convert_value::<Rhs, Lhs, T>(rhs.value)
     }
 }
 
-impl<U> Mul<f64> for Measurement<U> {
+impl<U, S: Scalar> Mul<S> for Measurement<U> {
     type Output = Measurement<U>;
 
-    fn mul(self, rhs: f64) -> Self::Output {
-        Measurement::new(self.value * rhs)
+    #[inline]
+    fn mul(self, rhs: S) -> Self::Output {
+        Measurement::new(self.value * rhs.to_f64())
+    }
+}
+
+/// Multiplying a quotient by a measurement of its denominator cancels
+/// it out, on either side, e.g a flow rate in L/min multiplied by a
+/// duration in seconds gives a volume in liters. Any other pair of
+/// units is combined into a [`MulUnit`], e.g km × km = km·km, which
+/// [`into_square`](Measurement::into_square) turns into areas like
+/// `PowUnit<Meter, 2>`.
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> Mul<Measurement<Rhs, T>> for Measurement<Lhs, T>
+where
+    Lhs: UnitProduct<Rhs, (Kind<Lhs>, Kind<Rhs>)>,
+{
+    type Output = Measurement<<Lhs as UnitProduct<Rhs, (Kind<Lhs>, Kind<Rhs>)>>::Output, T>;
+
+    #[inline]
+    fn mul(self, rhs: Measurement<Rhs, T>) -> Self::Output {
+        Measurement::new(Lhs::multiply(self.value, rhs))
     }
 }
 
-impl<U> MulAssign<f64> for Measurement<U> {
-    fn mul_assign(&mut self, rhs: f64) {
-        self.value *= rhs;
+impl<U, S: Scalar> MulAssign<S> for Measurement<U> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: S) {
+        self.value *= rhs.to_f64();
     }
 }
 
-impl<U> Div<f64> for Measurement<U> {
+/// Division by a scalar. As with `f64`, dividing by zero gives an
+/// infinite or NaN value; see [`Measurement::checked_div`] for
+/// the variant that returns None instead.
+impl<U, S: Scalar> Div<S> for Measurement<U> {
     type Output = Measurement<U>;
 
-    fn div(self, rhs: f64) -> Self::Output {
-        Measurement::new(self.value / rhs)
+    #[inline]
+    fn div(self, rhs: S) -> Self::Output {
+        Measurement::new(self.value / rhs.to_f64())
+    }
+}
+
+impl<U, S: Scalar> DivAssign<S> for Measurement<U> {
+    #[inline]
+    fn div_assign(&mut self, rhs: S) {
+        self.value /= rhs.to_f64();
+    }
+}
+
+/// Multiplication and division of measurements held by types other
+/// than f64, by scalars of the same type.
+macro_rules! impl_number_scalar_ops {
+    ($($t:ty),*) => {
+        $(
+            impl<U> Mul<$t> for Measurement<U, $t> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: $t) -> Self::Output {
+                    Measurement::new(self.value * rhs)
+                }
+            }
+
+            impl<U> MulAssign<$t> for Measurement<U, $t> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: $t) {
+                    self.value *= rhs;
+                }
+            }
+
+            impl<U> Div<$t> for Measurement<U, $t> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: $t) -> Self::Output {
+                    Measurement::new(self.value / rhs)
+                }
+            }
+
+            impl<U> DivAssign<$t> for Measurement<U, $t> {
+                #[inline]
+                fn div_assign(&mut self, rhs: $t) {
+                    self.value /= rhs;
+                }
+            }
+        )*
+    };
+}
+
+impl_number_scalar_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32);
+
+/// Dividing two measurements gives a measurement of the quotient of
+/// their units, e.g a volume in liters divided by a duration in
+/// minutes gives a flow rate in L/min. Measurements of the same
+/// [dimension](MeasureUnit::Dimension) cancel out into their plain
+/// ratio instead, as given by [`Measurement::ratio`], e.g 1 KB divided
+/// by 1 B gives 1000, and dividing by a quotient whose numerator has
+/// the same dimension leaves its denominator, e.g a size in MB divided
+/// by a rate in Mbps gives a duration in seconds. See [`type_logic`]
+/// for how these cases are told apart.
+impl<N: MeasureUnit, D: MeasureUnit, T: Number> Div<Measurement<D, T>> for Measurement<N, T>
+where
+    DimensionId<N>: Compare<DimensionId<D>>,
+    Kind<D>: CompareNumerator<N, D>,
+    N: UnitQuotient<D, T, (Comparison<N, D>, NumeratorComparison<N, D>)>,
+{
+    type Output = <N as UnitQuotient<D, T, (Comparison<N, D>, NumeratorComparison<N, D>)>>::Output;
+
+    #[inline]
+    fn div(self, rhs: Measurement<D, T>) -> Self::Output {
+        N::divide(self, rhs)
     }
 }
 
-impl<U> DivAssign<f64> for Measurement<U> {
-    fn div_assign(&mut self, rhs: f64) {
-        self.value /= rhs;
+impl<U> Div<Measurement<U>> for f64 {
+    type Output = Measurement<ReciprocalUnit<U>>;
+
+    #[inline]
+    fn div(self, rhs: Measurement<U>) -> Self::Output {
+        Measurement::new(self / rhs.value)
     }
 }
 
-impl<U> Display for Measurement<U>
+impl<U, T: Number> Display for Measurement<U, T>
 where
     U: MeasureUnit,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <f64 as Display>::fmt(&self.value, f)?;
-        f.write_str(" ")?;
-        f.write_str(&U::symbol())
+        Display::fmt(&self.format(format::default_format()), f)
     }
 }
 
-impl<Lhs: MeasureUnit, Rhs> PartialOrd<Measurement<Rhs>> for Measurement<Lhs>
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> PartialOrd<Measurement<Rhs, T>>
+    for Measurement<Lhs, T>
 where
-    Lhs::AliasedUnit: FromUnit<Rhs>,
+    Canonical<Lhs>: FromUnit<Rhs>,
 {
-    fn partial_cmp(&self, other: &Measurement<Rhs>) -> Option<std::cmp::Ordering> {
-        self.value.partial_cmp(&other.into_unit::<Lhs>().value)
+    #[inline]
+    fn partial_cmp(&self, other: &Measurement<Rhs, T>) -> Option<std::cmp::Ordering> {
+        self.value
+            .partial_cmp(&convert_value::<Rhs, Lhs, T>(other.value))
     }
 }
 
-impl<Lhs: MeasureUnit, Rhs> PartialEq<Measurement<Rhs>> for Measurement<Lhs>
+impl<Lhs: MeasureUnit, Rhs: MeasureUnit, T: Number> PartialEq<Measurement<Rhs, T>>
+    for Measurement<Lhs, T>
 where
-    Lhs::AliasedUnit: FromUnit<Rhs>,
+    Canonical<Lhs>: FromUnit<Rhs>,
 {
-    fn eq(&self, other: &Measurement<Rhs>) -> bool {
-        self.value == other.into_unit::<Lhs>().value
+    #[inline]
+    fn eq(&self, other: &Measurement<Rhs, T>) -> bool {
+        self.value == convert_value::<Rhs, Lhs, T>(other.value)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rate"))]
 mod tests {
     use crate::{
-        units::{Gbps, Hour, Kbps, Kilobit, Megabit, Second},
-        DivUnit, MeasureUnit, Measurement,
+        units::{
+            Bit, Byte, Gbps, Gigabit, Hour, Kbps, Kilobit, Kilobyte, Megabit, Millisecond, Minute,
+            Second,
+        },
+        DivUnit, MeasureUnit, Measurement, MulUnit, PowUnit, ReciprocalUnit,
     };
     use quickcheck::Arbitrary;
     use quickcheck_macros::quickcheck;
@@ -250,6 +1554,90 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_constants() {
+        let mut total = Measurement::<Minute>::ZERO;
+        total += Measurement::<Second>::new(90.0);
+
+        assert_eq!(1.5, total.value());
+        assert!(total < Measurement::<Hour>::INFINITY);
+        assert!(total > Measurement::<Hour>::NEG_INFINITY);
+        assert_eq!(f64::MAX, Measurement::<Hour>::MAX.value());
+        assert_eq!(f64::MIN, Measurement::<Hour>::MIN.value());
+        assert_eq!(f64::EPSILON, Measurement::<Hour>::EPSILON.value());
+        assert_eq!(f32::EPSILON, Measurement::<Hour, f32>::EPSILON.value());
+        assert_eq!(f32::INFINITY, Measurement::<Hour, f32>::INFINITY.value());
+    }
+
+    #[test]
+    fn test_affine_conversions() {
+        use crate::AffineUnit;
+
+        crate::define_unit!(Kelvin, "K");
+        crate::define_unit!(Rankine, "°R");
+        crate::define_unit!(Reaumur, "°Ré");
+
+        crate::define_affine_conversions! {
+            quantity: "temperature";
+
+            (Kelvin, 1, 0),
+            (Rankine, 5.0 / 9.0, 0),
+            (Reaumur, 1.25, 273.15)
+        }
+
+        let boiling = Measurement::<Reaumur>::new(80.0);
+        assert!(cmp_float!(373.15, boiling.into_unit::<Kelvin>().value()));
+        assert!(cmp_float!(671.67, boiling.into_unit::<Rankine>().value()));
+        assert!(cmp_float!(
+            80.0,
+            boiling
+                .into_unit::<Rankine>()
+                .into_unit::<Reaumur>()
+                .value()
+        ));
+        assert_eq!(273.15, Reaumur::OFFSET);
+        assert_eq!("temperature", Rankine::quantity());
+
+        let warming = Measurement::<DivUnit<Kelvin, Hour>>::new(1.5);
+        assert_eq!(
+            0.025,
+            warming.into_unit::<DivUnit<Kelvin, Minute>>().value()
+        );
+    }
+
+    #[test]
+    fn test_const_evaluation() {
+        const TIMEOUT: Measurement<Second> = Measurement::new(30.0);
+        const TIMEOUT_MS: Measurement<Millisecond> = TIMEOUT.const_rescale();
+        const RATE: f64 = Measurement::<DivUnit<Megabit, Second>>::new(2.5)
+            .const_rescale::<DivUnit<Kilobit, Second>>()
+            .value();
+
+        assert_eq!(30_000.0, TIMEOUT_MS.value());
+        assert_eq!(0.5, TIMEOUT.const_rescale::<Minute>().value());
+        assert_eq!(2500.0, RATE);
+        assert_eq!(TIMEOUT.into_unit::<Hour>(), TIMEOUT.const_rescale::<Hour>());
+    }
+
+    #[test]
+    fn test_sign_predicates() {
+        let positive = Measurement::<Second>::new(2.5);
+        let negative = Measurement::<Second>::new(-0.5);
+        let nan = Measurement::<Second>::new(f64::NAN);
+
+        assert!(positive.is_positive() && !positive.is_negative() && !positive.is_zero());
+        assert!(negative.is_negative() && !negative.is_positive());
+        assert!(Measurement::<Second>::new(-0.0).is_zero());
+        assert!(!nan.is_zero() && !nan.is_positive() && !nan.is_negative());
+
+        assert_eq!(1.0, positive.signum());
+        assert_eq!(-1.0, negative.signum());
+        assert_eq!(0.0, Measurement::<Second>::ZERO.signum());
+        assert!(nan.signum().is_nan());
+        assert_eq!(-1, Measurement::<Second, i32>::new(-30).signum());
+        assert_eq!(0, Measurement::<Second, u8>::new(0).signum());
+    }
+
     #[quickcheck]
     fn test_add_same_unit(value1: Measurement<Hour>, value2: Measurement<Hour>) -> bool {
         let r: Measurement<Hour> = value1 + value2;
@@ -282,6 +1670,24 @@ mod tests {
         )
     }
 
+    #[quickcheck]
+    fn test_add_folds_factor(value1: Measurement<Hour>, value2: Measurement<Second>) -> bool {
+        // Exact equality: a cross-unit add must be nothing but one
+        // multiplication by the conversion factor and one addition.
+        let r: Measurement<Hour> = value1 + value2;
+        r.value() == value1.value() + value2.value() * (1.0 / 3600.0)
+    }
+
+    #[quickcheck]
+    fn test_add_complex_folds_factor(
+        value1: Measurement<DivUnit<Megabit, Hour>>,
+        value2: Measurement<Kbps>,
+    ) -> bool {
+        let factor = (1_000.0 / 1_000_000.0) / (1.0 / 3600.0);
+        let r: Measurement<DivUnit<Megabit, Hour>> = value1 + value2;
+        r.value() == value1.value() + value2.value() * factor
+    }
+
     #[quickcheck]
     fn test_sub_same_unit(value1: Measurement<Hour>, value2: Measurement<Hour>) -> bool {
         let r: Measurement<Hour> = value1 - value2;
@@ -313,6 +1719,233 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_checked_arithmetic() {
+        let max: Measurement<Hour> = Measurement::new(f64::MAX);
+        let one: Measurement<Second> = Measurement::new(1.0);
+
+        assert!(max.checked_add(max).is_none());
+        assert!(max.checked_sub(max * -1.0).is_none());
+        assert!(max.checked_mul_scalar(2.0).is_none());
+        assert!(Measurement::<Hour>::new(f64::NAN)
+            .checked_add(one)
+            .is_none());
+        assert_eq!(
+            Some(7200.0),
+            one.checked_add(Measurement::<Hour>::new(2.0))
+                .map(|m| m.value() - 1.0)
+        );
+        assert_eq!(
+            Some(-1.0),
+            one.checked_mul_scalar(-1.0).map(Measurement::value)
+        );
+        assert!(one.checked_div(0.0).is_none());
+        assert!(one.checked_div(0).is_none());
+        assert!(Measurement::<Hour>::new(0.0).checked_div(-0.0).is_none());
+        assert!(max.checked_div(0.5).is_none());
+        assert_eq!(Some(0.25), one.checked_div(4).map(Measurement::value));
+
+        assert_eq!(f64::INFINITY, (one / 0.0).value());
+        assert!((Measurement::<Hour>::new(0.0) / 0).value().is_nan());
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let chunk: Measurement<Kilobit> = Measurement::new(1.5);
+
+        assert_eq!(6.0, (chunk * 4u64).value());
+        assert_eq!(6.0, (chunk * 4.0f32).value());
+        assert_eq!(0.75, (chunk / 2i32).value());
+
+        let mut m = chunk;
+        m *= 2usize;
+        m /= 3u8;
+        assert_eq!(1.0, m.value());
+    }
+
+    #[test]
+    fn test_integer_values() {
+        let sent: Measurement<Byte, u64> = Measurement::new(u64::MAX - 1);
+        let total = sent + Measurement::<Byte, u64>::new(1);
+
+        // Same-unit arithmetic doesn't go through f64.
+        assert_eq!(u64::MAX, total.value());
+        assert_eq!("18446744073709551615 B", total.to_string());
+        assert!(total > sent);
+
+        let kilobits: Measurement<Kilobit, u64> = Measurement::new(3);
+        assert_eq!(375, kilobits.raw_in::<Byte>());
+        assert_eq!(Measurement::<Byte, u64>::new(375), kilobits);
+        assert_eq!(12, (kilobits * 4).value());
+        assert_eq!(1, (kilobits / 2).value());
+        assert_eq!(
+            Measurement::<Byte, u64>::ZERO,
+            Measurement::<Kilobit, u64>::default()
+        );
+        assert!(Measurement::<Byte, i32>::new(-1).is_negative());
+
+        // Rescaling integers doesn't go through f64 either.
+        assert_eq!(375, kilobits.rescale::<Byte>().value());
+        assert_eq!(
+            u64::MAX,
+            Measurement::<Byte, u64>::MAX.rescale::<Bit>().value()
+        );
+        assert_eq!(
+            -2,
+            Measurement::<Bit, i8>::new(-15).rescale::<Byte>().value()
+        );
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        let max = Measurement::<Byte, u64>::MAX;
+        let one = Measurement::<Byte, u64>::new(1);
+        let bit = Measurement::<Kilobit, u64>::new(1);
+
+        assert_eq!(None, max.checked_add(one));
+        assert_eq!(None, Measurement::<Byte, u64>::ZERO.checked_sub(one));
+        assert_eq!(Some(126), one.checked_add(bit).map(Measurement::value));
+        assert_eq!(u64::MAX, max.saturating_add(bit).value());
+        assert_eq!(0, one.saturating_sub(bit).value());
+        assert_eq!(0, max.wrapping_add(one).value());
+        assert_eq!(
+            u64::MAX,
+            Measurement::<Byte, u64>::ZERO.wrapping_sub(one).value()
+        );
+
+        let minutes = Measurement::<Minute, u32>::new(u32::MAX / 10);
+        assert_eq!(None, minutes.checked_into_unit::<Second>());
+        assert_eq!(
+            Some(25_769_803_740),
+            minutes
+                .cast::<u64>()
+                .checked_into_unit::<Second>()
+                .map(Measurement::value)
+        );
+    }
+
+    #[test]
+    fn test_f32_values() {
+        let period: Measurement<Minute, f32> = Measurement::new(1.5);
+
+        assert_eq!(90.0f32, period.raw_in::<Second>());
+        assert_eq!(3.0f32, (period * 2.0f32).value());
+        assert_eq!("1.5 min", period.to_string());
+        assert_eq!(
+            None,
+            Measurement::<Hour, f32>::MAX.checked_into_unit::<Second>()
+        );
+        assert_eq!(1.5, period.cast::<f64>().value());
+    }
+
+    #[test]
+    fn test_mul_cancels_denominator() {
+        let speed: Measurement<Gbps> = Measurement::new(0.5);
+        let sent: Measurement<Gigabit> = speed * Measurement::<Minute>::new(2.0);
+        assert_eq!(60.0, sent.value());
+
+        let speed: Measurement<DivUnit<Megabit, Hour>> = Measurement::new(36.0);
+        assert_eq!(0.01, (speed * Measurement::<Second>::new(1.0)).value());
+
+        // The quotient may be on either side.
+        let sent: Measurement<Kilobit> =
+            Measurement::<Minute>::new(1.0) * Measurement::<Kbps>::new(2.0);
+        assert_eq!(120.0, sent.value());
+        let sent: Measurement<Megabit, u64> = Measurement::<Second, u64>::new(10)
+            * Measurement::<DivUnit<Megabit, Second>, u64>::new(3);
+        assert_eq!(30, sent.value());
+    }
+
+    #[test]
+    fn test_div_measurements() {
+        let rate: Measurement<DivUnit<Megabit, Minute>> =
+            Measurement::<Megabit>::new(120.0) / Measurement::<Minute>::new(2.0);
+        assert_eq!(60.0, rate.value());
+        assert_eq!(1.0, rate.raw_in::<DivUnit<Megabit, Second>>());
+        assert_eq!("60 Mb/min", rate.to_string());
+
+        // Dividing and multiplying back cancels out.
+        let sent: Measurement<Megabit> = rate * Measurement::<Hour>::new(1.0);
+        assert_eq!(3600.0, sent.value());
+
+        let used = Measurement::<Megabit>::new(250.0);
+        assert_eq!(0.25, used.ratio(Measurement::<Gigabit>::new(1.0)));
+        assert_eq!(
+            0.5,
+            Measurement::<Byte, u64>::new(1).ratio(Measurement::<Byte, u64>::new(2))
+        );
+    }
+
+    #[test]
+    fn test_div_cancels_same_dimension() {
+        let ratio: f64 = Measurement::<Kilobyte>::new(1.0) / Measurement::<Byte>::new(1.0);
+        assert_eq!(1000.0, ratio);
+
+        let ratio: f64 = Measurement::<Gbps>::new(1.0) / Measurement::<Kbps>::new(250.0);
+        assert_eq!(4000.0, ratio);
+
+        let ratio: f64 = Measurement::<Byte, u64>::new(1) / Measurement::<Byte, u64>::new(4);
+        assert_eq!(0.25, ratio);
+
+        // Quotients of different dimensions keep their units.
+        let rate: Measurement<DivUnit<Gbps, Minute>> =
+            Measurement::<Gbps>::new(1.0) / Measurement::<Minute>::new(1.0);
+        assert_eq!(1.0, rate.value());
+    }
+
+    #[test]
+    fn test_div_cancels_numerator() {
+        let time: Measurement<Second> =
+            Measurement::<Kilobyte>::new(1.0) / Measurement::<Kbps>::new(4.0);
+        assert_eq!(2.0, time.value());
+
+        let time: Measurement<Minute> =
+            Measurement::<Gigabit>::new(3.0) / Measurement::<DivUnit<Megabit, Minute>>::new(500.0);
+        assert_eq!(6.0, time.value());
+    }
+
+    #[test]
+    fn test_mul_combines_units() {
+        let usage: Measurement<MulUnit<Gigabit, Hour>> =
+            Measurement::<Gigabit>::new(2.0) * Measurement::<Hour>::new(1.5);
+        assert_eq!(3.0, usage.value());
+        assert_eq!("3 Gb·h", usage.to_string());
+        assert_eq!(10_800.0, usage.raw_in::<MulUnit<Gigabit, Second>>());
+
+        let squared: Measurement<PowUnit<Megabit, 2>> =
+            (Measurement::<Kilobit>::new(2000.0) * Measurement::<Megabit>::new(3.0)).into_square();
+        assert_eq!(6.0, squared.value());
+    }
+
+    #[test]
+    fn test_reciprocal() {
+        let period: Measurement<Minute> = Measurement::new(0.5);
+        let frequency: Measurement<ReciprocalUnit<Minute>> = 1.0 / period;
+        let per_second: Measurement<ReciprocalUnit<Second>> = frequency.into_unit();
+
+        assert_eq!(2.0, frequency.value());
+        assert!(cmp_float!(per_second.value(), 2.0 / 60.0));
+        assert_eq!("2 1/min", frequency.to_string());
+    }
+
+    #[test]
+    fn test_powi_sqrt() {
+        let deviation: Measurement<Minute> = Measurement::new(3.0);
+        let variance: Measurement<PowUnit<Minute, 2>> = deviation.powi::<2>();
+        let in_seconds: Measurement<PowUnit<Second, 2>> = variance.into_unit();
+
+        assert_eq!(9.0, variance.value());
+        assert_eq!("9 min²", variance.to_string());
+        assert!(cmp_float!(in_seconds.value(), 9.0 * 3600.0));
+        assert!(cmp_float!(in_seconds.sqrt().value(), 180.0));
+
+        let fourth: Measurement<PowUnit<Minute, 4>> = deviation.powi();
+        assert_eq!(9.0, fourth.sqrt().value());
+
+        let inverse: Measurement<PowUnit<DivUnit<Kilobit, Second>, -12>> = Measurement::new(1.0);
+        assert_eq!("1 (Kb/s)⁻¹²", inverse.to_string());
+    }
+
     #[test]
     fn test_add_compiles() {
         let m1: Measurement<DivUnit<Kilobit, Second>> = Default::default();
@@ -364,4 +1997,63 @@ mod tests {
         let _: Measurement<DivUnit<Kilobit, Second>> = m2.into_unit();
         let _: Measurement<DivUnit<Megabit, Hour>> = m3.into_unit();
     }
+
+    #[test]
+    fn test_raw_in() {
+        let timeout = Measurement::<Minute>::new(1.5);
+
+        assert_eq!(90.0, timeout.raw_in::<Second>());
+        assert_eq!(1.5, timeout.raw_in::<Minute>());
+        assert_eq!(
+            1000.0,
+            Measurement::<Kbps>::new(8.0).raw_in::<DivUnit<Byte, Second>>()
+        );
+    }
+
+    #[test]
+    fn test_unit_macro() {
+        let _: Measurement<DivUnit<Kilobit, Second>> =
+            Measurement::<crate::unit!(Kilobit / Second)>::ZERO;
+        let _: Measurement<MulUnit<Gigabit, Second>> =
+            Measurement::<crate::unit!(Gigabit * Second)>::ZERO;
+        let _: Measurement<DivUnit<Byte, PowUnit<Second, 2>>> =
+            Measurement::<crate::unit!(Byte / Second ^ 2)>::ZERO;
+        let _: Measurement<PowUnit<DivUnit<Kilobit, Second>, -1>> =
+            Measurement::<crate::unit!((Kilobit / Second) ^ -1)>::ZERO;
+        let _: Measurement<MulUnit<DivUnit<Megabit, Second>, Hour>> =
+            Measurement::<crate::unit!(Megabit / Second * Hour)>::ZERO;
+        let _: Measurement<MulUnit<Megabit, DivUnit<Second, Hour>>> =
+            Measurement::<crate::unit!(Megabit * (Second / Hour))>::ZERO;
+
+        crate::define_alias!(crate::unit!(Gigabit * Second) as GigabitSecond, "Gb·s");
+        let transferred = Measurement::<GigabitSecond>::new(1.5);
+        assert_eq!(
+            Measurement::<crate::unit!(Megabit * Minute)>::new(25.0),
+            transferred
+        );
+        assert_eq!("1.5 Gb·s", transferred.to_string());
+        assert_eq!(
+            "2 Kb·s",
+            Measurement::<crate::unit!(Kilobit * Second)>::new(2.0).to_string()
+        );
+    }
+
+    #[test]
+    fn test_canonical_aliases() {
+        crate::define_alias!(Kbps as KilobitRate, "kbit/s");
+
+        // Aliases of aliases resolve into the canonical unit.
+        let rate = Measurement::<KilobitRate>::new(8000.0);
+        assert_eq!(8000.0, rate.raw_in::<DivUnit<Kilobit, Second>>());
+        assert_eq!(0.008, rate.raw_in::<Gbps>());
+        assert_eq!(Measurement::<Kbps>::new(8000.0), rate);
+        assert_eq!("8000 kbit/s", rate.to_string());
+
+        // Aliases in composed units resolve too, in both directions.
+        let acceleration = Measurement::<DivUnit<Gbps, Second>>::new(1.0);
+        let converted: Measurement<DivUnit<KilobitRate, Minute>> = acceleration.into_unit();
+        assert_eq!(60_000_000.0, converted.value());
+        assert_eq!("60000000 kbit/s/min", converted.to_string());
+        assert_eq!(acceleration, converted);
+    }
 }
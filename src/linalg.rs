@@ -0,0 +1,118 @@
+//! Interoperability with nalgebra, enabled by the `nalgebra` feature.
+//!
+//! Measurements can be used directly as the scalar of nalgebra
+//! vectors and matrices, so vector math keeps track of units:
+//!
+//! ```
+//! # #[cfg(feature = "time")] {
+//! use nalgebra::Vector2;
+//! use strong_units::{units::{Minute, Second}, Measurement};
+//!
+//! let a = Vector2::new(Measurement::<Second>::new(30.0), Measurement::new(60.0));
+//! let b = Vector2::new(Measurement::<Second>::new(30.0), Measurement::new(0.0));
+//! let sum: Vector2<Measurement<Minute>> = strong_units::linalg::into_unit(&(a + b));
+//! assert_eq!(1.0, sum.x.value());
+//! # }
+//! ```
+
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, Matrix, OMatrix, RawStorage};
+use num_traits::Zero;
+
+use crate::{FromUnit, MeasureUnit, Measurement};
+
+impl<U: MeasureUnit> Zero for Measurement<U>
+where
+    U::AliasedUnit: FromUnit<U>,
+{
+    #[inline]
+    fn zero() -> Self {
+        Measurement::new(0.0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Measurement::is_zero(*self)
+    }
+}
+
+/// Wraps every component of the given matrix of raw values into a
+/// measurement of the unit U.
+pub fn wrap<U, R, C, S>(matrix: &Matrix<f64, R, C, S>) -> OMatrix<Measurement<U>, R, C>
+where
+    U: MeasureUnit + 'static,
+    U::AliasedUnit: FromUnit<U>,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<f64, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    matrix.map(Measurement::new)
+}
+
+/// Extracts the raw values of every component of the given matrix of
+/// measurements.
+pub fn unwrap<U, R, C, S>(matrix: &Matrix<Measurement<U>, R, C, S>) -> OMatrix<f64, R, C>
+where
+    U: MeasureUnit + 'static,
+    U::AliasedUnit: FromUnit<U>,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Measurement<U>, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    matrix.map(Measurement::value)
+}
+
+/// Converts every component of the given matrix of measurements into
+/// the unit V.
+pub fn into_unit<U, V, R, C, S>(
+    matrix: &Matrix<Measurement<U>, R, C, S>,
+) -> OMatrix<Measurement<V>, R, C>
+where
+    U: MeasureUnit + 'static,
+    U::AliasedUnit: FromUnit<U>,
+    V: MeasureUnit + 'static,
+    V::AliasedUnit: FromUnit<U> + FromUnit<V>,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Measurement<U>, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    matrix.map(Measurement::into_unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Matrix2, Vector3};
+
+    use super::{into_unit, unwrap, wrap};
+    use crate::Measurement;
+
+    crate::define_unit!(Minute, "min");
+    crate::define_unit!(Hour, "h");
+
+    crate::define_linear_conversions! {
+        quantity: "time";
+
+        (Minute, 1),
+        (Hour, 60 Minute)
+    }
+
+    #[test]
+    fn test_vector_ops() {
+        let a: Vector3<Measurement<Minute>> = wrap(&Vector3::new(1.0, 2.0, 3.0));
+        let b: Vector3<Measurement<Minute>> = wrap(&Vector3::new(59.0, 58.0, 57.0));
+        let zero = Vector3::<Measurement<Minute>>::zeros();
+
+        let sum: Vector3<Measurement<Hour>> = into_unit(&(a + b - zero));
+        assert_eq!(Vector3::new(1.0, 1.0, 1.0), unwrap(&sum));
+    }
+
+    #[test]
+    fn test_matrix_wrap() {
+        let m: Matrix2<Measurement<Hour>> = wrap(&Matrix2::new(1.0, 2.0, 3.0, 4.0));
+
+        assert_eq!(3.0, m[(1, 0)].value());
+        assert_eq!(Matrix2::new(1.0, 2.0, 3.0, 4.0), unwrap(&m));
+    }
+}
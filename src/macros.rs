@@ -4,6 +4,7 @@
 macro_rules! define_nonlinear_conversion {
     ($from_unit:ident -> $to_unit:ident, |$arg: ident| $expr:expr) => {
 	impl FromUnit<$from_unit> for $to_unit {
+	    #[inline]
 	    fn from_value(input: Measurement<$from_unit>) -> Measurement<Self> {
 		fn do_conversion($arg: f64) -> f64 {
 		    $expr
@@ -23,53 +24,231 @@ macro_rules! define_nonlinear_conversion {
 
 }
 
-/// Defines a unit, given a name and its symbol.
+/// Defines a unit, given a name and its symbol. Optionally, it also
+/// takes the ASCII fallback of its symbol if it is not the
+/// transliterated one, the long name of the unit, along with its
+/// plural if it is not formed by just appending an "s", the number of
+/// decimal digits its values are displayed with by default, and the
+/// [dimension](crate::dimension) it measures.
 #[macro_export]
 macro_rules! define_unit {
-    ($id:ident, $symbol:literal) => {
+    ($id:ident, $symbol:literal
+     $(, ascii: $ascii:literal)?
+     $(, name: $name:literal)?
+     $(, plural: $plural:literal)?
+     $(, precision: $precision:literal)?
+     $(, dimension: $dimension:ty)?) => {
         pub struct $id;
         impl $crate::MeasureUnit for $id {
+            type AliasedUnit = Self;
+            type Kind = $crate::type_logic::Plain;
+            type Dimension = $crate::__dimension_or_unspecified!($($dimension)?);
+
             fn symbol() -> std::borrow::Cow<'static, str> {
                 $symbol.into()
             }
+
+            $(
+                fn ascii_symbol() -> std::borrow::Cow<'static, str> {
+                    $ascii.into()
+                }
+            )?
+
+            $(
+                fn name() -> std::borrow::Cow<'static, str> {
+                    $name.into()
+                }
+            )?
+
+            $(
+                fn plural_name() -> std::borrow::Cow<'static, str> {
+                    $plural.into()
+                }
+            )?
+
+            $(
+                fn default_precision() -> Option<usize> {
+                    Some($precision)
+                }
+            )?
         }
     };
 }
 
+/// Expands into the given dimension, or into the unspecified one if
+/// none is given.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dimension_or_unspecified {
+    () => {
+        $crate::dimension::Unspecified
+    };
+    ($dimension:ty) => {
+        $dimension
+    };
+}
+
 /// Defines a alias unit, that holds its own symbol and it is
-/// equivalent to another unit.
+/// equivalent to another unit. Optionally, it also takes the number of
+/// decimal digits its values are displayed with by default, which
+/// otherwise is the one of the aliased unit.
 #[macro_export]
 macro_rules! define_alias {
-    ($unit:ty as $aliasunit:ident, $symbol:literal) => {
+    ($unit:ty as $aliasunit:ident, $symbol:literal $(, precision: $precision:literal)?) => {
         pub struct $aliasunit;
         impl $crate::MeasureUnit for $aliasunit {
-            type AliasedUnit = $unit;
+            type AliasedUnit = $crate::Canonical<$unit>;
+            type Kind = <$crate::Canonical<$unit> as $crate::MeasureUnit>::Kind;
+            type Dimension = <$crate::Canonical<$unit> as $crate::MeasureUnit>::Dimension;
 
             fn symbol() -> std::borrow::Cow<'static, str> {
                 $symbol.into()
             }
+
+            fn name() -> std::borrow::Cow<'static, str> {
+                <$unit as $crate::MeasureUnit>::name()
+            }
+
+            fn plural_name() -> std::borrow::Cow<'static, str> {
+                <$unit as $crate::MeasureUnit>::plural_name()
+            }
+
+            fn default_precision() -> Option<usize> {
+                None$(.or(Some($precision)))?
+                    .or_else(<$unit as $crate::MeasureUnit>::default_precision)
+            }
         }
 
         impl<T> $crate::FromUnit<$aliasunit> for T
         where
-            T: $crate::FromUnit<$unit>,
+            T: $crate::FromUnit<$crate::Canonical<$unit>>
+                + $crate::dimension::SameDimension<$aliasunit>,
         {
+            #[inline]
             fn from_value(input: $crate::Measurement<$aliasunit>) -> $crate::Measurement<Self> {
-                T::from_value($crate::Measurement::<$unit>::new(input.value()))
+                T::from_value($crate::Measurement::<$crate::Canonical<$unit>>::new(input.value()))
+            }
+        }
+
+        unsafe impl<T> $crate::FromUnitLinear<$aliasunit> for T
+        where
+            T: $crate::FromUnitLinear<$crate::Canonical<$unit>>
+                + $crate::dimension::SameDimension<$aliasunit>,
+        {
+        }
+    };
+}
+
+/// Defines a base [dimension](crate::dimension::Dimension), given the
+/// name of its type and the one it is displayed with, for units
+/// measuring quantities that don't have one yet, e.g
+/// `define_dimension!(Energy, "energy")`. Dimensions defined through it
+/// are [unidentified](crate::type_logic::Unidentified), so dividing two
+/// measurements of them gives a quotient of their units rather than a
+/// plain ratio.
+#[macro_export]
+macro_rules! define_dimension {
+    ($(#[$attr:meta])* $id:ident, $name:literal) => {
+        $crate::define_dimension!(
+            @id $crate::type_logic::Unidentified;
+            $(#[$attr])* $id, $name
+        );
+    };
+
+    (@id $dimension_id:ty; $(#[$attr:meta])* $id:ident, $name:literal) => {
+        $(#[$attr])*
+        pub struct $id;
+        impl $crate::dimension::Dimension for $id {
+            type Id = $dimension_id;
+
+            fn name() -> std::borrow::Cow<'static, str> {
+                $name.into()
             }
         }
     };
 }
 
+/// Expands into the composed unit described by the given expression of
+/// units, so compound units can be written as they read, e.g
+/// `unit!(Kilowatt * Hour)` for `MulUnit<Kilowatt, Hour>`,
+/// `unit!(Kilobit / Second)` for `DivUnit<Kilobit, Second>` or
+/// `unit!(Meter / Second^2)` for `DivUnit<Meter, PowUnit<Second, 2>>`.
+/// Operators are applied from left to right, exponents first, and
+/// parentheses group units as usual. Units must be given by their name,
+/// so units from other modules must be imported first.
+///
+/// It can be used wherever a type is expected, including
+/// `define_alias!`, e.g
+/// `define_alias!(unit!(Newton * Meter) as NewtonMeter, "N·m")`.
+#[macro_export]
+macro_rules! unit {
+    (@acc [$($acc:tt)*]) => {
+	$($acc)*
+    };
+
+    (@acc [$($acc:tt)*] $op:tt $unit:ident ^ $exp:literal $($rest:tt)*) => {
+	$crate::unit!(@op $op [$($acc)*] [$crate::PowUnit<$unit, { $exp }>] $($rest)*)
+    };
+
+    (@acc [$($acc:tt)*] $op:tt ($($group:tt)+) ^ $exp:literal $($rest:tt)*) => {
+	$crate::unit!(@op $op [$($acc)*] [$crate::PowUnit<$crate::unit!($($group)+), { $exp }>] $($rest)*)
+    };
+
+    (@acc [$($acc:tt)*] $op:tt $unit:ident $($rest:tt)*) => {
+	$crate::unit!(@op $op [$($acc)*] [$unit] $($rest)*)
+    };
+
+    (@acc [$($acc:tt)*] $op:tt ($($group:tt)+) $($rest:tt)*) => {
+	$crate::unit!(@op $op [$($acc)*] [$crate::unit!($($group)+)] $($rest)*)
+    };
+
+    (@op * [$($lhs:tt)*] [$($rhs:tt)*] $($rest:tt)*) => {
+	$crate::unit!(@acc [$crate::MulUnit<$($lhs)*, $($rhs)*>] $($rest)*)
+    };
+
+    (@op / [$($lhs:tt)*] [$($rhs:tt)*] $($rest:tt)*) => {
+	$crate::unit!(@acc [$crate::DivUnit<$($lhs)*, $($rhs)*>] $($rest)*)
+    };
+
+    ($unit:ident ^ $exp:literal $($rest:tt)*) => {
+	$crate::unit!(@acc [$crate::PowUnit<$unit, { $exp }>] $($rest)*)
+    };
+
+    (($($group:tt)+) ^ $exp:literal $($rest:tt)*) => {
+	$crate::unit!(@acc [$crate::PowUnit<$crate::unit!($($group)+), { $exp }>] $($rest)*)
+    };
+
+    ($unit:ident $($rest:tt)*) => {
+	$crate::unit!(@acc [$unit] $($rest)*)
+    };
+
+    (($($group:tt)+) $($rest:tt)*) => {
+	$crate::unit!(@acc [$crate::unit!($($group)+)] $($rest)*)
+    };
+}
+
 /// Defines the conversions of a set of units whose relationship is linear between them, given a multiply factor.
+/// If the name of the measured quantity is given, LinearUnit is also implemented for them, and the
+/// factor of each unit may be given relative to another unit of the same quantity instead of the base
+/// one, e.g `(Hour, 60 Minute)`. Only the conversions between adjacent units need to be known then, and
+/// the composite factors are computed at compile time. As they are multiplied in floating point, factors
+/// not exactly representable (like 0.0254) are better given relative to the base unit directly.
+///
+/// Units defined along with a quantity form a family, and are converted between them through a single
+/// generic implementation of [`FromUnit`](crate::FromUnit) (see [`ScaledUnit`](crate::ScaledUnit)), so
+/// the size of the generated code grows linearly with the number of units. Units of a quantity defined
+/// elsewhere join its family through `extends`, naming any of its units.
 #[macro_export]
 macro_rules! define_linear_conversions {
     (@impl_from_unit from:($lunit:ident, $lmul:expr), to:($runit:ident, $rmul:expr)) => {
 	unsafe impl $crate::FromUnitLinear<$lunit> for $runit {}
 
 	impl $crate::FromUnit<$lunit> for $runit {
+	    #[inline]
 	    fn from_value(input: $crate::Measurement<$lunit>) -> $crate::Measurement<Self> {
-		$crate::Measurement::new(input.value() * (($lmul) as f64) / (($rmul) as f64))
+		// Folded into a single constant, so the conversion is just one multiplication.
+		const FACTOR: f64 = (($lmul) as f64) / (($rmul) as f64);
+		$crate::Measurement::new(input.value() * FACTOR)
 	    }
 	}
     };
@@ -86,7 +265,300 @@ macro_rules! define_linear_conversions {
 
     };
 
+    // Implements LinearUnit and ScaledUnit for each unit. Factors
+    // followed by a unit are relative to that unit, and are resolved
+    // through its LinearUnit implementation.
+    (@scaled family: $family:ty; quantity: $quantity:literal;
+     $(($unit:ident, $mul:literal $($of:ident)?)),*) => {
+	$(
+	    impl $crate::LinearUnit for $unit {
+		const FACTOR_TO_BASE: f64 =
+		    ($mul) as f64 $(* <$of as $crate::LinearUnit>::FACTOR_TO_BASE)?;
+
+		fn quantity() -> std::borrow::Cow<'static, str> {
+		    $quantity.into()
+		}
+	    }
+
+	    impl $crate::ScaledUnit for $unit {
+		type Family = $family;
+	    }
+	)*
+    };
+
     ($(($unit:ident, $mul:literal)),*) => {
 	$crate::define_linear_conversions!(@cartesian_product $(($unit, $mul))*; $(($unit, $mul))*);
     };
+
+    // Same as above, but also implementing LinearUnit for each unit,
+    // taking as base unit the one whose factor is 1.
+    (quantity: $quantity:literal;
+     ($first:ident, $fmul:literal $($fof:ident)?) $(, ($unit:ident, $mul:literal $($of:ident)?))*) => {
+	$crate::define_linear_conversions!(
+	    @scaled family: $first; quantity: $quantity;
+	    ($first, $fmul $($fof)?) $(, ($unit, $mul $($of)?))*
+	);
+    };
+
+    // Same as above, but for units measuring a quantity already defined
+    // elsewhere, which are converted both between them and from and
+    // into the existing ones.
+    (quantity: $quantity:literal;
+     extends: $eunit:ident;
+     $(($unit:ident, $mul:literal $($of:ident)?)),*) => {
+	$crate::define_linear_conversions!(
+	    @scaled family: <$eunit as $crate::ScaledUnit>::Family; quantity: $quantity;
+	    $(($unit, $mul $($of)?)),*
+	);
+    };
+
+    // Former syntax of the above, listing all the existing units along
+    // with their factors. Only the first one is needed now.
+    (quantity: $quantity:literal;
+     extends: ($efirst:ident, $efirstmul:literal) $(, ($eunit:ident, $emul:literal))*;
+     $(($unit:ident, $mul:literal $($of:ident)?)),*) => {
+	$crate::define_linear_conversions!(
+	    quantity: $quantity;
+	    extends: $efirst;
+	    $(($unit, $mul $($of)?)),*
+	);
+    };
+}
+
+/// Defines the conversions of a set of units whose relationship is affine between them, given the
+/// factor and the offset that convert a value of each unit into the base one, the one whose factor is
+/// 1 and whose offset is 0, e.g `(Celsius, 1, 273.15)` when the base unit is the kelvin. AffineUnit and
+/// BaseConversion are also implemented for them. Since only the identity conversion of each unit
+/// is linear, they can't be converted when composed into other units, like
+/// `DivUnit<Celsius, Second>`.
+#[macro_export]
+macro_rules! define_affine_conversions {
+    (@impl_from_unit from: $lunit:ident, to: $runit:ident) => {
+	impl $crate::FromUnit<$lunit> for $runit {
+	    #[inline]
+	    fn from_value(input: $crate::Measurement<$lunit>) -> $crate::Measurement<Self> {
+		// Folded into a single factor and offset, applied at once.
+		const FACTOR: f64 =
+		    <$lunit as $crate::AffineUnit>::SCALE / <$runit as $crate::AffineUnit>::SCALE;
+		const OFFSET: f64 = (<$lunit as $crate::AffineUnit>::OFFSET
+		    - <$runit as $crate::AffineUnit>::OFFSET)
+		    / <$runit as $crate::AffineUnit>::SCALE;
+		$crate::Measurement::new(input.value() * FACTOR + OFFSET)
+	    }
+	}
+    };
+
+    (@cartesian_product $lunit:ident; $($runit:ident)*) => {
+	$(
+	    $crate::define_affine_conversions!(@impl_from_unit from: $lunit, to: $runit);
+	)*
+    };
+
+    (@cartesian_product $lheadunit:ident $($lunit:ident)*; $($runit:ident)*) => {
+	$crate::define_affine_conversions!(@cartesian_product $lheadunit; $($runit)*);
+	$crate::define_affine_conversions!(@cartesian_product $($lunit)*; $($runit)*);
+    };
+
+    (quantity: $quantity:literal; $(($unit:ident, $scale:expr, $offset:expr)),*) => {
+	$(
+	    impl $crate::AffineUnit for $unit {
+		const SCALE: f64 = ($scale) as f64;
+		const OFFSET: f64 = ($offset) as f64;
+
+		fn quantity() -> std::borrow::Cow<'static, str> {
+		    $quantity.into()
+		}
+	    }
+
+	    impl $crate::BaseConversion for $unit {
+		fn quantity_name() -> std::borrow::Cow<'static, str> {
+		    $quantity.into()
+		}
+
+		#[inline]
+		fn to_base(value: f64) -> f64 {
+		    value * <$unit as $crate::AffineUnit>::SCALE + <$unit as $crate::AffineUnit>::OFFSET
+		}
+
+		#[inline]
+		fn from_base(value: f64) -> f64 {
+		    (value - <$unit as $crate::AffineUnit>::OFFSET) / <$unit as $crate::AffineUnit>::SCALE
+		}
+	    }
+
+	    unsafe impl $crate::FromUnitLinear<$unit> for $unit {}
+	)*
+
+	$crate::define_affine_conversions!(@cartesian_product $($unit)*; $($unit)*);
+    };
+}
+
+/// Builds a static slice of [`DynUnit`](crate::dynamic::DynUnit)
+/// handles of the given units.
+#[macro_export]
+macro_rules! dyn_units {
+    ($($unit:ty),* $(,)?) => {
+        &[$(&$crate::dynamic::StaticUnit::<$unit>::new()),*]
+    };
+}
+
+/// Defines a newtype of the measurements of the given unit that are
+/// within the given range, e.g
+/// `define_bounded_measurement!(ChunkSize: Byte in 4 Kibibyte ..= 16 Mebibyte)`.
+/// The bounds may be given in any unit of the same quantity. See
+/// [`bounded`](crate::bounded) for the generated API.
+#[macro_export]
+macro_rules! define_bounded_measurement {
+    ($(#[$attr:meta])* $name:ident: $unit:ident in $min:literal $minunit:ident ..= $max:literal $maxunit:ident) => {
+	$(#[$attr])*
+	#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+	pub struct $name($crate::Measurement<$unit>);
+
+	impl $name {
+	    /// Returns the smallest valid value.
+	    pub fn min() -> $crate::Measurement<$unit> {
+		$crate::Measurement::<$minunit>::new($min as f64).into_unit()
+	    }
+
+	    /// Returns the largest valid value.
+	    pub fn max() -> $crate::Measurement<$unit> {
+		$crate::Measurement::<$maxunit>::new($max as f64).into_unit()
+	    }
+
+	    /// Creates a new value from the given measurement, or returns
+	    /// an error if it is out of the valid range.
+	    pub fn new<V: $crate::MeasureUnit>(
+		measurement: $crate::Measurement<V>,
+	    ) -> Result<Self, $crate::bounded::BoundedError>
+	    where
+		<$unit as $crate::MeasureUnit>::AliasedUnit: $crate::FromUnit<V>,
+	    {
+		let value = measurement.into_unit::<$unit>();
+		if (Self::min().value()..=Self::max().value()).contains(&value.value()) {
+		    Ok(Self(value))
+		} else {
+		    Err($crate::bounded::BoundedError::OutOfBounds {
+			value: measurement.to_string(),
+			min: $crate::Measurement::<$minunit>::new($min as f64).to_string(),
+			max: $crate::Measurement::<$maxunit>::new($max as f64).to_string(),
+		    })
+		}
+	    }
+
+	    /// Returns the measurement.
+	    pub fn get(self) -> $crate::Measurement<$unit> {
+		self.0
+	    }
+	}
+
+	impl TryFrom<$crate::Measurement<$unit>> for $name {
+	    type Error = $crate::bounded::BoundedError;
+
+	    fn try_from(measurement: $crate::Measurement<$unit>) -> Result<Self, Self::Error> {
+		Self::new(measurement)
+	    }
+	}
+
+	impl From<$name> for $crate::Measurement<$unit> {
+	    fn from(value: $name) -> Self {
+		value.0
+	    }
+	}
+
+	impl std::fmt::Display for $name {
+	    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(&self.0, f)
+	    }
+	}
+
+	impl std::str::FromStr for $name {
+	    type Err = $crate::bounded::BoundedError;
+
+	    fn from_str(input: &str) -> Result<Self, Self::Err> {
+		Self::new($crate::parse::parse_as::<$unit>(input)?)
+	    }
+	}
+
+	$crate::__bounded_measurement_serde!($name, $unit);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bounded_measurement_serde {
+    ($name:ident, $unit:ident) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(
+                    &self
+                        .0
+                        .format($crate::format::MeasurementFormat::new().round_trip()),
+                )
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let measurement = $crate::config::deserialize::<D, $unit>(deserializer)?;
+                Self::new(measurement)
+                    .map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bounded_measurement_serde {
+    ($name:ident, $unit:ident) => {};
+}
+
+/// Asserts that the conversions between every pair of the given units
+/// are symmetric and consistently ordered, and that converting through
+/// any third unit of the list gives the same result as converting
+/// directly, e.g `assert_conversion_consistency!(Second, Minute, Hour)`.
+/// Each unit must also be convertible into itself, like the ones
+/// defined through `define_linear_conversions!`.
+/// If the list is preceded by a name, a test function with that name
+/// is defined instead. See [`testing`](crate::testing).
+#[macro_export]
+macro_rules! assert_conversion_consistency {
+    (@each_a [] $all:tt) => {};
+
+    (@each_a [$a:ident $($rest:ident)*] $all:tt) => {
+	$crate::assert_conversion_consistency!(@each_b $a $all $all);
+	$crate::assert_conversion_consistency!(@each_a [$($rest)*] $all);
+    };
+
+    (@each_b $a:ident [] $all:tt) => {};
+
+    (@each_b $a:ident [$b:ident $($rest:ident)*] $all:tt) => {
+	$crate::testing::assert_symmetric::<$a, $b>();
+	$crate::assert_conversion_consistency!(@each_c $a $b $all);
+	$crate::assert_conversion_consistency!(@each_b $a [$($rest)*] $all);
+    };
+
+    (@each_c $a:ident $b:ident [$($c:ident)*]) => {
+	$(
+	    $crate::testing::assert_transitive::<$a, $b, $c>();
+	)*
+    };
+
+    ($test:ident: $($unit:ident),+ $(,)?) => {
+	#[test]
+	fn $test() {
+	    $crate::assert_conversion_consistency!($($unit),+);
+	}
+    };
+
+    ($($unit:ident),+ $(,)?) => {{
+	$crate::assert_conversion_consistency!(@each_a [$($unit)+] [$($unit)+]);
+    }};
 }
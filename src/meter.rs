@@ -0,0 +1,720 @@
+//! Live measurement of the rate at which some quantity, like the data
+//! transferred by a connection, accumulates over time.
+//!
+//! The state of the meters can be saved and restored later (see
+//! [`RateCounter::state`]), e.g by resumable downloads or restarted
+//! daemons, so their estimates don't start cold. With the `serde`
+//! feature, the saved states can be serialized.
+
+#[cfg(feature = "rate")]
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::{
+    fmt::Debug,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "rate")]
+use crate::units::{Bps, Byte};
+use crate::{units::Second, DivUnit, FromUnit, MeasureUnit, Measurement};
+
+/// Moment some time is measured from. States restored from a previous
+/// run may have started before any representable instant (e.g right
+/// after a reboot), so it's kept as the time already counted before a
+/// known instant.
+#[derive(Debug, Clone, Copy)]
+struct Origin {
+    instant: Instant,
+    counted: Duration,
+}
+
+impl Origin {
+    fn at(instant: Instant) -> Self {
+        Self {
+            instant,
+            counted: Duration::ZERO,
+        }
+    }
+
+    /// Returns the origin the given number of seconds before `now`,
+    /// or `now` if the seconds are not a valid duration.
+    fn before(now: Instant, seconds: f64) -> Self {
+        Self {
+            instant: now,
+            counted: Duration::try_from_secs_f64(seconds).unwrap_or_default(),
+        }
+    }
+
+    fn seconds_until(&self, now: Instant) -> f64 {
+        (self.counted + now.saturating_duration_since(self.instant)).as_secs_f64()
+    }
+}
+
+fn rate<U>(amount: Measurement<U>, seconds: f64) -> f64 {
+    if seconds > 0.0 {
+        amount.value() / seconds
+    } else {
+        0.0
+    }
+}
+
+/// Accumulates amounts of the unit U as they are recorded, and
+/// measures the rate at which they arrive, both since the counter was
+/// created and between consecutive samples.
+pub struct RateCounter<U> {
+    started: Origin,
+    sampled: Origin,
+    total: Measurement<U>,
+    pending: Measurement<U>,
+}
+
+impl<U: MeasureUnit> RateCounter<U>
+where
+    U::AliasedUnit: FromUnit<U>,
+{
+    /// Creates a new counter that starts counting now.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Creates a new counter that starts counting at the given instant.
+    pub fn starting_at(start: Instant) -> Self {
+        Self {
+            started: Origin::at(start),
+            sampled: Origin::at(start),
+            total: Measurement::new(0.0),
+            pending: Measurement::new(0.0),
+        }
+    }
+
+    /// Records a new amount.
+    pub fn record<V: MeasureUnit>(&mut self, amount: Measurement<V>)
+    where
+        U::AliasedUnit: FromUnit<V>,
+    {
+        let amount: Measurement<U> = amount.into_unit();
+        self.total += amount;
+        self.pending += amount;
+    }
+
+    /// Returns the total amount recorded since the counter was created.
+    pub fn total(&self) -> Measurement<U> {
+        self.total
+    }
+
+    /// Returns the average rate since the counter was created until
+    /// the given instant.
+    pub fn average_rate_at(&self, now: Instant) -> Measurement<DivUnit<U, Second>> {
+        Measurement::new(rate(self.total, self.started.seconds_until(now)))
+    }
+
+    /// Returns the average rate since the counter was created until
+    /// now.
+    pub fn average_rate(&self) -> Measurement<DivUnit<U, Second>> {
+        self.average_rate_at(Instant::now())
+    }
+
+    /// Returns the rate of the amounts recorded since the previous
+    /// sample (or since the counter was created) until the given
+    /// instant, and starts a new sample at that instant.
+    pub fn sample_at(&mut self, now: Instant) -> Measurement<DivUnit<U, Second>> {
+        let elapsed = self.sampled.seconds_until(now);
+        let rate = Measurement::new(rate(self.pending, elapsed));
+
+        self.sampled = Origin::at(now);
+        self.pending = Measurement::new(0.0);
+        rate
+    }
+
+    /// Returns the rate of the amounts recorded since the previous
+    /// sample (or since the counter was created) until now, and starts
+    /// a new sample.
+    pub fn sample(&mut self) -> Measurement<DivUnit<U, Second>> {
+        self.sample_at(Instant::now())
+    }
+
+    /// Returns the instant the current sample started at, or the one
+    /// the counter was restored at if the sample was restored from a
+    /// saved state.
+    pub fn last_sampled(&self) -> Instant {
+        self.sampled.instant
+    }
+
+    /// Returns the state of the counter at the given instant.
+    pub fn state_at(&self, now: Instant) -> RateCounterState {
+        RateCounterState {
+            elapsed: self.started.seconds_until(now),
+            since_sample: self.sampled.seconds_until(now),
+            total: self.total.value(),
+            pending: self.pending.value(),
+        }
+    }
+
+    /// Returns the current state of the counter, which can be saved
+    /// and [restored](Self::restore) later.
+    pub fn state(&self) -> RateCounterState {
+        self.state_at(Instant::now())
+    }
+
+    /// Creates a counter that continues from the given state at the
+    /// given instant. The time passed between saving the state and
+    /// restoring it is not counted.
+    pub fn restore_at(state: RateCounterState, now: Instant) -> Self {
+        Self {
+            started: Origin::before(now, state.elapsed),
+            sampled: Origin::before(now, state.since_sample),
+            total: Measurement::new(state.total),
+            pending: Measurement::new(state.pending),
+        }
+    }
+
+    /// Creates a counter that continues from the given state now.
+    pub fn restore(state: RateCounterState) -> Self {
+        Self::restore_at(state, Instant::now())
+    }
+}
+
+impl<U: MeasureUnit> Default for RateCounter<U>
+where
+    U::AliasedUnit: FromUnit<U>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U> Clone for RateCounter<U> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<U: MeasureUnit> Debug for RateCounter<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateCounter")
+            .field("started", &self.started)
+            .field("sampled", &self.sampled)
+            .field("total", &self.total)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+/// Saved state of a [`RateCounter`]. Since instants can't be saved,
+/// times are relative to the moment the state was taken. Amounts are
+/// expressed in the unit of the counter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateCounterState {
+    /// Seconds since the counter started.
+    pub elapsed: f64,
+    /// Seconds since the current sample started.
+    pub since_sample: f64,
+    /// Total amount recorded.
+    pub total: f64,
+    /// Amount recorded since the current sample started.
+    pub pending: f64,
+}
+
+/// Exponentially weighted moving average of the rate measured by a
+/// [`RateCounter`]. Older samples lose half of their weight every
+/// half-life, so the smoothed rate reacts to sustained changes while
+/// ignoring short bursts.
+pub struct EwmaRate<U> {
+    counter: RateCounter<U>,
+    half_life: f64,
+    smoothed: Option<f64>,
+}
+
+impl<U: MeasureUnit> EwmaRate<U>
+where
+    U::AliasedUnit: FromUnit<U>,
+{
+    /// Creates a new smoothed rate with the given half-life, that
+    /// starts counting now.
+    pub fn new<T: MeasureUnit>(half_life: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        Self::starting_at(half_life, Instant::now())
+    }
+
+    /// Creates a new smoothed rate with the given half-life, that
+    /// starts counting at the given instant.
+    pub fn starting_at<T: MeasureUnit>(half_life: Measurement<T>, start: Instant) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        Self {
+            counter: RateCounter::starting_at(start),
+            half_life: half_life.into_unit::<Second>().value(),
+            smoothed: None,
+        }
+    }
+
+    /// Records a new amount.
+    pub fn record<V: MeasureUnit>(&mut self, amount: Measurement<V>)
+    where
+        U::AliasedUnit: FromUnit<V>,
+    {
+        self.counter.record(amount);
+    }
+
+    /// Folds the amounts recorded since the previous update into the
+    /// smoothed rate at the given instant, and returns the new
+    /// smoothed rate. The first update takes the measured rate as is.
+    pub fn update_at(&mut self, now: Instant) -> Measurement<DivUnit<U, Second>> {
+        let elapsed = self.counter.sampled.seconds_until(now);
+        let current = self.counter.sample_at(now).value();
+
+        let smoothed = match self.smoothed {
+            None => current,
+            Some(previous) if self.half_life > 0.0 => {
+                let weight = 1.0 - (-elapsed / self.half_life).exp2();
+                previous + (current - previous) * weight
+            }
+            Some(_) => current,
+        };
+
+        self.smoothed = Some(smoothed);
+        Measurement::new(smoothed)
+    }
+
+    /// Folds the amounts recorded since the previous update into the
+    /// smoothed rate, and returns the new smoothed rate.
+    pub fn update(&mut self) -> Measurement<DivUnit<U, Second>> {
+        self.update_at(Instant::now())
+    }
+
+    /// Returns the smoothed rate as of the latest update, or zero if
+    /// it was never updated.
+    pub fn rate(&self) -> Measurement<DivUnit<U, Second>> {
+        Measurement::new(self.smoothed.unwrap_or(0.0))
+    }
+
+    /// Returns the underlying counter.
+    pub fn counter(&self) -> &RateCounter<U> {
+        &self.counter
+    }
+
+    /// Returns the state of the smoothed rate at the given instant.
+    pub fn state_at(&self, now: Instant) -> EwmaRateState {
+        EwmaRateState {
+            counter: self.counter.state_at(now),
+            half_life: self.half_life,
+            smoothed: self.smoothed,
+        }
+    }
+
+    /// Returns the current state of the smoothed rate, which can be
+    /// saved and [restored](Self::restore) later.
+    pub fn state(&self) -> EwmaRateState {
+        self.state_at(Instant::now())
+    }
+
+    /// Creates a smoothed rate that continues from the given state at
+    /// the given instant.
+    pub fn restore_at(state: EwmaRateState, now: Instant) -> Self {
+        Self {
+            counter: RateCounter::restore_at(state.counter, now),
+            half_life: state.half_life,
+            smoothed: state.smoothed,
+        }
+    }
+
+    /// Creates a smoothed rate that continues from the given state
+    /// now.
+    pub fn restore(state: EwmaRateState) -> Self {
+        Self::restore_at(state, Instant::now())
+    }
+}
+
+impl<U> Clone for EwmaRate<U> {
+    fn clone(&self) -> Self {
+        Self {
+            counter: self.counter.clone(),
+            ..*self
+        }
+    }
+}
+
+impl<U: MeasureUnit> Debug for EwmaRate<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EwmaRate")
+            .field("counter", &self.counter)
+            .field("half_life", &self.half_life)
+            .field("smoothed", &self.smoothed)
+            .finish()
+    }
+}
+
+/// Cloneable handle to the throughput of a transfer, shared between
+/// the code moving the data and the code reporting its progress, e.g
+/// by instrumented streams or request bodies. All the clones record
+/// into and report the same throughput.
+#[cfg(feature = "rate")]
+#[derive(Debug, Clone)]
+pub struct ThroughputHandle(Arc<Mutex<EwmaRate<Byte>>>);
+
+#[cfg(feature = "rate")]
+impl ThroughputHandle {
+    /// Creates a new handle whose live rate is smoothed with a
+    /// half-life of one second.
+    pub fn new() -> Self {
+        Self::with_half_life(Measurement::<Second>::new(1.0))
+    }
+
+    /// Creates a new handle whose live rate is smoothed with the given
+    /// half-life.
+    pub fn with_half_life<T: MeasureUnit>(half_life: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        Self(Arc::new(Mutex::new(EwmaRate::new(half_life))))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, EwmaRate<Byte>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Records the given amount of transferred data.
+    pub fn record<V: MeasureUnit>(&self, amount: Measurement<V>)
+    where
+        Byte: FromUnit<V>,
+    {
+        self.lock().record(amount);
+    }
+
+    /// Returns the total amount of data transferred.
+    pub fn total(&self) -> Measurement<Byte> {
+        self.lock().counter().total()
+    }
+
+    /// Returns the live rate of the transfer, smoothed up to now.
+    pub fn rate(&self) -> Measurement<Bps> {
+        self.lock().update().into_unit()
+    }
+
+    /// Returns the average rate since the handle was created.
+    pub fn average_rate(&self) -> Measurement<Bps> {
+        self.lock().counter().average_rate().into_unit()
+    }
+}
+
+#[cfg(feature = "rate")]
+impl Default for ThroughputHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Saved state of an [`EwmaRate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EwmaRateState {
+    /// State of the underlying counter.
+    pub counter: RateCounterState,
+    /// Half-life of the samples, in seconds.
+    pub half_life: f64,
+    /// Smoothed rate as of the latest update, if any, in the unit of
+    /// the counter per second.
+    pub smoothed: Option<f64>,
+}
+
+/// Estimates the time left for completing a task of a known size, like
+/// a download, from the [smoothed rate](EwmaRate) of progress.
+pub struct EtaEstimator<U> {
+    rate: EwmaRate<U>,
+    size: Measurement<U>,
+}
+
+impl<U: MeasureUnit> EtaEstimator<U>
+where
+    U::AliasedUnit: FromUnit<U>,
+{
+    /// Creates a new estimator for a task of the given size, smoothing
+    /// the rate with the given half-life, that starts counting now.
+    pub fn new<V, T>(size: Measurement<V>, half_life: Measurement<T>) -> Self
+    where
+        V: MeasureUnit,
+        T: MeasureUnit,
+        U::AliasedUnit: FromUnit<V>,
+        Second: FromUnit<T>,
+    {
+        Self::starting_at(size, half_life, Instant::now())
+    }
+
+    /// Creates a new estimator for a task of the given size, smoothing
+    /// the rate with the given half-life, that starts counting at the
+    /// given instant.
+    pub fn starting_at<V, T>(
+        size: Measurement<V>,
+        half_life: Measurement<T>,
+        start: Instant,
+    ) -> Self
+    where
+        V: MeasureUnit,
+        T: MeasureUnit,
+        U::AliasedUnit: FromUnit<V>,
+        Second: FromUnit<T>,
+    {
+        Self {
+            rate: EwmaRate::starting_at(half_life, start),
+            size: size.into_unit(),
+        }
+    }
+
+    /// Records a new amount of progress.
+    pub fn record<V: MeasureUnit>(&mut self, amount: Measurement<V>)
+    where
+        U::AliasedUnit: FromUnit<V>,
+    {
+        self.rate.record(amount);
+    }
+
+    /// Folds the progress recorded since the previous update into the
+    /// smoothed rate at the given instant, and returns the new
+    /// estimation.
+    pub fn update_at(&mut self, now: Instant) -> Option<Measurement<Second>> {
+        self.rate.update_at(now);
+        self.eta()
+    }
+
+    /// Folds the progress recorded since the previous update into the
+    /// smoothed rate, and returns the new estimation.
+    pub fn update(&mut self) -> Option<Measurement<Second>> {
+        self.update_at(Instant::now())
+    }
+
+    /// Returns the amount left for completing the task, which is zero
+    /// once the recorded progress reaches its size.
+    pub fn remaining(&self) -> Measurement<U> {
+        Measurement::new((self.size.value() - self.rate.counter().total().value()).max(0.0))
+    }
+
+    /// Returns the time left for completing the task at the smoothed
+    /// rate as of the latest update, or None if there is no progress
+    /// to estimate it from.
+    pub fn eta(&self) -> Option<Measurement<Second>> {
+        let remaining = self.remaining().value();
+        let rate = self.rate.rate().value();
+
+        if remaining == 0.0 {
+            Some(Measurement::new(0.0))
+        } else if rate > 0.0 {
+            Some(Measurement::new(remaining / rate))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the underlying smoothed rate.
+    pub fn rate(&self) -> &EwmaRate<U> {
+        &self.rate
+    }
+
+    /// Returns the state of the estimator at the given instant.
+    pub fn state_at(&self, now: Instant) -> EtaEstimatorState {
+        EtaEstimatorState {
+            rate: self.rate.state_at(now),
+            size: self.size.value(),
+        }
+    }
+
+    /// Returns the current state of the estimator, which can be saved
+    /// and [restored](Self::restore) later.
+    pub fn state(&self) -> EtaEstimatorState {
+        self.state_at(Instant::now())
+    }
+
+    /// Creates an estimator that continues from the given state at the
+    /// given instant.
+    pub fn restore_at(state: EtaEstimatorState, now: Instant) -> Self {
+        Self {
+            rate: EwmaRate::restore_at(state.rate, now),
+            size: Measurement::new(state.size),
+        }
+    }
+
+    /// Creates an estimator that continues from the given state now.
+    pub fn restore(state: EtaEstimatorState) -> Self {
+        Self::restore_at(state, Instant::now())
+    }
+}
+
+impl<U> Clone for EtaEstimator<U> {
+    fn clone(&self) -> Self {
+        Self {
+            rate: self.rate.clone(),
+            ..*self
+        }
+    }
+}
+
+impl<U: MeasureUnit> Debug for EtaEstimator<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EtaEstimator")
+            .field("rate", &self.rate)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// Saved state of an [`EtaEstimator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EtaEstimatorState {
+    /// State of the smoothed rate of progress.
+    pub rate: EwmaRateState,
+    /// Size of the task, in the unit of the estimator.
+    pub size: f64,
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{EtaEstimator, EwmaRate, RateCounter, RateCounterState, ThroughputHandle};
+    use crate::{
+        units::{Byte, Kilobyte, Mbps, Megabyte, Second},
+        Measurement,
+    };
+
+    #[test]
+    fn test_rate_counter() {
+        let start = Instant::now();
+        let mut counter = RateCounter::<Byte>::starting_at(start);
+
+        counter.record(Measurement::<Kilobyte>::new(1.0));
+        counter.record(Measurement::<Byte>::new(1000.0));
+        assert_eq!(
+            1000.0,
+            counter.sample_at(start + Duration::from_secs(2)).value()
+        );
+
+        counter.record(Measurement::<Kilobyte>::new(4.0));
+        assert_eq!(
+            4000.0,
+            counter.sample_at(start + Duration::from_secs(3)).value()
+        );
+
+        assert_eq!(6000.0, counter.total().value());
+        let average = counter.average_rate_at(start + Duration::from_secs(3));
+        assert_eq!(2000.0, average.value());
+        assert_eq!(0.016, average.into_unit::<Mbps>().value());
+    }
+
+    #[test]
+    fn test_ewma_rate() {
+        let start = Instant::now();
+        let second = |n: u64| start + Duration::from_secs(n);
+        let mut ewma = EwmaRate::<Megabyte>::starting_at(Measurement::<Second>::new(1.0), start);
+
+        ewma.record(Measurement::<Megabyte>::new(10.0));
+        assert_eq!(10.0, ewma.update_at(second(1)).value());
+
+        // A full half-life later the old rate keeps half its weight.
+        ewma.record(Measurement::<Megabyte>::new(30.0));
+        assert_eq!(20.0, ewma.update_at(second(2)).value());
+
+        ewma.record(Measurement::<Megabyte>::new(100.0));
+        assert_eq!(42.5, ewma.update_at(second(4)).value());
+        assert_eq!(42.5, ewma.rate().value());
+    }
+
+    #[test]
+    fn test_eta_estimator() {
+        let start = Instant::now();
+        let second = |n: u64| start + Duration::from_secs(n);
+        let mut eta = EtaEstimator::<Megabyte>::starting_at(
+            Measurement::<Megabyte>::new(100.0),
+            Measurement::<Second>::new(1.0),
+            start,
+        );
+
+        assert_eq!(None, eta.eta());
+        eta.record(Measurement::<Megabyte>::new(10.0));
+        assert_eq!(Some(9.0), eta.update_at(second(1)).map(|eta| eta.value()));
+
+        eta.record(Measurement::<Megabyte>::new(120.0));
+        eta.update_at(second(2));
+        assert_eq!(0.0, eta.remaining().value());
+        assert_eq!(Some(0.0), eta.eta().map(|eta| eta.value()));
+    }
+
+    #[test]
+    fn test_restore_state() {
+        let start = Instant::now();
+        let second = |n: u64| start + Duration::from_secs(n);
+        let mut eta = EtaEstimator::<Megabyte>::starting_at(
+            Measurement::<Megabyte>::new(100.0),
+            Measurement::<Second>::new(1.0),
+            start,
+        );
+
+        eta.record(Measurement::<Megabyte>::new(20.0));
+        eta.update_at(second(2));
+        eta.record(Measurement::<Megabyte>::new(5.0));
+        let state = eta.state_at(second(3));
+        assert_eq!(3.0, state.rate.counter.elapsed);
+        assert_eq!(1.0, state.rate.counter.since_sample);
+
+        // Restored much later, as if no time had passed.
+        let restored = second(3600);
+        let mut eta = EtaEstimator::<Megabyte>::restore_at(state, restored);
+        assert_eq!(state, eta.state_at(restored));
+        assert_eq!(75.0, eta.remaining().value());
+        assert_eq!(
+            25.0 / 3.0,
+            eta.rate().counter().average_rate_at(restored).value()
+        );
+
+        eta.record(Measurement::<Megabyte>::new(5.0));
+        assert_eq!(
+            Some(11.2),
+            eta.update_at(restored + Duration::from_secs(1))
+                .map(|eta| eta.value())
+        );
+    }
+
+    #[test]
+    fn test_restore_long_running_state() {
+        // Older than the instants that may be representable, e.g right
+        // after a reboot.
+        let state = RateCounterState {
+            elapsed: 1e9,
+            since_sample: 1e9,
+            total: 2e9,
+            pending: 1e9,
+        };
+
+        let now = Instant::now();
+        let mut counter = RateCounter::<Byte>::restore_at(state, now);
+        assert_eq!(state, counter.state_at(now));
+        assert_eq!(2.0, counter.average_rate_at(now).value());
+        assert_eq!(1.0, counter.sample_at(now).value());
+    }
+
+    #[test]
+    fn test_throughput_handle() {
+        let handle = ThroughputHandle::new();
+        let shared = handle.clone();
+
+        handle.record(Measurement::<Kilobyte>::new(1.5));
+        shared.record(Measurement::<Byte>::new(500.0));
+        assert_eq!(2000.0, shared.total().value());
+        assert!(handle.rate().value() > 0.0);
+        assert!(handle.average_rate().value() > 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_state_serde() {
+        let start = Instant::now();
+        let mut counter = RateCounter::<Byte>::starting_at(start);
+        counter.record(Measurement::<Kilobyte>::new(2.0));
+
+        let state = counter.state_at(start + Duration::from_secs(4));
+        let saved = toml::to_string(&state).unwrap();
+        assert_eq!(state, toml::from_str(&saved).unwrap());
+    }
+}
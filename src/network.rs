@@ -0,0 +1,243 @@
+//! Calculations commonly needed when reasoning about the performance
+//! of network links, relating their rate, latency and the amount of
+//! data in flight.
+
+use crate::{
+    units::{Bit, Bps, Byte, Second},
+    DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+/// Returns the bandwidth-delay product of a link of the given rate and
+/// round-trip time, i.e the amount of data that is in flight when the
+/// link is fully used.
+pub fn bandwidth_delay_product<R, T>(rate: Measurement<R>, rtt: Measurement<T>) -> Measurement<Byte>
+where
+    R: MeasureUnit,
+    T: MeasureUnit,
+    DivUnit<Bit, Second>: FromUnit<R>,
+    Second: FromUnit<T>,
+{
+    let bits: Measurement<Bit> = rate.into_unit::<Bps>() * rtt;
+    bits.into_unit()
+}
+
+/// Returns the time it takes to transfer the given amount of data at
+/// the given rate. The size and the rate may be expressed in any unit
+/// of data and data rate, e.g a size in MiB and a rate in Mbps, and
+/// the data of both cancels out when dividing them.
+pub fn transfer_time<D, R>(size: Measurement<D>, rate: Measurement<R>) -> Measurement<Second>
+where
+    D: MeasureUnit,
+    R: MeasureUnit,
+    Bit: FromUnit<D>,
+    DivUnit<Bit, Second>: FromUnit<R>,
+{
+    (size.into_unit::<Bit>() / rate.into_unit::<Bps>()).into_unit()
+}
+
+/// Returns the time it takes to put the given amount of data on a
+/// link of the given rate, without counting the propagation delay.
+pub fn serialization_delay<D, R>(size: Measurement<D>, rate: Measurement<R>) -> Measurement<Second>
+where
+    D: MeasureUnit,
+    R: MeasureUnit,
+    Bit: FromUnit<D>,
+    DivUnit<Bit, Second>: FromUnit<R>,
+{
+    transfer_time(size, rate)
+}
+
+/// Returns the window size needed for a sender to reach the given
+/// throughput over a path of the given round-trip time, rounded up to
+/// a whole number of segments of the given size.
+pub fn required_window<R, T, S>(
+    throughput: Measurement<R>,
+    rtt: Measurement<T>,
+    segment: Measurement<S>,
+) -> Measurement<Byte>
+where
+    R: MeasureUnit,
+    T: MeasureUnit,
+    S: MeasureUnit,
+    DivUnit<Bit, Second>: FromUnit<R>,
+    Second: FromUnit<T>,
+    Byte: FromUnit<S>,
+{
+    let window = bandwidth_delay_product(throughput, rtt).value();
+    let segment = segment.into_unit::<Byte>().value();
+    if !(segment.is_finite() && segment > 0.0) {
+        return Measurement::new(window);
+    }
+
+    Measurement::new((window / segment).ceil() * segment)
+}
+
+/// Returns the highest throughput a sender limited to the given
+/// window size can reach over a path of the given round-trip time.
+pub fn window_limited_throughput<D, T>(
+    window: Measurement<D>,
+    rtt: Measurement<T>,
+) -> Measurement<Bps>
+where
+    D: MeasureUnit,
+    T: MeasureUnit,
+    Bit: FromUnit<D>,
+    Second: FromUnit<T>,
+{
+    Measurement::new(window.into_unit::<Bit>().value() / rtt.into_unit::<Second>().value())
+}
+
+/// Fraction of the bits sent over a link that carry payload, once the
+/// line coding and the framing overhead are accounted for. Relates the
+/// raw line rate of a link with its goodput.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Efficiency(f64);
+
+impl Efficiency {
+    /// 8b/10b line coding, used by Gigabit Ethernet, SATA or PCIe 2.0.
+    pub const ENCODING_8B10B: Self = Self(8.0 / 10.0);
+    /// 64b/66b line coding, used by 10 Gigabit Ethernet and faster.
+    pub const ENCODING_64B66B: Self = Self(64.0 / 66.0);
+    /// 128b/130b line coding, used by PCIe 3.0 and later.
+    pub const ENCODING_128B130B: Self = Self(128.0 / 130.0);
+
+    /// Creates an efficiency from its value, between 0 and 1.
+    pub const fn new(efficiency: f64) -> Self {
+        Self(efficiency)
+    }
+
+    /// Returns the efficiency of framing each payload of the given
+    /// size with the given overhead, e.g a 1460 byte segment carrying
+    /// 40 bytes of headers. Returns None if the payload is not a
+    /// positive number or the overhead is negative.
+    pub fn of_framing<P, O>(payload: Measurement<P>, overhead: Measurement<O>) -> Option<Self>
+    where
+        P: MeasureUnit,
+        O: MeasureUnit,
+        Bit: FromUnit<P> + FromUnit<O>,
+    {
+        let payload = payload.into_unit::<Bit>().value();
+        let overhead = overhead.into_unit::<Bit>().value();
+        let valid = payload.is_finite() && payload > 0.0 && overhead.is_finite() && overhead >= 0.0;
+
+        valid.then(|| Self(payload / (payload + overhead)))
+    }
+
+    /// Returns the value of the efficiency, between 0 and 1.
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Returns the efficiency of applying this one and then the given
+    /// one, e.g a line coding and then the framing of a protocol.
+    pub fn then(self, other: Efficiency) -> Self {
+        Self(self.0 * other.0)
+    }
+
+    /// Returns the goodput of a link of the given line rate.
+    pub fn goodput<R: MeasureUnit>(self, line_rate: Measurement<R>) -> Measurement<Bps>
+    where
+        DivUnit<Bit, Second>: FromUnit<R>,
+    {
+        line_rate.into_unit::<Bps>() * self.0
+    }
+
+    /// Returns the line rate needed for reaching the given goodput.
+    pub fn line_rate<R: MeasureUnit>(self, goodput: Measurement<R>) -> Measurement<Bps>
+    where
+        DivUnit<Bit, Second>: FromUnit<R>,
+    {
+        goodput.into_unit::<Bps>() / self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bandwidth_delay_product, required_window, serialization_delay, transfer_time,
+        window_limited_throughput, Efficiency,
+    };
+    use crate::{
+        units::{
+            Bit, Byte, Gbps, Gibibyte, Gigabyte, Kibibyte, Kilobit, Kilobyte, Mbps, Megabyte,
+            Millisecond, Minute, Second,
+        },
+        DivUnit, Measurement,
+    };
+
+    #[test]
+    fn test_bandwidth_delay_product() {
+        let bdp = bandwidth_delay_product(
+            Measurement::<Gbps>::new(1.0),
+            Measurement::<Millisecond>::new(80.0),
+        );
+        assert_eq!(10.0, bdp.into_unit::<Megabyte>().value());
+    }
+
+    #[test]
+    fn test_transfer_time() {
+        let time = transfer_time(
+            Measurement::<Gigabyte>::new(1.5),
+            Measurement::<Mbps>::new(100.0),
+        );
+        assert_eq!(2.0, time.into_unit::<Minute>().value());
+
+        let time = transfer_time(
+            Measurement::<Gibibyte>::new(1.0),
+            Measurement::<Gbps>::new(8.0),
+        );
+        assert_eq!(1.073_741_824, time.value());
+
+        let time = transfer_time(
+            Measurement::<Kilobit>::new(64.0),
+            Measurement::<DivUnit<Kilobyte, Second>>::new(2.0),
+        );
+        assert_eq!(Measurement::<Second>::new(4.0), time);
+    }
+
+    #[test]
+    fn test_serialization_delay() {
+        let delay = serialization_delay(
+            Measurement::<Byte>::new(1500.0),
+            Measurement::<Mbps>::new(100.0),
+        );
+        assert!((delay.into_unit::<Millisecond>().value() - 0.12).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_windows() {
+        let rtt = Measurement::<Millisecond>::new(20.0);
+
+        let window = required_window(
+            Measurement::<Mbps>::new(100.0),
+            rtt,
+            Measurement::<Byte>::new(1460.0),
+        );
+        assert_eq!(251_120.0, window.value());
+
+        let throughput = window_limited_throughput(Measurement::<Kibibyte>::new(64.0), rtt);
+        assert!((throughput.into_unit::<Mbps>().value() - 26.2144).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_efficiency() {
+        let sata = Measurement::<Gbps>::new(6.0);
+        let goodput = Efficiency::ENCODING_8B10B.goodput(sata);
+        assert!((goodput.into_unit::<Gbps>().value() - 4.8).abs() < 1e-9);
+
+        let tcp = Efficiency::of_framing(
+            Measurement::<Byte>::new(1460.0),
+            Measurement::<Byte>::new(40.0),
+        )
+        .unwrap();
+        let ethernet = Efficiency::ENCODING_64B66B.then(tcp);
+        let goodput = ethernet.goodput(Measurement::<Gbps>::new(10.3125));
+        assert!((goodput.into_unit::<Gbps>().value() - 9.733_333).abs() < 1e-6);
+        assert!((ethernet.line_rate(goodput).into_unit::<Gbps>().value() - 10.3125).abs() < 1e-9);
+
+        assert!(
+            Efficiency::of_framing(Measurement::<Bit>::new(0.0), Measurement::<Bit>::new(8.0))
+                .is_none()
+        );
+    }
+}
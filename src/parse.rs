@@ -0,0 +1,648 @@
+//! Parsing of measurements from strings such as "1.5 MiB".
+//!
+//! Besides the symbols of the built-in and registered units, symbols
+//! written as the quotient of two known units are recognized, like
+//! "Mb/s" or "KiB / min", so the unit of user-supplied strings doesn't
+//! need to be known ahead of time:
+//!
+//! ```
+//! # #[cfg(feature = "rate")] {
+//! use strong_units::{
+//!     parse::{parse_any, parse_as},
+//!     units::Kbps,
+//! };
+//!
+//! assert_eq!("300 Mbps", parse_any("300 Mb/s").unwrap().to_string());
+//! assert_eq!(8.192, parse_as::<Kbps>("1 KiB/s").unwrap().value());
+//! # }
+//! ```
+
+use std::{borrow::Cow, error::Error, fmt::Display, str::FromStr, sync::RwLock};
+
+use crate::{
+    dynamic::{dyn_unit, AnyMeasurement, DynUnit},
+    registry, units,
+    units::find_unit,
+    BaseConversion, MeasureUnit, Measurement, Number,
+};
+
+/// Error returned when a string cannot be parsed as a measurement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input doesn't start with a valid number.
+    InvalidNumber(String),
+    /// The symbol following the number doesn't identify a known unit.
+    UnknownUnit(String),
+    /// The unit doesn't measure the expected quantity.
+    WrongQuantity { expected: String, found: String },
+    /// The symbol following the number isn't the one of the expected
+    /// unit.
+    WrongUnit { expected: String, found: String },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::InvalidNumber(number) => write!(f, "invalid number: {:?}", number),
+            ParseError::UnknownUnit(symbol) => write!(f, "unknown unit: {:?}", symbol),
+            ParseError::WrongQuantity { expected, found } => {
+                write!(f, "expected a measurement of {}, found {}", expected, found)
+            }
+            ParseError::WrongUnit { expected, found } => {
+                write!(
+                    f,
+                    "expected a measurement in {:?}, found {:?}",
+                    expected, found
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error for an input whose numeric part is invalid, including the
+/// whole input if it doesn't start with a number at all.
+fn invalid_number(input: &str, number: &str) -> ParseError {
+    let found = if number.is_empty() {
+        input.trim()
+    } else {
+        number
+    };
+    ParseError::InvalidNumber(found.to_owned())
+}
+
+/// Splits the input into its numeric part and the symbol that follows
+/// it, which may be separated or not by whitespace.
+pub(crate) fn split_measurement(input: &str) -> (&str, &str) {
+    let input = input.trim();
+    let bytes = input.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit() || **c == b'.')
+            .count()
+    };
+
+    let mut end = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+    end += digits(end);
+
+    // Only consume an exponent if it is actually followed by digits,
+    // so symbols like "EB" are not mistaken by one.
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        let exponent = digits(end + 1 + sign);
+        if exponent > 0 {
+            end += 1 + sign + exponent;
+        }
+    }
+
+    (&input[..end], input[end..].trim_start())
+}
+
+/// Measurements are parsed from a number followed by the symbol of U,
+/// e.g "42.5 Kbps" as `Measurement<Kbps>`. The symbol is compared
+/// ignoring case and whitespace, so "42.5kbps" is also accepted, but no
+/// conversion is done from other units; see [`parse_as`] for that.
+impl<U: MeasureUnit, T: Number + FromStr> FromStr for Measurement<U, T> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (number, symbol) = split_measurement(input);
+        let normalize =
+            |symbol: &str| -> String { symbol.chars().filter(|c| !c.is_whitespace()).collect() };
+
+        // Symbols are matched ignoring case only if the spelling used
+        // isn't the symbol of another unit, so "Mb" is not taken for
+        // "MB".
+        let found = normalize(symbol);
+        let expected = [normalize(&U::symbol()), normalize(&U::ascii_symbol())];
+        let matches = expected.contains(&found)
+            || (detect_unit(symbol, None).is_none()
+                && expected
+                    .iter()
+                    .any(|expected| expected.to_lowercase() == found.to_lowercase()));
+
+        if !matches {
+            return Err(ParseError::WrongUnit {
+                expected: U::symbol().into_owned(),
+                found: symbol.to_owned(),
+            });
+        }
+
+        number
+            .parse()
+            .map(Measurement::new)
+            .map_err(|_| invalid_number(input, number))
+    }
+}
+
+/// Parses a measurement expressed in any unit of the same quantity as
+/// U, and converts it into U, e.g "1.5 MiB" as `Measurement<Kilobyte>`.
+/// Numbers without a unit are taken as expressed in U. Symbols are
+/// looked up first among the units of the quantity of U, so they take
+/// precedence over units of other quantities with the same symbol.
+pub fn parse_as<U: MeasureUnit + 'static>(input: &str) -> Result<Measurement<U>, ParseError>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    let (number, symbol) = split_measurement(input);
+    let value = number
+        .parse::<f64>()
+        .map_err(|_| invalid_number(input, number))?;
+    if symbol.is_empty() {
+        return Ok(Measurement::new(value));
+    }
+
+    let expected = dyn_unit::<U>().quantity();
+    let unit = detect_unit(symbol, Some(&expected))
+        .ok_or_else(|| ParseError::UnknownUnit(symbol.to_owned()))?;
+
+    AnyMeasurement::new(value, unit)
+        .convert()
+        .ok_or_else(|| ParseError::WrongQuantity {
+            expected: expected.into_owned(),
+            found: unit.quantity().into_owned(),
+        })
+}
+
+/// Parses a measurement expressed in any of the built-in or registered
+/// units, or in a quotient of them, e.g "1.5 MiB", "300Mbps" or
+/// "300 Mb/s".
+pub fn parse_any(input: &str) -> Result<AnyMeasurement, ParseError> {
+    let (number, symbol) = split_measurement(input);
+    let value = number
+        .parse::<f64>()
+        .map_err(|_| invalid_number(input, number))?;
+    let unit =
+        detect_unit(symbol, None).ok_or_else(|| ParseError::UnknownUnit(symbol.to_owned()))?;
+
+    Ok(AnyMeasurement::new(value, unit))
+}
+
+/// Looks up the unit with the given symbol, preferring the units of the
+/// given quantity. Symbols such as "Mb/s" are resolved into the known
+/// unit of the same quantity and size as the quotient if there is one
+/// (Mbps), or into a [`QuotientOfUnits`] otherwise. Quotients of
+/// quotients, like "Mb/s/s", are not recognized.
+pub(crate) fn detect_unit(symbol: &str, quantity: Option<&str>) -> Option<&'static dyn DynUnit> {
+    let known = || {
+        units::UNIT_SETS
+            .iter()
+            .flat_map(|units| units.iter().copied())
+            .chain(registry::registered_units())
+    };
+
+    let preferred = quantity.and_then(|quantity| {
+        known().find(|unit| {
+            unit.quantity() == quantity
+                && (unit.symbol() == symbol || unit.ascii_symbol() == symbol)
+        })
+    });
+    if let Some(unit) = preferred.or_else(|| find_unit(symbol)) {
+        return Some(unit);
+    }
+
+    // Both sides must be known units on their own, so quotients are
+    // only detected one level deep, and only for pairs of known units.
+    let (numerator, denominator) = symbol.split_once('/')?;
+    let numerator = find_unit(numerator.trim())?;
+    let denominator = find_unit(denominator.trim())?;
+    // Units related by an offset, like degrees Celsius, can't be
+    // composed, as their zero doesn't mean the absence of the quantity.
+    if !(numerator.is_linear() && denominator.is_linear()) {
+        return None;
+    }
+
+    let quotient = QuotientOfUnits {
+        numerator,
+        denominator,
+    };
+
+    let quantity = quotient.quantity();
+    let factor = quotient.value_to_base(1.0);
+    let same = known().find(|unit| {
+        unit.quantity() == quantity
+            && (unit.value_to_base(1.0) - factor).abs() <= factor * f64::EPSILON * 4.0
+    });
+
+    Some(same.unwrap_or_else(|| quotient.leak()))
+}
+
+/// Quotients already detected, leaked so measurements can hold
+/// `'static` references to them. They are only created for pairs of
+/// known units, so there is a bounded number of them.
+static QUOTIENTS: RwLock<Vec<&'static QuotientOfUnits>> = RwLock::new(Vec::new());
+
+/// Quotient of two units known at runtime, for symbols like "KiB/min"
+/// which don't match any known unit.
+struct QuotientOfUnits {
+    numerator: &'static dyn DynUnit,
+    denominator: &'static dyn DynUnit,
+}
+
+impl QuotientOfUnits {
+    fn is(&self, other: &QuotientOfUnits) -> bool {
+        self.numerator.symbol() == other.numerator.symbol()
+            && self.denominator.symbol() == other.denominator.symbol()
+    }
+
+    /// Returns the leaked copy of this quotient, leaking it only the
+    /// first time it is detected.
+    fn leak(self) -> &'static QuotientOfUnits {
+        let mut quotients = QUOTIENTS.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(quotient) = quotients.iter().find(|quotient| quotient.is(&self)) {
+            return quotient;
+        }
+
+        let quotient = Box::leak(Box::new(self));
+        quotients.push(quotient);
+        quotient
+    }
+}
+
+impl DynUnit for QuotientOfUnits {
+    fn symbol(&self) -> Cow<'static, str> {
+        [&self.numerator.symbol(), "/", &self.denominator.symbol()]
+            .concat()
+            .into()
+    }
+
+    fn ascii_symbol(&self) -> Cow<'static, str> {
+        [
+            &self.numerator.ascii_symbol(),
+            "/",
+            &self.denominator.ascii_symbol(),
+        ]
+        .concat()
+        .into()
+    }
+
+    fn quantity(&self) -> Cow<'static, str> {
+        [
+            &self.numerator.quantity(),
+            "/",
+            &self.denominator.quantity(),
+        ]
+        .concat()
+        .into()
+    }
+
+    fn value_to_base(&self, value: f64) -> f64 {
+        self.numerator.value_to_base(value) / self.denominator.value_to_base(1.0)
+    }
+
+    fn value_from_base(&self, value: f64) -> f64 {
+        self.numerator.value_from_base(value) * self.denominator.value_to_base(1.0)
+    }
+}
+
+/// Conventions for writing numbers in a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// Character separating the integer and the fractional parts.
+    pub decimal_separator: char,
+    /// Characters that may separate groups of digits.
+    pub group_separators: &'static [char],
+}
+
+impl NumberLocale {
+    /// "1,234.56", also accepting thin spaces between groups.
+    pub const ENGLISH: NumberLocale = NumberLocale {
+        decimal_separator: '.',
+        group_separators: &[',', '\u{2009}', '\u{202F}'],
+    };
+
+    /// "1.234,56" or "1 234,56", as used in most of continental Europe.
+    pub const EUROPEAN: NumberLocale = NumberLocale {
+        decimal_separator: ',',
+        group_separators: &['.', ' ', '\u{A0}', '\u{2009}', '\u{202F}'],
+    };
+
+    /// "1'234.56", as used in Switzerland.
+    pub const SWISS: NumberLocale = NumberLocale {
+        decimal_separator: '.',
+        group_separators: &['\'', '\u{2019}', '\u{2009}', '\u{202F}'],
+    };
+}
+
+/// Rewrites the number the input starts with into the format expected
+/// by [`parse_any`], dropping the group separators and replacing the
+/// decimal separator. Returns the rewritten input, and the length of
+/// the original number.
+///
+/// A group separator between two digits is only accepted if it is
+/// followed by a group of exactly three digits, and preceded by at most
+/// three, so "1.5 MB" is rejected instead of being read as 15 MB with
+/// [`NumberLocale::EUROPEAN`].
+fn delocalize(input: &str, locale: NumberLocale) -> Result<(String, usize), ParseError> {
+    let mut output = String::with_capacity(input.len());
+    let mut end = 0;
+    let mut group = 0;
+    let mut grouped = false;
+    let mut fraction = false;
+
+    for (i, c) in input.char_indices() {
+        let rest = &input[i + c.len_utf8()..];
+        let next_digits = rest.chars().take_while(char::is_ascii_digit).count();
+
+        if c.is_ascii_digit() || (i == 0 && matches!(c, '+' | '-')) {
+            output.push(c);
+            group += usize::from(c.is_ascii_digit());
+        } else if c == locale.decimal_separator {
+            output.push('.');
+            fraction = true;
+        } else if locale.group_separators.contains(&c)
+            && output.ends_with(|prev: char| prev.is_ascii_digit())
+            && next_digits > 0
+        {
+            // Group separators are only considered between digits, so
+            // a space before the unit symbol is not mistaken by one.
+            let valid = !fraction
+                && next_digits == 3
+                && (1..=3).contains(&group)
+                && (!grouped || group == 3);
+            if !valid {
+                return Err(ParseError::InvalidNumber(
+                    input[..i + c.len_utf8() + next_digits].to_owned(),
+                ));
+            }
+
+            grouped = true;
+            group = 0;
+        } else {
+            break;
+        }
+
+        end = i + c.len_utf8();
+    }
+
+    output.push_str(&input[end..]);
+    Ok((output, end))
+}
+
+/// Same as [`parse_any`], but accepting numbers formatted according to
+/// the given locale, e.g "1.234,56 km" or "1 234,5 MB".
+pub fn parse_any_localized(
+    input: &str,
+    locale: NumberLocale,
+) -> Result<AnyMeasurement, ParseError> {
+    let input = input.trim();
+    let (delocalized, end) = delocalize(input, locale)?;
+
+    parse_any(&delocalized).map_err(|e| localized_error(e, &input[..end]))
+}
+
+/// Same as [`parse_as`], but accepting numbers formatted according to
+/// the given locale, e.g "1.234,56 MiB" as `Measurement<Kibibyte>`.
+pub fn parse_as_localized<U: MeasureUnit + 'static>(
+    input: &str,
+    locale: NumberLocale,
+) -> Result<Measurement<U>, ParseError>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    let input = input.trim();
+    let (delocalized, end) = delocalize(input, locale)?;
+
+    parse_as(&delocalized).map_err(|e| localized_error(e, &input[..end]))
+}
+
+/// Reports invalid numbers as they were written in the original input,
+/// rather than as rewritten by [`delocalize`].
+fn localized_error(error: ParseError, number: &str) -> ParseError {
+    match error {
+        ParseError::InvalidNumber(_) => ParseError::InvalidNumber(number.to_owned()),
+        e => e,
+    }
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use super::{
+        parse_any, parse_any_localized, parse_as, parse_as_localized, split_measurement,
+        NumberLocale, ParseError,
+    };
+    use crate::{
+        units::{Kbps, Kibibyte, Megabit, Megabyte, Second},
+        Measurement,
+    };
+
+    #[test]
+    fn test_split_measurement() {
+        assert_eq!(("1.5", "MiB"), split_measurement(" 1.5 MiB "));
+        assert_eq!(("-2e3", "s"), split_measurement("-2e3s"));
+        assert_eq!(("1", "EB"), split_measurement("1EB"));
+        assert_eq!(("", "KB"), split_measurement("KB"));
+    }
+
+    #[test]
+    fn test_parse_any() {
+        let m = parse_any("300 Mbps").unwrap();
+        assert_eq!(300.0, m.value());
+        assert_eq!("Mbps", m.unit().symbol());
+        assert_eq!("min", parse_any("2.5min").unwrap().unit().symbol());
+    }
+
+    #[test]
+    fn test_parse_any_errors() {
+        assert_eq!(
+            Err(ParseError::InvalidNumber("MiB".to_owned())),
+            parse_any("MiB")
+        );
+        assert_eq!(
+            Err(ParseError::UnknownUnit("parsecs".to_owned())),
+            parse_any("12 parsecs")
+        );
+    }
+
+    #[test]
+    fn test_parse_as() {
+        assert_eq!(
+            Ok(1536.0),
+            parse_as::<Kibibyte>("1.5 MiB").map(|m| m.value())
+        );
+        assert_eq!(Ok(1000.0), parse_as::<Kbps>("1 Mbps").map(|m| m.value()));
+        assert_eq!(Ok(30.0), parse_as::<Second>("30").map(|m| m.value()));
+
+        let error = parse_as::<Second>("2 MB").unwrap_err();
+        assert_eq!(
+            ParseError::WrongQuantity {
+                expected: "time".to_owned(),
+                found: "data".to_owned()
+            },
+            error
+        );
+        assert_eq!(
+            "expected a measurement of time, found data",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_detect_quotients() {
+        let rate = parse_any("300 Mb/s").unwrap();
+        assert_eq!("Mbps", rate.unit().symbol());
+        assert_eq!(300.0, rate.value());
+
+        let rate = parse_any("2 KiB / min").unwrap();
+        assert_eq!("KiB/min", rate.unit().symbol());
+        assert_eq!("data/time", rate.unit().quantity());
+        assert!(std::ptr::addr_eq(
+            rate.unit(),
+            parse_any("1 KiB/min").unwrap().unit()
+        ));
+
+        assert_eq!(Ok(8.192), parse_as::<Kbps>("1 KiB/s").map(|m| m.value()));
+        assert_eq!(
+            Err(ParseError::UnknownUnit("KiB/parsec".to_owned())),
+            parse_any("1 KiB/parsec")
+        );
+        assert_eq!(
+            Err(ParseError::UnknownUnit("KiB/s/s".to_owned())),
+            parse_any("1 KiB/s/s")
+        );
+        assert_eq!(
+            Err(ParseError::WrongQuantity {
+                expected: "time".to_owned(),
+                found: "data/time".to_owned()
+            }),
+            parse_as::<Second>("1 Mb/s")
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Ok(42.5),
+            "42.5 Kbps".parse::<Measurement<Kbps>>().map(|m| m.value())
+        );
+        assert_eq!(
+            Ok(42.5),
+            " 42.5kbps ".parse::<Measurement<Kbps>>().map(|m| m.value())
+        );
+        assert_eq!(
+            Ok(90),
+            "90 s"
+                .parse::<Measurement<Second, u32>>()
+                .map(|m| m.value())
+        );
+
+        let error = "1.5 MiB".parse::<Measurement<Kibibyte>>().unwrap_err();
+        assert_eq!(
+            ParseError::WrongUnit {
+                expected: "KiB".to_owned(),
+                found: "MiB".to_owned()
+            },
+            error
+        );
+        assert_eq!(
+            "expected a measurement in \"KiB\", found \"MiB\"",
+            error.to_string()
+        );
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1.5".to_owned())),
+            "1.5 s".parse::<Measurement<Second, u32>>()
+        );
+        assert!("30".parse::<Measurement<Second>>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_case() {
+        assert_eq!(
+            Ok(1.0),
+            "1 mb".parse::<Measurement<Megabyte>>().map(|m| m.value())
+        );
+        assert_eq!(
+            Ok(1.0),
+            "1 Mb".parse::<Measurement<Megabit>>().map(|m| m.value())
+        );
+        assert_eq!(
+            Err(ParseError::WrongUnit {
+                expected: "MB".to_owned(),
+                found: "Mb".to_owned()
+            }),
+            "1 Mb".parse::<Measurement<Megabyte>>()
+        );
+        assert!("1 mb".parse::<Measurement<Megabit>>().is_ok());
+    }
+
+    #[test]
+    fn test_parse_any_localized() {
+        let parse = |input, locale| parse_any_localized(input, locale).map(|m| m.to_string());
+
+        assert_eq!(
+            Ok("1234.56 MB".to_owned()),
+            parse("1.234,56 MB", NumberLocale::EUROPEAN)
+        );
+        assert_eq!(
+            Ok("1234.5 MB".to_owned()),
+            parse("1 234,5 MB", NumberLocale::EUROPEAN)
+        );
+        assert_eq!(
+            Ok("1234.5 MB".to_owned()),
+            parse("1\u{202F}234,5MB", NumberLocale::EUROPEAN)
+        );
+        assert_eq!(
+            Ok("-1234567.5 s".to_owned()),
+            parse("-1,234,567.5 s", NumberLocale::ENGLISH)
+        );
+        assert_eq!(
+            Ok("1234.5 Kb".to_owned()),
+            parse("1'234.5 Kb", NumberLocale::SWISS)
+        );
+        assert_eq!(
+            Ok("2500 Mbps".to_owned()),
+            parse("2,5e3 Mbps", NumberLocale::EUROPEAN)
+        );
+    }
+
+    #[test]
+    fn test_parse_any_localized_errors() {
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1,2,3".to_owned())),
+            parse_any_localized("1,2,3 MB", NumberLocale::EUROPEAN)
+        );
+        assert_eq!(
+            Err(ParseError::UnknownUnit(", MB".to_owned())),
+            parse_any_localized("1, MB", NumberLocale::ENGLISH)
+        );
+
+        for ambiguous in [
+            "1.5 MB",
+            "12.34 MB",
+            "1234.567 MB",
+            "1.234.5 MB",
+            "1,234.567 MB",
+        ] {
+            assert!(
+                matches!(
+                    parse_any_localized(ambiguous, NumberLocale::EUROPEAN),
+                    Err(ParseError::InvalidNumber(_))
+                ),
+                "{} was accepted",
+                ambiguous
+            );
+        }
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1.5".to_owned())),
+            parse_any_localized("1.5 MB", NumberLocale::EUROPEAN)
+        );
+    }
+
+    #[test]
+    fn test_parse_as_localized() {
+        let parse = |input| parse_as_localized::<Kibibyte>(input, NumberLocale::EUROPEAN);
+
+        assert_eq!(Ok(1536.0), parse("1,5 MiB").map(|m| m.value()));
+        assert_eq!(Ok(1234.5), parse("1.234,5").map(|m| m.value()));
+        assert_eq!(
+            Err(ParseError::InvalidNumber("1.5".to_owned())),
+            parse("1.5 MiB")
+        );
+        assert!(matches!(
+            parse("1,5 s"),
+            Err(ParseError::WrongQuantity { .. })
+        ));
+    }
+}
@@ -0,0 +1,15 @@
+//! Convenience re-exports of the types, traits and macros needed for
+//! working with measurements and defining new units.
+//!
+//! ```
+//! use strong_units::prelude::*;
+//! ```
+
+pub use crate::{
+    define_affine_conversions, define_alias, define_linear_conversions,
+    define_nonlinear_conversion, define_unit, unit, AffineUnit, DivUnit, FromUnit, FromUnitLinear,
+    LinearUnit, MeasureUnit, Measurement, MulUnit, PowUnit, ReciprocalUnit, Scalar,
+};
+
+#[cfg(feature = "time")]
+pub use crate::units::InstantExt;
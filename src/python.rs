@@ -0,0 +1,170 @@
+//! Python bindings, enabled by the `python` feature. Each quantity is
+//! exposed as a Python class that stores its value in the base unit
+//! of the quantity, and accepts and produces values in any of its
+//! units by symbol, e.g:
+//!
+//! ```python
+//! from strong_units import DataSize, Duration
+//!
+//! size = DataSize(1.5, "GiB")
+//! elapsed = Duration(90, "s")
+//! print(size.to("MB"), elapsed.humanize())
+//! ```
+//!
+//! The crate is only built as a Rust library by default. The extension
+//! module is built as a dynamic library through maturin, which passes
+//! `--crate-type cdylib` itself, with the `pyo3/extension-module`
+//! feature enabled as well, e.g `maturin build --features
+//! python,pyo3/extension-module`.
+
+use pyo3::{basic::CompareOp, exceptions::PyValueError, prelude::*};
+
+use crate::{
+    dynamic::{dyn_unit, AnyMeasurement, DynUnit},
+    humanize::humanize,
+    units::{self, Bps, Byte, Second, BINARY_BYTE_UNITS, BYTE_UNITS, RATE_UNITS, TIME_UNITS},
+};
+
+/// Looks up the built-in unit with the given symbol, ensuring it
+/// measures the same quantity as the given reference unit.
+fn find_unit(symbol: &str, reference: &dyn DynUnit) -> PyResult<&'static dyn DynUnit> {
+    units::find_unit(symbol)
+        .filter(|unit| unit.quantity() == reference.quantity())
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "unknown unit of {}: {}",
+                reference.quantity(),
+                symbol
+            ))
+        })
+}
+
+macro_rules! py_quantity {
+    ($class:ident as $name:literal,
+     unit: $unit:ty,
+     humanize: |$binary:ident| $humanize_units:expr) => {
+        #[pyclass(name = $name, module = "strong_units", frozen)]
+        #[derive(Clone, Copy)]
+        pub struct $class {
+            base_value: f64,
+        }
+
+        impl $class {
+            /// Unit in which the measurement is represented in Python.
+            fn unit() -> &'static dyn DynUnit {
+                dyn_unit::<$unit>()
+            }
+
+            fn measurement(&self) -> AnyMeasurement {
+                let unit = Self::unit();
+                AnyMeasurement::new(unit.value_from_base(self.base_value), unit)
+            }
+
+            fn humanize_units($binary: bool) -> &'static [&'static dyn DynUnit] {
+                $humanize_units
+            }
+        }
+
+        #[pymethods]
+        impl $class {
+            /// Creates a new measurement from the given value and unit symbol.
+            #[new]
+            fn new(value: f64, unit: &str) -> PyResult<Self> {
+                let unit = find_unit(unit, Self::unit())?;
+                Ok(Self {
+                    base_value: unit.value_to_base(value),
+                })
+            }
+
+            /// Returns the value of the measurement, expressed in the given unit.
+            fn to(&self, unit: &str) -> PyResult<f64> {
+                Ok(find_unit(unit, Self::unit())?.value_from_base(self.base_value))
+            }
+
+            /// Formats the measurement using the most readable unit.
+            #[pyo3(signature = (precision = 2, binary = false))]
+            fn humanize(&self, precision: usize, binary: bool) -> String {
+                format!(
+                    "{:.*}",
+                    precision,
+                    humanize(self.measurement(), Self::humanize_units(binary))
+                )
+            }
+
+            fn __add__(&self, other: &Self) -> Self {
+                Self {
+                    base_value: self.base_value + other.base_value,
+                }
+            }
+
+            fn __sub__(&self, other: &Self) -> Self {
+                Self {
+                    base_value: self.base_value - other.base_value,
+                }
+            }
+
+            fn __mul__(&self, scalar: f64) -> Self {
+                Self {
+                    base_value: self.base_value * scalar,
+                }
+            }
+
+            fn __rmul__(&self, scalar: f64) -> Self {
+                self.__mul__(scalar)
+            }
+
+            fn __truediv__(&self, scalar: f64) -> Self {
+                Self {
+                    base_value: self.base_value / scalar,
+                }
+            }
+
+            fn __neg__(&self) -> Self {
+                Self {
+                    base_value: -self.base_value,
+                }
+            }
+
+            fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+                self.base_value
+                    .partial_cmp(&other.base_value)
+                    .map_or(matches!(op, CompareOp::Ne), |ordering| op.matches(ordering))
+            }
+
+            fn __str__(&self) -> String {
+                self.humanize(2, false)
+            }
+
+            fn __repr__(&self) -> String {
+                let measurement = self.measurement();
+                format!(
+                    "{}({:?}, '{}')",
+                    $name,
+                    measurement.value(),
+                    measurement.unit().symbol()
+                )
+            }
+        }
+    };
+}
+
+py_quantity!(PyDuration as "Duration",
+             unit: Second,
+             humanize: |_binary| TIME_UNITS);
+
+py_quantity!(PyDataSize as "DataSize",
+             unit: Byte,
+             humanize: |binary| if binary { BINARY_BYTE_UNITS } else { BYTE_UNITS });
+
+py_quantity!(PyDataRate as "DataRate",
+             unit: Bps,
+             humanize: |_binary| RATE_UNITS);
+
+/// Entry point of the `strong_units` Python module.
+#[pymodule]
+fn strong_units(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDuration>()?;
+    module.add_class::<PyDataSize>()?;
+    module.add_class::<PyDataRate>()?;
+    Ok(())
+}
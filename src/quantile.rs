@@ -0,0 +1,260 @@
+//! Streaming estimation of quantiles, e.g for reporting the p50, p95
+//! and p99 latencies of a long-running service without storing every
+//! sample.
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{FromUnit, MeasureUnit, Measurement};
+
+/// Compression used by [`QuantileDigest::default`].
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Estimates the quantiles of a stream of measurements of the unit U,
+/// using a merging t-digest. Samples are clustered into centroids
+/// whose size is bounded by the compression, being smaller near the
+/// extremes, so tail quantiles are estimated more accurately than the
+/// median. Higher compressions are more accurate, but use more memory.
+pub struct QuantileDigest<U> {
+    _marker: PhantomData<fn() -> U>,
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl<U: MeasureUnit> QuantileDigest<U> {
+    /// Creates an empty digest with the given compression.
+    pub fn new(compression: f64) -> Self {
+        Self {
+            _marker: PhantomData,
+            compression: compression.max(1.0),
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Records a new sample. Non-finite samples are ignored.
+    pub fn record<V: MeasureUnit>(&mut self, sample: Measurement<V>)
+    where
+        U::AliasedUnit: FromUnit<V>,
+    {
+        let value = sample.into_unit::<U>().value();
+        if !value.is_finite() {
+            return;
+        }
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+
+        if self.buffer.len() as f64 >= self.compression * 5.0 {
+            self.centroids = self.merged();
+            self.buffer.clear();
+        }
+    }
+
+    /// Adds all the samples recorded by another digest into this one.
+    pub fn merge(&mut self, other: &Self) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.centroids.extend(other.merged());
+        self.centroids = self.merged();
+        self.buffer.clear();
+    }
+
+    /// Returns the number of samples recorded.
+    pub fn count(&self) -> u64 {
+        let weight: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        weight as u64 + self.buffer.len() as u64
+    }
+
+    /// Returns the smallest sample recorded, if any.
+    pub fn min(&self) -> Option<Measurement<U>> {
+        (self.count() > 0).then(|| Measurement::new(self.min))
+    }
+
+    /// Returns the largest sample recorded, if any.
+    pub fn max(&self) -> Option<Measurement<U>> {
+        (self.count() > 0).then(|| Measurement::new(self.max))
+    }
+
+    /// Estimates the value below which the given fraction (between 0
+    /// and 1) of the samples fall, or returns None if no sample has
+    /// been recorded.
+    pub fn quantile(&self, q: f64) -> Option<Measurement<U>> {
+        let centroids = self.merged();
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        if total == 0.0 {
+            return None;
+        }
+
+        let target = q.clamp(0.0, 1.0) * total;
+
+        // Each centroid is placed at the middle of the samples it
+        // represents, and the extremes at both ends of the range.
+        let (mut previous_position, mut previous_mean) = (0.0, self.min);
+        let mut cumulative = 0.0;
+
+        for centroid in &centroids {
+            let position = cumulative + centroid.weight / 2.0;
+            if target < position {
+                return Some(Measurement::new(interpolate(
+                    (previous_position, previous_mean),
+                    (position, centroid.mean),
+                    target,
+                )));
+            }
+
+            cumulative += centroid.weight;
+            (previous_position, previous_mean) = (position, centroid.mean);
+        }
+
+        Some(Measurement::new(interpolate(
+            (previous_position, previous_mean),
+            (total, self.max),
+            target,
+        )))
+    }
+
+    /// Merges the buffered samples into the centroids, combining
+    /// neighbour centroids as long as they stay below the size bound
+    /// at their quantile.
+    fn merged(&self) -> Vec<Centroid> {
+        let mut all: Vec<Centroid> = self
+            .centroids
+            .iter()
+            .copied()
+            .chain(
+                self.buffer
+                    .iter()
+                    .map(|&mean| Centroid { mean, weight: 1.0 }),
+            )
+            .collect();
+        all.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+        let mut result: Vec<Centroid> = Vec::with_capacity(all.len());
+        let mut cumulative = 0.0;
+
+        for centroid in all {
+            if let Some(last) = result.last_mut() {
+                let weight = last.weight + centroid.weight;
+                let q = (cumulative + weight / 2.0) / total;
+                let limit = 4.0 * total * q * (1.0 - q) / self.compression;
+
+                if weight <= limit.max(1.0) {
+                    last.mean += (centroid.mean - last.mean) * centroid.weight / weight;
+                    last.weight = weight;
+                    continue;
+                }
+
+                cumulative += last.weight;
+            }
+
+            result.push(centroid);
+        }
+
+        result
+    }
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 {
+        y1
+    } else {
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    }
+}
+
+impl<U: MeasureUnit> Default for QuantileDigest<U> {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl<U> Clone for QuantileDigest<U> {
+    fn clone(&self) -> Self {
+        Self {
+            _marker: PhantomData,
+            centroids: self.centroids.clone(),
+            buffer: self.buffer.clone(),
+            ..*self
+        }
+    }
+}
+
+impl<U: MeasureUnit> Debug for QuantileDigest<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(&format!("QuantileDigest<{}>", U::symbol()))
+            .field("compression", &self.compression)
+            .field("count", &self.count())
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use super::QuantileDigest;
+    use crate::{
+        units::{Millisecond, Second},
+        Measurement,
+    };
+
+    fn assert_close(expected: f64, actual: Option<Measurement<Millisecond>>, tolerance: f64) {
+        let actual = actual.unwrap().value();
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_quantiles() {
+        let mut digest = QuantileDigest::<Millisecond>::default();
+        // Shuffled, so the samples don't arrive in order.
+        for i in 0..10_000u64 {
+            let value = (i * 7919) % 10_000 + 1;
+            digest.record(Measurement::<Millisecond>::new(value as f64));
+        }
+
+        assert_eq!(10_000, digest.count());
+        assert_close(1.0, digest.min(), 0.0);
+        assert_close(10_000.0, digest.max(), 0.0);
+        assert_close(5_000.0, digest.quantile(0.5), 50.0);
+        assert_close(9_500.0, digest.quantile(0.95), 20.0);
+        assert_close(9_900.0, digest.quantile(0.99), 10.0);
+    }
+
+    #[test]
+    fn test_units_and_merge() {
+        let mut a = QuantileDigest::<Millisecond>::new(50.0);
+        let mut b = QuantileDigest::<Millisecond>::new(50.0);
+
+        a.record(Measurement::<Second>::new(1.0));
+        a.record(Measurement::<Second>::new(2.0));
+        b.record(Measurement::<Millisecond>::new(3000.0));
+        a.merge(&b);
+
+        assert_eq!(3, a.count());
+        assert_close(2000.0, a.quantile(0.5), 0.0);
+        assert_close(3000.0, a.quantile(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_empty() {
+        let digest = QuantileDigest::<Millisecond>::default();
+        assert!(digest.quantile(0.5).is_none());
+        assert!(digest.min().is_none());
+    }
+}
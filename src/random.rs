@@ -0,0 +1,214 @@
+//! Random measurements drawn from typed ranges, for load generators and
+//! simulations, e.g payloads between 1 KiB and 4 MiB:
+//!
+//! ```
+//! # #[cfg(feature = "data")] {
+//! use rand::Rng;
+//! use strong_units::{
+//!     random::MeasurementRange,
+//!     units::{Byte, Kibibyte, Mebibyte},
+//!     Measurement,
+//! };
+//!
+//! let sizes = MeasurementRange::<Byte>::log_uniform(
+//!     Measurement::<Kibibyte>::new(1.0),
+//!     Measurement::<Mebibyte>::new(4.0),
+//! );
+//!
+//! let size = rand::thread_rng().sample(&sizes);
+//! assert!((1024.0..4_194_304.0).contains(&size.value()));
+//! # }
+//! ```
+
+use std::marker::PhantomData;
+
+use rand::{distributions::Distribution, Rng};
+
+use crate::{Canonical, FromUnit, MeasureUnit, Measurement};
+
+/// Range of measurements of the unit U that random measurements can be
+/// drawn from, through its [`Distribution`] implementation. The range
+/// includes its lower bound but not the upper one, unless both are
+/// equal.
+pub struct MeasurementRange<U> {
+    low: f64,
+    high: f64,
+    logarithmic: bool,
+    _marker: PhantomData<fn() -> U>,
+}
+
+impl<U: MeasureUnit> MeasurementRange<U> {
+    fn new(low: f64, high: f64, logarithmic: bool) -> Self {
+        assert!(
+            low.is_finite() && high.is_finite() && low <= high,
+            "the bounds of a measurement range must be finite and sorted"
+        );
+
+        Self {
+            low,
+            high,
+            logarithmic,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a range whose measurements are drawn uniformly between
+    /// the given bounds, which may be expressed in any unit of the same
+    /// quantity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds are not finite, or the lower one is greater
+    /// than the upper one.
+    pub fn uniform<A: MeasureUnit, B: MeasureUnit>(
+        low: Measurement<A>,
+        high: Measurement<B>,
+    ) -> Self
+    where
+        Canonical<U>: FromUnit<A> + FromUnit<B>,
+    {
+        Self::new(low.raw_in::<U>(), high.raw_in::<U>(), false)
+    }
+
+    /// Creates a range whose measurements are drawn so that their
+    /// logarithm is uniform between the ones of the given bounds, so
+    /// each order of magnitude is as likely as the others, which suits
+    /// sizes spanning several of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds are not finite, the lower one is greater
+    /// than the upper one, or they are not positive.
+    pub fn log_uniform<A: MeasureUnit, B: MeasureUnit>(
+        low: Measurement<A>,
+        high: Measurement<B>,
+    ) -> Self
+    where
+        Canonical<U>: FromUnit<A> + FromUnit<B>,
+    {
+        let (low, high) = (low.raw_in::<U>(), high.raw_in::<U>());
+        assert!(
+            low > 0.0,
+            "the bounds of a logarithmic measurement range must be positive"
+        );
+
+        Self::new(low, high, true)
+    }
+
+    /// Returns the lower bound of the range.
+    pub fn low(&self) -> Measurement<U> {
+        Measurement::new(self.low)
+    }
+
+    /// Returns the upper bound of the range.
+    pub fn high(&self) -> Measurement<U> {
+        Measurement::new(self.high)
+    }
+}
+
+impl<U> Distribution<Measurement<U>> for MeasurementRange<U> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Measurement<U> {
+        if self.low == self.high {
+            return Measurement::new(self.low);
+        }
+
+        // Rounding may take the exponential to the upper bound, which is
+        // outside the range.
+        let value = if self.logarithmic {
+            rng.gen_range(self.low.ln()..self.high.ln())
+                .exp()
+                .clamp(self.low, self.high.next_down())
+        } else {
+            rng.gen_range(self.low..self.high)
+        };
+
+        Measurement::new(value)
+    }
+}
+
+impl<U> Clone for MeasurementRange<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U> Copy for MeasurementRange<U> {}
+
+impl<U: MeasureUnit> std::fmt::Debug for MeasurementRange<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MeasurementRange")
+            .field("low", &self.low())
+            .field("high", &self.high())
+            .field("logarithmic", &self.logarithmic)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+    use super::MeasurementRange;
+    use crate::Measurement;
+
+    crate::define_unit!(Millisecond, "ms");
+    crate::define_unit!(Second, "s");
+
+    crate::define_linear_conversions! {
+        quantity: "time";
+
+        (Millisecond, 1),
+        (Second, 1000 Millisecond)
+    }
+
+    #[test]
+    fn test_uniform() {
+        let range = MeasurementRange::<Millisecond>::uniform(
+            Measurement::<Millisecond>::new(500.0),
+            Measurement::<Second>::new(2.0),
+        );
+        let mut rng = StdRng::seed_from_u64(7);
+
+        assert_eq!(2000.0, range.high().value());
+        for _ in 0..1000 {
+            let value = range.sample(&mut rng).value();
+            assert!((500.0..2000.0).contains(&value));
+        }
+
+        let fixed = MeasurementRange::<Second>::uniform(
+            Measurement::<Second>::new(1.0),
+            Measurement::<Millisecond>::new(1000.0),
+        );
+        assert_eq!(1.0, fixed.sample(&mut rng).value());
+    }
+
+    #[test]
+    fn test_log_uniform() {
+        let range = MeasurementRange::<Millisecond>::log_uniform(
+            Measurement::<Millisecond>::new(1.0),
+            Measurement::<Second>::new(1.0),
+        );
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let values: Vec<f64> = (0..3000).map(|_| range.sample(&mut rng).value()).collect();
+        assert!(values.iter().all(|value| (1.0..1000.0).contains(value)));
+
+        // Each decade is drawn about a third of the times.
+        let below_ten = values.iter().filter(|value| **value < 10.0).count();
+        assert!((800..1200).contains(&below_ten));
+    }
+
+    #[test]
+    fn test_log_uniform_excludes_high() {
+        let high = 1.0f64.next_up();
+        let range = MeasurementRange::<Second>::log_uniform(
+            Measurement::<Second>::new(1.0),
+            Measurement::<Second>::new(high),
+        );
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..1000 {
+            assert_eq!(1.0, range.sample(&mut rng).value());
+        }
+    }
+}
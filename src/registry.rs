@@ -0,0 +1,595 @@
+//! Units registered at runtime, for applications that need to handle
+//! site-specific units (e.g a "blob" of 37.5 MB) without recompiling
+//! the unit definitions. Registered units are honored everywhere the
+//! built-in units are looked up by symbol, like
+//! [`parse_any`](crate::parse::parse_any).
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    sync::{LazyLock, RwLock},
+};
+
+#[cfg(feature = "data")]
+use crate::dimension::Data;
+#[cfg(feature = "rate")]
+use crate::dimension::Quotient;
+#[cfg(feature = "time")]
+use crate::dimension::Time;
+#[cfg(any(feature = "time", feature = "data"))]
+use crate::dynamic::StaticUnit;
+use crate::{
+    dimension::Dimension,
+    dynamic::{AnyMeasurement, DynUnit},
+    units, Canonical, FromUnit, MeasureUnit, Measurement, Number,
+};
+
+/// Registered units. They are leaked on registration, so lookups can
+/// hand out `'static` references to them.
+static REGISTRY: RwLock<Vec<&'static dyn DynUnit>> = RwLock::new(Vec::new());
+
+/// Conversion factors already resolved, indexed by the symbols of the
+/// source and target units. Failed resolutions are not cached, since
+/// the symbols may be registered later.
+#[derive(Default)]
+struct FactorCache {
+    /// Incremented whenever a new unit is registered, so factors
+    /// resolved before that aren't cached afterwards.
+    generation: u64,
+    factors: HashMap<String, HashMap<String, f64>>,
+}
+
+static FACTORS: LazyLock<RwLock<FactorCache>> = LazyLock::new(Default::default);
+
+/// Unit defined at runtime as a multiple of the base unit of an
+/// existing quantity.
+#[derive(Debug, Clone)]
+pub struct RuntimeUnit {
+    symbol: Cow<'static, str>,
+    quantity: Cow<'static, str>,
+    factor_to_base: f64,
+}
+
+impl DynUnit for RuntimeUnit {
+    fn symbol(&self) -> Cow<'static, str> {
+        self.symbol.clone()
+    }
+
+    fn quantity(&self) -> Cow<'static, str> {
+        self.quantity.clone()
+    }
+
+    fn value_to_base(&self, value: f64) -> f64 {
+        value * self.factor_to_base
+    }
+
+    fn value_from_base(&self, value: f64) -> f64 {
+        value / self.factor_to_base
+    }
+}
+
+/// Error returned when a unit cannot be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterError {
+    /// There is already a unit with the same symbol.
+    DuplicateSymbol(String),
+    /// The equivalence of the unit is not a positive, finite number,
+    /// or is expressed in a unit related to the base one by an offset,
+    /// like degrees Celsius.
+    InvalidFactor(String),
+}
+
+impl Display for RegisterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterError::DuplicateSymbol(symbol) => {
+                write!(f, "duplicate unit symbol: {:?}", symbol)
+            }
+            RegisterError::InvalidFactor(symbol) => {
+                write!(f, "invalid conversion factor for unit {:?}", symbol)
+            }
+        }
+    }
+}
+
+impl Error for RegisterError {}
+
+/// Registers a new unit with the given symbol, equivalent to the given
+/// measurement, e.g `register_unit("blob", Measurement::<Megabyte>::new(37.5))`.
+/// The new unit measures the same quantity as the measurement, and
+/// lives until the end of the program.
+pub fn register_unit(
+    symbol: impl Into<Cow<'static, str>>,
+    equivalent: impl Into<AnyMeasurement>,
+) -> Result<&'static dyn DynUnit, RegisterError> {
+    let symbol = symbol.into();
+    let equivalent = equivalent.into();
+    let factor_to_base = equivalent.base_value();
+
+    if !(factor_to_base.is_finite() && factor_to_base > 0.0 && equivalent.unit().is_linear()) {
+        return Err(RegisterError::InvalidFactor(symbol.into_owned()));
+    }
+
+    // Held during the whole registration, so two threads can't register
+    // the same symbol at once.
+    let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+
+    if units::find_builtin_unit(&symbol).is_some()
+        || registry
+            .iter()
+            .any(|unit| unit.symbol() == symbol || unit.ascii_symbol() == symbol)
+    {
+        return Err(RegisterError::DuplicateSymbol(symbol.into_owned()));
+    }
+
+    let unit: &'static dyn DynUnit = Box::leak(Box::new(RuntimeUnit {
+        symbol,
+        quantity: equivalent.unit().quantity(),
+        factor_to_base,
+    }));
+
+    registry.push(unit);
+    let mut cache = FACTORS.write().unwrap_or_else(|e| e.into_inner());
+    cache.generation += 1;
+    cache.factors.clear();
+    Ok(unit)
+}
+
+/// Looks up the unit registered at runtime with the given symbol, or
+/// with the given ASCII fallback of its symbol.
+pub fn find_registered_unit(symbol: &str) -> Option<&'static dyn DynUnit> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .find(|unit| unit.symbol() == symbol || unit.ascii_symbol() == symbol)
+        .copied()
+}
+
+/// Returns all the units registered at runtime, in registration order.
+pub fn registered_units() -> Vec<&'static dyn DynUnit> {
+    REGISTRY.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Conventional unit for displaying the measurements of a dimension,
+/// so generic code holding a measurement of any of its units, like
+/// some data size, can display it without knowing the concrete unit.
+/// It is also the built-in [preferred unit](preferred_unit) of the
+/// quantity, e.g mebibytes for data:
+///
+/// ```
+/// # #[cfg(feature = "data")] {
+/// use strong_units::{units::Kibibyte, Measurement};
+///
+/// let size = Measurement::<Kibibyte>::new(3072.0);
+/// assert_eq!("3 MiB", size.into_preferred_unit().to_string());
+/// # }
+/// ```
+pub trait PreferredDisplayUnit: Dimension {
+    /// Unit the measurements of the dimension are displayed in.
+    type Unit: MeasureUnit<Dimension = Self>;
+}
+
+/// Preferred display unit of the dimension of the unit U.
+pub type PreferredUnit<U> = <<U as MeasureUnit>::Dimension as PreferredDisplayUnit>::Unit;
+
+#[cfg(feature = "time")]
+impl PreferredDisplayUnit for Time {
+    type Unit = units::Second;
+}
+
+#[cfg(feature = "data")]
+impl PreferredDisplayUnit for Data {
+    type Unit = units::Mebibyte;
+}
+
+#[cfg(feature = "rate")]
+impl PreferredDisplayUnit for Quotient<Data, Time> {
+    type Unit = units::Bps;
+}
+
+impl<U: MeasureUnit, T: Number> Measurement<U, T>
+where
+    U::Dimension: PreferredDisplayUnit,
+{
+    /// Converts the current measurement into the preferred display
+    /// unit of its dimension. See [`PreferredDisplayUnit`].
+    pub fn into_preferred_unit(self) -> Measurement<PreferredUnit<U>, T>
+    where
+        Canonical<PreferredUnit<U>>: FromUnit<U>,
+    {
+        self.into_unit()
+    }
+}
+
+/// Built-in preferred units, the [`PreferredDisplayUnit`] of each
+/// dimension, used unless overridden.
+const BUILTIN_PREFERRED: &[&dyn DynUnit] = &[
+    #[cfg(feature = "time")]
+    &StaticUnit::<<Time as PreferredDisplayUnit>::Unit>::new(),
+    #[cfg(feature = "data")]
+    &StaticUnit::<<Data as PreferredDisplayUnit>::Unit>::new(),
+    #[cfg(feature = "rate")]
+    &StaticUnit::<<Quotient<Data, Time> as PreferredDisplayUnit>::Unit>::new(),
+];
+
+/// Units preferred for displaying each quantity at runtime, overriding
+/// the built-in ones. The ones used by the whole process are managed
+/// through [`set_preferred_unit`] and [`clear_preferred_unit`], but
+/// separate sets can be kept as well, e.g one per user.
+#[derive(Debug, Default)]
+pub struct PreferredUnits {
+    units: RwLock<Vec<&'static dyn DynUnit>>,
+}
+
+impl PreferredUnits {
+    /// Creates a new set, with only the built-in preferred units.
+    pub const fn new() -> Self {
+        Self {
+            units: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Sets the given unit as the preferred one for displaying the
+    /// quantity it measures, replacing the previous one.
+    pub fn set(&self, unit: &'static dyn DynUnit) {
+        let mut units = self.units.write().unwrap_or_else(|e| e.into_inner());
+        let quantity = unit.quantity();
+
+        units.retain(|current| current.quantity() != quantity);
+        units.push(unit);
+    }
+
+    /// Removes the preferred unit set for the given quantity, if any,
+    /// falling back to the built-in one.
+    pub fn clear(&self, quantity: &str) {
+        self.units
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|current| current.quantity() != quantity);
+    }
+
+    /// Returns the unit preferred for displaying the given quantity, if
+    /// any, either set or built-in.
+    pub fn get(&self, quantity: &str) -> Option<&'static dyn DynUnit> {
+        let units = self.units.read().unwrap_or_else(|e| e.into_inner());
+
+        units
+            .iter()
+            .chain(BUILTIN_PREFERRED)
+            .find(|unit| unit.quantity() == quantity)
+            .copied()
+    }
+
+    /// Expresses the given measurement in the unit preferred for
+    /// displaying its quantity, or returns it unchanged if there is
+    /// none.
+    pub fn express(&self, measurement: AnyMeasurement) -> AnyMeasurement {
+        match self.get(&measurement.unit().quantity()) {
+            Some(unit) => AnyMeasurement::new(unit.value_from_base(measurement.base_value()), unit),
+            None => measurement,
+        }
+    }
+}
+
+/// Preferred units of the whole process.
+static PREFERRED: PreferredUnits = PreferredUnits::new();
+
+/// Sets the given unit as the preferred one for displaying the
+/// quantity it measures in the whole process, replacing the previous
+/// one. See [`PreferredUnits::set`].
+pub fn set_preferred_unit(unit: &'static dyn DynUnit) {
+    PREFERRED.set(unit);
+}
+
+/// Removes the preferred unit set for the given quantity in the whole
+/// process, if any, falling back to the built-in one.
+pub fn clear_preferred_unit(quantity: &str) {
+    PREFERRED.clear(quantity);
+}
+
+/// Returns the unit preferred for displaying the given quantity in the
+/// whole process, if any. Unless set through [`set_preferred_unit`],
+/// it is the [`PreferredDisplayUnit`] of the quantity: seconds for
+/// time, mebibytes for data and bits per second for transmission
+/// rates.
+pub fn preferred_unit(quantity: &str) -> Option<&'static dyn DynUnit> {
+    PREFERRED.get(quantity)
+}
+
+impl AnyMeasurement {
+    /// Expresses this measurement in the unit preferred for displaying
+    /// its quantity (see [`preferred_unit`]), or returns it unchanged
+    /// if there is none.
+    pub fn to_preferred_unit(self) -> AnyMeasurement {
+        PREFERRED.express(self)
+    }
+}
+
+/// Returns the factor that converts values expressed in the unit with
+/// the symbol `from` into the unit with the symbol `to`, looking up
+/// both built-in and registered units. Returns None if any of the
+/// units is unknown, if they measure different quantities, or if any
+/// of them is related to the base unit by an offset, like degrees
+/// Celsius, which are converted by [`convert`] instead.
+///
+/// Factors are memoized, so repeated conversions between the same
+/// units don't have to look them up again.
+pub fn conversion_factor(from: &str, to: &str) -> Option<f64> {
+    let generation = {
+        let cache = FACTORS.read().unwrap_or_else(|e| e.into_inner());
+        let cached = cache.factors.get(from).and_then(|factors| factors.get(to));
+        if let Some(factor) = cached {
+            return Some(*factor);
+        }
+
+        cache.generation
+    };
+
+    let factor = resolve_factor(from, to)?;
+    let mut cache = FACTORS.write().unwrap_or_else(|e| e.into_inner());
+    if cache.generation == generation {
+        cache
+            .factors
+            .entry(from.to_owned())
+            .or_default()
+            .insert(to.to_owned(), factor);
+    }
+
+    Some(factor)
+}
+
+fn resolve_factor(from: &str, to: &str) -> Option<f64> {
+    let (from, to) = (units::find_unit(from)?, units::find_unit(to)?);
+    if from.quantity() != to.quantity() || !(from.is_linear() && to.is_linear()) {
+        return None;
+    }
+
+    Some(to.value_from_base(from.value_to_base(1.0)))
+}
+
+/// Converts the given value between the units with the given
+/// symbols. See [`conversion_factor`].
+pub fn convert(value: f64, from: &str, to: &str) -> Option<f64> {
+    if let Some(factor) = conversion_factor(from, to) {
+        return Some(value * factor);
+    }
+
+    // Units related by an offset, which have no factor.
+    let (from, to) = (units::find_unit(from)?, units::find_unit(to)?);
+    (from.quantity() == to.quantity()).then(|| to.value_from_base(from.value_to_base(value)))
+}
+
+/// Factor of a unit in a [`FactorTable`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitFactor {
+    pub symbol: String,
+    pub name: String,
+    /// Factor that converts a value expressed in the unit into the base
+    /// unit of the quantity.
+    pub factor_to_base: f64,
+    /// Offset added after applying the factor, which is only non-zero
+    /// for units like degrees Celsius.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub offset_to_base: f64,
+}
+
+/// Conversion factors of all the units of a quantity, both built-in
+/// and registered at runtime, as plain data that can be exported to
+/// external tools (docs generators, UIs, bindings...). They are exactly
+/// the factors used by the crate for converting between them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FactorTable {
+    pub quantity: String,
+    /// Units of the quantity, the built-in ones first, in the order of
+    /// [`UNIT_SETS`](crate::units::UNIT_SETS).
+    pub units: Vec<UnitFactor>,
+}
+
+impl FactorTable {
+    fn unit(&self, symbol: &str) -> Option<&UnitFactor> {
+        self.units.iter().find(|unit| unit.symbol == symbol)
+    }
+
+    /// Returns the factor that converts values expressed in the unit
+    /// with the symbol `from` into the unit with the symbol `to`, if
+    /// both are in the table. For units related by an offset, like
+    /// degrees Celsius, it only converts differences between values;
+    /// see [`FactorTable::convert`].
+    pub fn factor(&self, from: &str, to: &str) -> Option<f64> {
+        Some(self.unit(from)?.factor_to_base / self.unit(to)?.factor_to_base)
+    }
+
+    /// Converts the given value between the units with the given
+    /// symbols, applying their offsets, if both are in the table.
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Option<f64> {
+        let (from, to) = (self.unit(from)?, self.unit(to)?);
+        let base = value * from.factor_to_base + from.offset_to_base;
+        Some((base - to.offset_to_base) / to.factor_to_base)
+    }
+
+    /// Returns the matrix of the factors between every pair of units,
+    /// where `matrix[i][j]` converts values expressed in the unit `i`
+    /// into the unit `j`. See [`FactorTable::factor`].
+    pub fn matrix(&self) -> Vec<Vec<f64>> {
+        self.units
+            .iter()
+            .map(|from| {
+                self.units
+                    .iter()
+                    .map(|to| from.factor_to_base / to.factor_to_base)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Returns the conversion factors of all the units measuring the given
+/// quantity, e.g "data", or None if there are none.
+pub fn factor_table(quantity: &str) -> Option<FactorTable> {
+    factor_tables()
+        .into_iter()
+        .find(|table| table.quantity == quantity)
+}
+
+/// Returns the conversion factors of the units of every quantity, in
+/// the order their units are first found in
+/// [`UNIT_SETS`](crate::units::UNIT_SETS), followed by the quantities
+/// only measured by units registered at runtime.
+pub fn factor_tables() -> Vec<FactorTable> {
+    let builtin = units::UNIT_SETS
+        .iter()
+        .flat_map(|units| units.iter().copied());
+    let mut tables: Vec<FactorTable> = Vec::new();
+
+    for unit in builtin.chain(registered_units()) {
+        let quantity = unit.quantity();
+        let offset_to_base = unit.value_to_base(0.0);
+        let entry = UnitFactor {
+            symbol: unit.symbol().into_owned(),
+            name: unit.name().into_owned(),
+            factor_to_base: unit.value_to_base(1.0) - offset_to_base,
+            offset_to_base,
+        };
+
+        match tables.iter_mut().find(|table| table.quantity == quantity) {
+            Some(table) => table.units.push(entry),
+            None => tables.push(FactorTable {
+                quantity: quantity.into_owned(),
+                units: vec![entry],
+            }),
+        }
+    }
+
+    tables
+}
+
+#[cfg(all(test, feature = "data"))]
+mod tests {
+    use super::{
+        conversion_factor, convert, factor_table, factor_tables, preferred_unit, register_unit,
+        PreferredUnits, RegisterError, FACTORS,
+    };
+    use crate::{
+        dynamic::{dyn_unit, AnyMeasurement},
+        parse::parse_any,
+        units::{find_unit, Byte, Kibibyte, Kilobyte, Mebibyte, Megabyte},
+        Measurement,
+    };
+
+    #[test]
+    fn test_register_unit() {
+        let blob = register_unit("blob", Measurement::<Megabyte>::new(37.5)).unwrap();
+
+        assert_eq!("data", blob.quantity());
+        assert_eq!(600_000_000.0, blob.value_to_base(2.0));
+        assert_eq!("blob", find_unit("blob").unwrap().symbol());
+
+        let parsed = parse_any("2 blob").unwrap();
+        assert_eq!("2 blob", parsed.to_string());
+        assert_eq!(
+            AnyMeasurement::from(Measurement::<Megabyte>::new(75.0)),
+            parsed
+        );
+    }
+
+    #[test]
+    fn test_register_errors() {
+        assert_eq!(
+            Err(RegisterError::DuplicateSymbol("MB".to_owned())),
+            register_unit("MB", Measurement::<Byte>::new(1.0)).map(|_| ())
+        );
+        assert_eq!(
+            Err(RegisterError::InvalidFactor("nothing".to_owned())),
+            register_unit("nothing", Measurement::<Byte>::new(0.0)).map(|_| ())
+        );
+
+        register_unit("cluster", Measurement::<Byte>::new(32_768.0)).unwrap();
+        assert_eq!(
+            Err(RegisterError::DuplicateSymbol("cluster".to_owned())),
+            register_unit("cluster", Measurement::<Byte>::new(4096.0)).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_conversion_factor() {
+        assert_eq!(Some(1000.0), conversion_factor("MB", "KB"));
+        assert_eq!(Some(8.0), convert(1.0, "B", "b"));
+        assert_eq!(None, conversion_factor("MB", "parsecs"));
+
+        // Failures are not cached.
+        let cache = FACTORS.read().unwrap();
+        assert!(cache
+            .factors
+            .get("MB")
+            .is_none_or(|factors| !factors.contains_key("parsecs")));
+        drop(cache);
+
+        // Unknown units resolve once they are registered.
+        assert_eq!(None, conversion_factor("crate", "KB"));
+        register_unit("crate", Measurement::<Megabyte>::new(2.0)).unwrap();
+        assert_eq!(Some(2000.0), conversion_factor("crate", "KB"));
+        assert_eq!(Some(2.0), convert(4000.0, "KB", "crate"));
+    }
+
+    #[test]
+    fn test_preferred_unit() {
+        let preferred = PreferredUnits::new();
+        let size: AnyMeasurement = Measurement::<Kibibyte>::new(2048.0).into();
+        assert_eq!("2 MiB", preferred.express(size).to_string());
+
+        preferred.set(dyn_unit::<Byte>());
+        assert_eq!("B", preferred.get("data").unwrap().symbol());
+        assert_eq!("2097152 B", preferred.express(size).to_string());
+        // The units of the whole process are left untouched.
+        assert_eq!("MiB", preferred_unit("data").unwrap().symbol());
+
+        preferred.clear("data");
+        assert_eq!("MiB", preferred.get("data").unwrap().symbol());
+        assert!(preferred.get("length").is_none());
+    }
+
+    #[test]
+    fn test_into_preferred_unit() {
+        let size = Measurement::<Kilobyte>::new(4194.304);
+        assert_eq!(4.0, size.into_preferred_unit().value());
+        assert_eq!(
+            Measurement::<Mebibyte, u64>::new(2),
+            Measurement::<Kibibyte, u64>::new(2048).into_preferred_unit()
+        );
+    }
+
+    #[test]
+    fn test_factor_table() {
+        register_unit("tome", Measurement::<Megabyte>::new(4.0)).unwrap();
+        let table = factor_table("data").unwrap();
+
+        assert_eq!("b", table.units[0].symbol);
+        assert_eq!("bit", table.units[0].name);
+        assert_eq!(1.0, table.units[0].factor_to_base);
+        assert_eq!(Some(1024.0), table.factor("KiB", "B"));
+        assert_eq!(Some(4.0), table.factor("tome", "MB"));
+        assert_eq!(
+            conversion_factor("tome", "KiB"),
+            table.factor("tome", "KiB")
+        );
+        assert_eq!(None, table.factor("tome", "s"));
+
+        let matrix = table.matrix();
+        let index = |symbol| table.units.iter().position(|unit| unit.symbol == symbol);
+        assert_eq!(8.0, matrix[index("B").unwrap()][index("b").unwrap()]);
+
+        assert!(factor_table("parsecs").is_none());
+        assert_eq!(
+            1,
+            factor_tables()
+                .iter()
+                .filter(|table| table.quantity == "data")
+                .count()
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Serialization of measurements through serde. Measurements are
+//! serialized along with the symbol of their unit, e.g
+//! `{ "value": 42.0, "unit": "Kbps" }`, and deserialized from any unit
+//! of the same quantity, converting the value into the unit of the
+//! measurement:
+//!
+//! ```
+//! # #[cfg(feature = "rate")] {
+//! use serde::{Deserialize, Serialize};
+//! use strong_units::{
+//!     units::{Kbps, Second},
+//!     Measurement,
+//! };
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Link {
+//!     rate: Measurement<Kbps>,
+//!     #[serde(with = "strong_units::serialization::value")]
+//!     latency: Measurement<Second>,
+//! }
+//!
+//! let link: Link = toml::from_str(
+//!     r#"
+//!     rate = { value = 1.5, unit = "Mbps" }
+//!     latency = 0.25
+//!     "#,
+//! )
+//! .unwrap();
+//! assert_eq!(1500.0, link.rate.value());
+//!
+//! let serialized = toml::to_string(&link).unwrap();
+//! assert!(serialized.contains("latency = 0.25"));
+//! assert!(serialized.contains("unit = \"Kbps\""));
+//! # }
+//! ```
+//!
+//! Fields serialized through [`value`] are written as bare numbers
+//! expressed in the unit of the field instead, for formats or peers
+//! that already know the unit.
+
+use std::borrow::Cow;
+
+use serde::{
+    de::{self, Deserializer},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+
+use crate::{
+    dynamic::{dyn_unit, AnyMeasurement},
+    parse::{detect_unit, ParseError},
+    BaseConversion, MeasureUnit, Measurement, Number,
+};
+
+impl<U: MeasureUnit, T: Number + Serialize> Serialize for Measurement<U, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Measurement", 2)?;
+        state.serialize_field("value", &self.value())?;
+        state.serialize_field("unit", &U::symbol())?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Measurement", deny_unknown_fields)]
+struct Tagged<'a, T> {
+    value: T,
+    #[serde(borrow)]
+    unit: Cow<'a, str>,
+}
+
+/// Measurements are deserialized from the value and the symbol of its
+/// unit. Values expressed in U are taken as they are, and values of
+/// any other unit of the same quantity are converted into U, which goes
+/// through f64 as described in [`Number`]. Like serialization, it is
+/// supported for the measurements of every unit, including the ones
+/// related by an offset like degrees Celsius.
+impl<'de, U: MeasureUnit + 'static, T: Number + Deserialize<'de>> Deserialize<'de>
+    for Measurement<U, T>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Tagged { value, unit } = Tagged::<T>::deserialize(deserializer)?;
+        if unit == U::symbol() || unit == U::ascii_symbol() {
+            return Ok(Measurement::new(value));
+        }
+
+        let expected = dyn_unit::<U>().quantity();
+        let found = detect_unit(&unit, Some(&expected))
+            .ok_or_else(|| de::Error::custom(ParseError::UnknownUnit(unit.into_owned())))?;
+
+        AnyMeasurement::new(value.to_f64(), found)
+            .convert::<U>()
+            .map(|measurement| measurement.cast())
+            .ok_or_else(|| {
+                de::Error::custom(ParseError::WrongQuantity {
+                    expected: expected.into_owned(),
+                    found: found.quantity().into_owned(),
+                })
+            })
+    }
+}
+
+/// Serialization of measurements as bare numbers expressed in the unit
+/// of the measurement, through serde's `with` attribute, e.g
+/// `#[serde(with = "strong_units::serialization::value")]`.
+pub mod value {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{Measurement, Number};
+
+    /// Serializes the value of the measurement, without its unit.
+    pub fn serialize<S, U, T>(
+        measurement: &Measurement<U, T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Number + Serialize,
+    {
+        measurement.value().serialize(serializer)
+    }
+
+    /// Deserializes a bare number as a measurement expressed in U.
+    pub fn deserialize<'de, D, U, T>(deserializer: D) -> Result<Measurement<U, T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Number + Deserialize<'de>,
+    {
+        T::deserialize(deserializer).map(Measurement::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[cfg(feature = "rate")]
+    use crate::units::{Byte, Kbps, Kilobyte, Second};
+    use crate::Measurement;
+
+    crate::define_unit!(Step, "step");
+    crate::define_unit!(Stride, "stride", ascii: "str");
+
+    crate::define_linear_conversions! {
+        quantity: "pace";
+
+        (Step, 1),
+        (Stride, 2 Step)
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Walk {
+        length: Measurement<Stride>,
+        #[serde(with = "super::value")]
+        rest: Measurement<Step, u32>,
+    }
+
+    #[test]
+    fn test_round_trip_custom_units() {
+        let walk = Walk {
+            length: Measurement::new(12.5),
+            rest: Measurement::new(3),
+        };
+
+        let serialized = toml::to_string(&walk).unwrap();
+        assert!(serialized.contains("rest = 3\n"));
+        assert!(serialized.contains("value = 12.5\nunit = \"stride\"\n"));
+        assert_eq!(walk, toml::from_str(&serialized).unwrap());
+
+        let walk: Walk =
+            toml::from_str("rest = 1\nlength = { value = 2, unit = \"str\" }").unwrap();
+        assert_eq!(2.0, walk.length.value());
+
+        let error = toml::from_str::<Walk>("rest = 1\nlength = { value = 2, unit = \"lap\" }")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown unit: \"lap\""));
+    }
+
+    #[cfg(feature = "rate")]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Transfer {
+        size: Measurement<Kilobyte>,
+        rate: Measurement<Kbps>,
+        sent: Measurement<Byte, u64>,
+        #[serde(with = "super::value")]
+        elapsed: Measurement<Second>,
+    }
+
+    #[cfg(feature = "rate")]
+    #[test]
+    fn test_round_trip() {
+        let transfer = Transfer {
+            size: Measurement::new(2.5),
+            rate: Measurement::new(800.0),
+            sent: Measurement::new(1 << 40),
+            elapsed: Measurement::new(25.0),
+        };
+
+        let serialized = toml::to_string(&transfer).unwrap();
+        assert!(serialized.contains("elapsed = 25.0\n"));
+        assert!(serialized.contains("value = 2.5\nunit = \"KB\"\n"));
+        assert_eq!(transfer, toml::from_str(&serialized).unwrap());
+    }
+
+    #[cfg(feature = "rate")]
+    #[test]
+    fn test_deserialize_converts() {
+        let transfer: Transfer = toml::from_str(
+            r#"
+            size = { value = 1.5, unit = "MB" }
+            rate = { value = 1, unit = "Mb/s" }
+            sent = { value = 3, unit = "KB" }
+            elapsed = 2
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(1500.0, transfer.size.value());
+        assert_eq!(1000.0, transfer.rate.value());
+        assert_eq!(3000, transfer.sent.value());
+        assert_eq!(2.0, transfer.elapsed.value());
+    }
+
+    #[cfg(feature = "rate")]
+    #[test]
+    fn test_deserialize_errors() {
+        let error = |input: &str| toml::from_str::<Transfer>(input).unwrap_err().to_string();
+        let fields = "rate = { value = 1, unit = \"Kbps\" }\nsent = { value = 1, unit = \"B\" }\nelapsed = 1\n";
+
+        assert!(error(&format!(
+            "size = {{ value = 1, unit = \"parsec\" }}\n{}",
+            fields
+        ))
+        .contains("unknown unit: \"parsec\""));
+        assert!(
+            error(&format!("size = {{ value = 1, unit = \"s\" }}\n{}", fields))
+                .contains("expected a measurement of data, found time")
+        );
+        assert!(error(&format!("size = 1\n{}", fields)).contains("size"));
+    }
+
+    #[cfg(feature = "temperature")]
+    #[test]
+    fn test_temperature_round_trip() {
+        use crate::units::{Celsius, Kelvin};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Reading {
+            temperature: Measurement<Celsius>,
+        }
+
+        let reading = Reading {
+            temperature: Measurement::new(21.5),
+        };
+        let serialized = toml::to_string(&reading).unwrap();
+        assert!(serialized.contains("unit = \"°C\""));
+        assert_eq!(reading, toml::from_str(&serialized).unwrap());
+
+        let kelvin: Measurement<Kelvin> =
+            toml::from_str::<Reading>("temperature = { value = 0, unit = \"degC\" }")
+                .map(|reading| reading.temperature.into_unit())
+                .unwrap();
+        assert_eq!(273.15, kelvin.value());
+
+        let converted: Reading =
+            toml::from_str("temperature = { value = 212, unit = \"°F\" }").unwrap();
+        assert!((converted.temperature.value() - 100.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,357 @@
+//! Utilities for processing series of timestamped measurements.
+
+#[cfg(feature = "rate")]
+use crate::units::{Bit, Bps};
+use crate::{units::Second, Canonical, DivUnit, FromUnit, MeasureUnit, Measurement};
+
+/// Method used for estimating the value of a series between samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Holds the value of the latest sample.
+    Previous,
+    /// Takes the value of the closest sample in time.
+    Nearest,
+    /// Interpolates linearly between the surrounding samples.
+    Linear,
+}
+
+/// Integrates a series of rate samples over time using the
+/// trapezoidal rule, e.g turning a bandwidth trace into the total
+/// amount of data transferred. Each sample is made of the time since
+/// an arbitrary origin and the rate at that moment. The samples must
+/// be sorted by time.
+pub fn integrate<U, T, R, I>(samples: I) -> Measurement<U>
+where
+    U: MeasureUnit,
+    T: MeasureUnit,
+    R: MeasureUnit,
+    Second: FromUnit<T>,
+    Canonical<DivUnit<U, Second>>: FromUnit<R>,
+    I: IntoIterator<Item = (Measurement<T>, Measurement<R>)>,
+{
+    let mut samples = samples.into_iter().map(|(time, rate)| {
+        let time: Measurement<Second> = time.into_unit();
+        let rate: Measurement<DivUnit<U, Second>> = rate.into_unit();
+        (time.value(), rate.value())
+    });
+
+    let Some(mut previous) = samples.next() else {
+        return Measurement::new(0.0);
+    };
+
+    let mut total = 0.0;
+    for current in samples {
+        total += (current.0 - previous.0) * (previous.1 + current.1) / 2.0;
+        previous = current;
+    }
+
+    Measurement::new(total)
+}
+
+/// Resamples a series of irregularly spaced samples onto a grid of
+/// fixed intervals, starting at the time of the first sample and
+/// ending at or before the last one. The value at each point of the
+/// grid is estimated using the given interpolation. Samples taken at a
+/// non-finite time are ignored. Returns an empty series if the
+/// interval is not a positive number.
+pub fn resample<T, U, I, S>(
+    samples: S,
+    interval: Measurement<I>,
+    interpolation: Interpolation,
+) -> Vec<(Measurement<T>, Measurement<U>)>
+where
+    T: MeasureUnit,
+    I: MeasureUnit,
+    T::AliasedUnit: FromUnit<I>,
+    S: IntoIterator<Item = (Measurement<T>, Measurement<U>)>,
+{
+    let interval = interval.into_unit::<T>().value();
+    let mut samples: Vec<(f64, f64)> = samples
+        .into_iter()
+        .map(|(time, value)| (time.value(), value.value()))
+        .filter(|(time, _)| time.is_finite())
+        .collect();
+    samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let (Some(first), Some(last)) = (samples.first(), samples.last()) else {
+        return Vec::new();
+    };
+
+    if !(interval.is_finite() && interval > 0.0) {
+        return Vec::new();
+    }
+
+    let (start, end) = (first.0, last.0);
+    let mut result = Vec::new();
+    let mut next = 0;
+
+    for step in 0.. {
+        let time = start + interval * step as f64;
+        if time > end {
+            break;
+        }
+
+        // Index of the first sample after the current time.
+        while next < samples.len() && samples[next].0 <= time {
+            next += 1;
+        }
+
+        let before = samples[next - 1];
+        let value = match samples.get(next) {
+            None => before.1,
+            Some(after) => match interpolation {
+                Interpolation::Previous => before.1,
+                Interpolation::Nearest if time - before.0 <= after.0 - time => before.1,
+                Interpolation::Nearest => after.1,
+                Interpolation::Linear => {
+                    let progress = (time - before.0) / (after.0 - before.0);
+                    before.1 + (after.1 - before.1) * progress
+                }
+            },
+        };
+
+        result.push((Measurement::new(time), Measurement::new(value)));
+    }
+
+    result
+}
+
+/// Computes the throughput of a recorded series of transfer events
+/// (each one the time it happened at and the amount of data
+/// transferred) over a window of the given size that slides by the
+/// given step. Each point of the result is the start of a window and
+/// the average rate within it, covering from the first event until a
+/// window starts after the last one. Events happening at a non-finite
+/// time are ignored. Returns an empty series if the window or the
+/// step are not positive numbers.
+#[cfg(feature = "rate")]
+pub fn sliding_throughput<T, D, W, P, E>(
+    events: E,
+    window: Measurement<W>,
+    step: Measurement<P>,
+) -> Vec<(Measurement<T>, Measurement<Bps>)>
+where
+    T: MeasureUnit,
+    D: MeasureUnit,
+    W: MeasureUnit,
+    P: MeasureUnit,
+    Bit: FromUnit<D>,
+    Second: FromUnit<T> + FromUnit<W> + FromUnit<P>,
+    T::AliasedUnit: FromUnit<Second>,
+    E: IntoIterator<Item = (Measurement<T>, Measurement<D>)>,
+{
+    let window = window.into_unit::<Second>().value();
+    let step = step.into_unit::<Second>().value();
+    let mut events: Vec<(f64, f64)> = events
+        .into_iter()
+        .map(|(time, data)| {
+            let time: Measurement<Second> = time.into_unit();
+            let data: Measurement<Bit> = data.into_unit();
+            (time.value(), data.value())
+        })
+        .filter(|(time, _)| time.is_finite())
+        .collect();
+    events.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let valid = |x: f64| x.is_finite() && x > 0.0;
+    let (Some(first), Some(last)) = (events.first(), events.last()) else {
+        return Vec::new();
+    };
+
+    if !(valid(window) && valid(step)) {
+        return Vec::new();
+    }
+
+    // Cumulative amount of data transferred before each event, so the
+    // data within any window is just a difference.
+    let mut cumulative = Vec::with_capacity(events.len() + 1);
+    cumulative.push(0.0);
+    for (_, data) in &events {
+        cumulative.push(cumulative[cumulative.len() - 1] + data);
+    }
+
+    let (first, last) = (first.0, last.0);
+    let before = |time: f64| events.partition_point(|(t, _)| *t < time);
+    let mut result = Vec::new();
+
+    for index in 0.. {
+        let start = first + step * index as f64;
+        if start > last {
+            break;
+        }
+
+        let data = cumulative[before(start + window)] - cumulative[before(start)];
+        let start: Measurement<Second> = Measurement::new(start);
+        result.push((start.into_unit(), Measurement::new(data / window)));
+    }
+
+    result
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use super::{integrate, resample, sliding_throughput, Interpolation};
+    use crate::{
+        units::{Byte, Kilobit, Kilobyte, Mbps, Megabit, Minute, Second},
+        DivUnit, Measurement,
+    };
+
+    fn values<T, U>(series: Vec<(Measurement<T>, Measurement<U>)>) -> Vec<(f64, f64)> {
+        series
+            .into_iter()
+            .map(|(time, value)| (time.value(), value.value()))
+            .collect()
+    }
+
+    fn irregular() -> Vec<(Measurement<Second>, Measurement<Byte>)> {
+        [(0.0, 0.0), (20.0, 100.0), (80.0, 40.0), (100.0, 0.0)]
+            .into_iter()
+            .map(|(time, value)| (Measurement::new(time), Measurement::new(value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_integrate_constant_rate() {
+        let samples = (0..=10).map(|t| {
+            (
+                Measurement::<Second>::new(t as f64),
+                Measurement::<DivUnit<Kilobit, Second>>::new(8.0),
+            )
+        });
+
+        let total: Measurement<Kilobit> = integrate(samples);
+        assert_eq!(80.0, total.value());
+    }
+
+    #[test]
+    fn test_integrate_mixed_units() {
+        let samples = vec![
+            (
+                Measurement::<Minute>::new(0.0),
+                Measurement::<Mbps>::new(0.0),
+            ),
+            (
+                Measurement::<Minute>::new(1.0),
+                Measurement::<Mbps>::new(8.0),
+            ),
+            (
+                Measurement::<Minute>::new(2.0),
+                Measurement::<Mbps>::new(8.0),
+            ),
+        ];
+
+        let total: Measurement<Megabit> = integrate(samples);
+        assert_eq!(240.0 + 480.0, total.value());
+        assert_eq!(90_000_000.0, total.into_unit::<Byte>().value());
+    }
+
+    #[test]
+    fn test_integrate_too_few_samples() {
+        let none: Vec<(Measurement<Second>, Measurement<Mbps>)> = vec![];
+        let total: Measurement<Megabit> = integrate(none);
+        assert_eq!(0.0, total.value());
+
+        let one = vec![(
+            Measurement::<Second>::new(1.0),
+            Measurement::<Mbps>::new(1.0),
+        )];
+        let total: Measurement<Megabit> = integrate(one);
+        assert_eq!(0.0, total.value());
+    }
+
+    #[test]
+    fn test_resample() {
+        let interval = Measurement::<Minute>::new(0.5);
+
+        assert_eq!(
+            vec![(0.0, 0.0), (30.0, 100.0), (60.0, 100.0), (90.0, 40.0)],
+            values(resample(irregular(), interval, Interpolation::Previous))
+        );
+        assert_eq!(
+            vec![(0.0, 0.0), (30.0, 100.0), (60.0, 40.0), (90.0, 40.0)],
+            values(resample(irregular(), interval, Interpolation::Nearest))
+        );
+        assert_eq!(
+            vec![(0.0, 0.0), (30.0, 90.0), (60.0, 60.0), (90.0, 20.0)],
+            values(resample(irregular(), interval, Interpolation::Linear))
+        );
+    }
+
+    #[test]
+    fn test_resample_invalid_interval() {
+        let interval = Measurement::<Second>::new(0.0);
+        assert!(resample(irregular(), interval, Interpolation::Linear).is_empty());
+    }
+
+    #[test]
+    fn test_resample_non_finite_times() {
+        let interval = Measurement::<Minute>::new(0.5);
+        let mut samples = irregular();
+        samples.push((Measurement::new(f64::NAN), Measurement::new(1.0)));
+        samples.push((Measurement::new(-f64::NAN), Measurement::new(1.0)));
+        samples.push((Measurement::new(f64::INFINITY), Measurement::new(1.0)));
+        samples.push((Measurement::new(f64::NEG_INFINITY), Measurement::new(1.0)));
+
+        assert_eq!(
+            vec![(0.0, 0.0), (30.0, 100.0), (60.0, 100.0), (90.0, 40.0)],
+            values(resample(samples, interval, Interpolation::Previous))
+        );
+
+        let samples = vec![(
+            Measurement::<Second>::new(f64::NAN),
+            Measurement::<Byte>::new(1.0),
+        )];
+        assert!(resample(samples, interval, Interpolation::Linear).is_empty());
+    }
+
+    #[test]
+    fn test_sliding_throughput() {
+        let events: Vec<(Measurement<Second>, Measurement<Kilobyte>)> =
+            [(0.0, 1.0), (0.5, 1.0), (1.0, 2.0), (2.5, 4.0)]
+                .into_iter()
+                .map(|(time, data)| (Measurement::new(time), Measurement::new(data)))
+                .collect();
+
+        let window = Measurement::<Second>::new(1.0);
+        let step = Measurement::<Second>::new(0.5);
+
+        assert_eq!(
+            vec![
+                (0.0, 16_000.0),
+                (0.5, 24_000.0),
+                (1.0, 16_000.0),
+                (1.5, 0.0),
+                (2.0, 32_000.0),
+                (2.5, 32_000.0),
+            ],
+            values(sliding_throughput(events, window, step))
+        );
+    }
+
+    #[test]
+    fn test_sliding_throughput_non_finite_times() {
+        let events: Vec<(Measurement<Second>, Measurement<Kilobyte>)> = [
+            (0.0, 1.0),
+            (f64::NAN, 1.0),
+            (f64::INFINITY, 1.0),
+            (-f64::INFINITY, 1.0),
+            (1.0, 2.0),
+        ]
+        .into_iter()
+        .map(|(time, data)| (Measurement::new(time), Measurement::new(data)))
+        .collect();
+
+        let window = Measurement::<Second>::new(1.0);
+
+        assert_eq!(
+            vec![(0.0, 8_000.0), (1.0, 16_000.0)],
+            values(sliding_throughput(events, window, window))
+        );
+
+        let events = vec![(
+            Measurement::<Second>::new(f64::NAN),
+            Measurement::<Kilobyte>::new(1.0),
+        )];
+        assert!(sliding_throughput(events, window, window).is_empty());
+    }
+}
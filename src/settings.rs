@@ -0,0 +1,184 @@
+//! Parsing of settings written with their unit, e.g limits configured
+//! through environment variables like `CACHE_SIZE=512MiB`, with errors
+//! that describe which setting is wrong and why.
+//!
+//! ```
+//! # #[cfg(all(feature = "time", feature = "data"))] {
+//! use strong_units::{settings::parse_setting, units::Second, Measurement};
+//!
+//! let timeout: Measurement<Second> = parse_setting("2 min").unwrap();
+//! assert_eq!(120.0, timeout.value());
+//!
+//! let error = parse_setting::<Measurement<Second>>("2 MiB").unwrap_err();
+//! assert_eq!(
+//!     "invalid setting \"2 MiB\": expected a measurement of time, found data",
+//!     error.to_string()
+//! );
+//! # }
+//! ```
+
+use std::{env, error::Error, fmt::Display};
+
+use crate::{
+    dynamic::AnyMeasurement,
+    parse::{parse_any, parse_as, ParseError},
+    BaseConversion, MeasureUnit, Measurement,
+};
+
+/// Implemented by the types that can be parsed from settings:
+/// measurements of a given unit, which accept any unit of the same
+/// quantity, and [`AnyMeasurement`], which accepts any unit.
+pub trait FromSetting: Sized {
+    fn from_setting(input: &str) -> Result<Self, ParseError>;
+}
+
+impl<U: MeasureUnit + 'static> FromSetting for Measurement<U>
+where
+    U::AliasedUnit: BaseConversion,
+{
+    fn from_setting(input: &str) -> Result<Self, ParseError> {
+        parse_as(input)
+    }
+}
+
+impl FromSetting for AnyMeasurement {
+    fn from_setting(input: &str) -> Result<Self, ParseError> {
+        parse_any(input)
+    }
+}
+
+/// Error returned when a setting cannot be read or parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingError {
+    /// The environment variable with the given name is not set.
+    Missing(String),
+    /// The environment variable with the given name is not valid
+    /// unicode.
+    NotUnicode(String),
+    /// The value of the setting is not a valid measurement.
+    Invalid {
+        /// Name of the environment variable, if read from one.
+        name: Option<String>,
+        value: String,
+        error: ParseError,
+    },
+}
+
+impl Display for SettingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingError::Missing(name) => write!(f, "environment variable {} is not set", name),
+            SettingError::NotUnicode(name) => {
+                write!(f, "environment variable {} is not valid unicode", name)
+            }
+            SettingError::Invalid {
+                name: Some(name),
+                value,
+                error,
+            } => write!(f, "invalid value {:?} for {}: {}", value, name, error),
+            SettingError::Invalid {
+                name: None,
+                value,
+                error,
+            } => write!(f, "invalid setting {:?}: {}", value, error),
+        }
+    }
+}
+
+impl Error for SettingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SettingError::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the given setting, e.g "30s" as `Measurement<Second>`. See
+/// [`parse_as`] for the accepted formats.
+pub fn parse_setting<T: FromSetting>(value: &str) -> Result<T, SettingError> {
+    T::from_setting(value).map_err(|error| SettingError::Invalid {
+        name: None,
+        value: value.to_owned(),
+        error,
+    })
+}
+
+/// Parses the value of the environment variable with the given name,
+/// e.g `parse_env::<Measurement<Byte>>("CACHE_SIZE")`.
+pub fn parse_env<T: FromSetting>(name: &str) -> Result<T, SettingError> {
+    let value = env::var(name).map_err(|error| match error {
+        env::VarError::NotPresent => SettingError::Missing(name.to_owned()),
+        env::VarError::NotUnicode(_) => SettingError::NotUnicode(name.to_owned()),
+    })?;
+
+    T::from_setting(&value).map_err(|error| SettingError::Invalid {
+        name: Some(name.to_owned()),
+        value,
+        error,
+    })
+}
+
+/// Parses the value of the environment variable with the given name,
+/// or returns the given default if it is not set.
+pub fn parse_env_or<T: FromSetting>(name: &str, default: T) -> Result<T, SettingError> {
+    match parse_env(name) {
+        Err(SettingError::Missing(_)) => Ok(default),
+        result => result,
+    }
+}
+
+#[cfg(all(test, feature = "data"))]
+mod tests {
+    use std::env;
+
+    use super::{parse_env, parse_env_or, parse_setting, SettingError};
+    use crate::{
+        dynamic::AnyMeasurement,
+        parse::ParseError,
+        units::{Byte, Kibibyte, Mebibyte},
+        Measurement,
+    };
+
+    #[test]
+    fn test_parse_setting() {
+        let size: Measurement<Kibibyte> = parse_setting("1.5 MiB").unwrap();
+        assert_eq!(1536.0, size.value());
+
+        let any: AnyMeasurement = parse_setting("3 KiB").unwrap();
+        assert_eq!("3 KiB", any.to_string());
+
+        assert_eq!(
+            Err(SettingError::Invalid {
+                name: None,
+                value: "12 parsecs".to_owned(),
+                error: ParseError::UnknownUnit("parsecs".to_owned()),
+            }),
+            parse_setting::<Measurement<Byte>>("12 parsecs")
+        );
+    }
+
+    #[test]
+    fn test_parse_env() {
+        env::set_var("STRONG_UNITS_TEST_CACHE_SIZE", "512MiB");
+        env::set_var("STRONG_UNITS_TEST_BAD_SIZE", "lots");
+
+        let size: Measurement<Mebibyte> = parse_env("STRONG_UNITS_TEST_CACHE_SIZE").unwrap();
+        assert_eq!(512.0, size.value());
+
+        let error = parse_env::<Measurement<Byte>>("STRONG_UNITS_TEST_BAD_SIZE").unwrap_err();
+        assert_eq!(
+            "invalid value \"lots\" for STRONG_UNITS_TEST_BAD_SIZE: invalid number: \"lots\"",
+            error.to_string()
+        );
+
+        assert_eq!(
+            Err(SettingError::Missing("STRONG_UNITS_TEST_UNSET".to_owned())),
+            parse_env::<Measurement<Byte>>("STRONG_UNITS_TEST_UNSET")
+        );
+        assert_eq!(
+            Ok(Measurement::<Byte>::new(10.0)),
+            parse_env_or("STRONG_UNITS_TEST_UNSET", Measurement::<Byte>::new(10.0))
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Sorting of collections by a measurement of their items, with a
+//! defined placement for NaN values, which `f64` alone doesn't order.
+//!
+//! ```
+//! # #[cfg(feature = "data")] {
+//! use strong_units::{sort::sort_by_measurement, units::Byte, Measurement};
+//!
+//! struct File {
+//!     name: &'static str,
+//!     size: Measurement<Byte>,
+//! }
+//!
+//! let mut files = vec![
+//!     File { name: "b", size: Measurement::new(300.0) },
+//!     File { name: "a", size: Measurement::new(f64::NAN) },
+//!     File { name: "c", size: Measurement::new(20.0) },
+//! ];
+//!
+//! sort_by_measurement(&mut files, |file| file.size);
+//! let names: Vec<_> = files.iter().map(|file| file.name).collect();
+//! assert_eq!(vec!["c", "b", "a"], names);
+//! # }
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::{
+    dynamic::{AnyMeasurement, QuantityMismatch},
+    Measurement,
+};
+
+/// Where NaN values are placed when sorting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NanPolicy {
+    /// NaN values go before any other value.
+    First,
+    /// NaN values go after any other value.
+    #[default]
+    Last,
+}
+
+fn cmp_values(a: f64, b: f64, nan: NanPolicy) -> Ordering {
+    match (a.is_nan(), b.is_nan(), nan) {
+        (true, true, _) => Ordering::Equal,
+        (true, false, NanPolicy::First) | (false, true, NanPolicy::Last) => Ordering::Less,
+        (true, false, NanPolicy::Last) | (false, true, NanPolicy::First) => Ordering::Greater,
+        (false, false, _) => a.total_cmp(&b),
+    }
+}
+
+/// Compares two measurements in ascending order, placing NaN values
+/// as given.
+pub fn cmp_measurements<U>(a: &Measurement<U>, b: &Measurement<U>, nan: NanPolicy) -> Ordering {
+    cmp_values(a.value(), b.value(), nan)
+}
+
+/// Returns a comparator of items by the measurement extracted from
+/// them, for `sort_by`, `max_by`, `binary_search_by` and the like.
+pub fn by_measurement<T, U, F>(key: F, nan: NanPolicy) -> impl Fn(&T, &T) -> Ordering
+where
+    F: Fn(&T) -> Measurement<U>,
+{
+    move |a, b| cmp_measurements(&key(a), &key(b), nan)
+}
+
+/// Sorts the items in ascending order of the measurement extracted from
+/// them, placing the ones whose measurement is NaN at the end. The sort
+/// is stable.
+pub fn sort_by_measurement<T, U, F>(items: &mut [T], key: F)
+where
+    F: Fn(&T) -> Measurement<U>,
+{
+    sort_by_measurement_with(items, NanPolicy::Last, key)
+}
+
+/// Same as [`sort_by_measurement`], placing NaN values as given.
+pub fn sort_by_measurement_with<T, U, F>(items: &mut [T], nan: NanPolicy, key: F)
+where
+    F: Fn(&T) -> Measurement<U>,
+{
+    items.sort_by(by_measurement(key, nan));
+}
+
+/// Sorts the items in ascending order of the measurement extracted from
+/// them, which may be expressed in different units. Returns an error,
+/// leaving the items untouched, if the measurements don't all measure
+/// the same quantity. The sort is stable.
+pub fn sort_by_any_measurement<T, F>(
+    items: &mut [T],
+    nan: NanPolicy,
+    key: F,
+) -> Result<(), QuantityMismatch>
+where
+    F: Fn(&T) -> AnyMeasurement,
+{
+    if let Some(first) = items.first().map(&key) {
+        if let Some(other) = items.iter().map(&key).find(|m| !m.same_quantity(&first)) {
+            return Err(QuantityMismatch {
+                left: first.quantity().into_owned(),
+                right: other.quantity().into_owned(),
+            });
+        }
+    }
+
+    items.sort_by(|a, b| cmp_values(key(a).base_value(), key(b).base_value(), nan));
+    Ok(())
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use super::{by_measurement, sort_by_any_measurement, sort_by_measurement_with, NanPolicy};
+    use crate::{
+        dynamic::{AnyMeasurement, QuantityMismatch},
+        units::{Byte, Hour, Kibibyte, Megabyte},
+        Measurement,
+    };
+
+    #[test]
+    fn test_sort_by_measurement() {
+        let mut sizes: Vec<f64> = vec![3.0, f64::NAN, -1.0, 2.0];
+        let key = |value: &f64| Measurement::<Byte>::new(*value);
+
+        sort_by_measurement_with(&mut sizes, NanPolicy::First, key);
+        assert!(sizes[0].is_nan());
+        assert_eq!(&[-1.0, 2.0, 3.0], &sizes[1..]);
+
+        let largest = sizes
+            .iter()
+            .copied()
+            .max_by(by_measurement(key, NanPolicy::First));
+        assert_eq!(Some(3.0), largest);
+    }
+
+    #[test]
+    fn test_sort_by_any_measurement() {
+        let mut sizes: Vec<AnyMeasurement> = vec![
+            Measurement::<Megabyte>::new(1.0).into(),
+            Measurement::<Byte>::new(10.0).into(),
+            Measurement::<Kibibyte>::new(1.0).into(),
+        ];
+
+        sort_by_any_measurement(&mut sizes, NanPolicy::Last, |size| *size).unwrap();
+        let sorted: Vec<String> = sizes.iter().map(ToString::to_string).collect();
+        assert_eq!(vec!["10 B", "1 KiB", "1 MB"], sorted);
+
+        sizes.push(Measurement::<Hour>::new(1.0).into());
+        assert_eq!(
+            Err(QuantityMismatch {
+                left: "data".to_owned(),
+                right: "time".to_owned(),
+            }),
+            sort_by_any_measurement(&mut sizes, NanPolicy::Last, |size| *size)
+        );
+    }
+}
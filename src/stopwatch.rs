@@ -0,0 +1,199 @@
+//! Measurement of the time taken by a task and each of its steps, or
+//! laps, which can be paused while the task waits on something that
+//! shouldn't be counted.
+
+use std::time::{Duration, Instant};
+
+use crate::{quantile::QuantileDigest, units::Second, Measurement};
+
+/// Measures the running time of a task, split in laps. Each lap lasts
+/// from the end of the previous one (or the start of the stopwatch)
+/// until [`lap`](Self::lap) is called, not counting the time the
+/// stopwatch was paused.
+///
+/// Optionally, the laps can be recorded into a [`QuantileDigest`] for
+/// summarizing them, e.g reporting the median and p99 lap time.
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+    accumulated: Duration,
+    running_since: Option<Instant>,
+    lap_start: Duration,
+    laps: u64,
+    digest: Option<QuantileDigest<Second>>,
+}
+
+impl Stopwatch {
+    /// Creates a new stopwatch that starts running now.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Creates a new stopwatch that starts running at the given
+    /// instant.
+    pub fn starting_at(start: Instant) -> Self {
+        Self {
+            accumulated: Duration::ZERO,
+            running_since: Some(start),
+            lap_start: Duration::ZERO,
+            laps: 0,
+            digest: None,
+        }
+    }
+
+    /// Records the time of every lap into a digest of the default
+    /// compression, available through [`lap_digest`](Self::lap_digest).
+    pub fn with_lap_digest(mut self) -> Self {
+        self.digest = Some(QuantileDigest::default());
+        self
+    }
+
+    /// Returns whether the stopwatch is running, i.e not paused.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Pauses the stopwatch at the given instant. Does nothing if it
+    /// was already paused.
+    pub fn pause_at(&mut self, now: Instant) {
+        self.accumulated = self.running_at(now);
+        self.running_since = None;
+    }
+
+    /// Pauses the stopwatch. Does nothing if it was already paused.
+    pub fn pause(&mut self) {
+        self.pause_at(Instant::now())
+    }
+
+    /// Resumes the stopwatch at the given instant. Does nothing if it
+    /// was already running.
+    pub fn resume_at(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Resumes the stopwatch. Does nothing if it was already running.
+    pub fn resume(&mut self) {
+        self.resume_at(Instant::now())
+    }
+
+    fn running_at(&self, now: Instant) -> Duration {
+        let current = self
+            .running_since
+            .map(|since| now.saturating_duration_since(since))
+            .unwrap_or_default();
+
+        self.accumulated + current
+    }
+
+    /// Returns the time the stopwatch has been running until the given
+    /// instant.
+    pub fn total_at(&self, now: Instant) -> Measurement<Second> {
+        self.running_at(now).into()
+    }
+
+    /// Returns the time the stopwatch has been running until now.
+    pub fn total(&self) -> Measurement<Second> {
+        self.total_at(Instant::now())
+    }
+
+    /// Ends the current lap at the given instant, returning its time,
+    /// and starts a new one.
+    pub fn lap_at(&mut self, now: Instant) -> Measurement<Second> {
+        let running = self.running_at(now);
+        let lap: Measurement<Second> = running.saturating_sub(self.lap_start).into();
+
+        self.lap_start = running;
+        self.laps += 1;
+        if let Some(digest) = &mut self.digest {
+            digest.record(lap);
+        }
+
+        lap
+    }
+
+    /// Ends the current lap now, returning its time, and starts a new
+    /// one.
+    pub fn lap(&mut self) -> Measurement<Second> {
+        self.lap_at(Instant::now())
+    }
+
+    /// Returns the number of laps ended so far.
+    pub fn laps(&self) -> u64 {
+        self.laps
+    }
+
+    /// Returns the digest of the lap times, if enabled through
+    /// [`with_lap_digest`](Self::with_lap_digest).
+    pub fn lap_digest(&self) -> Option<&QuantileDigest<Second>> {
+        self.digest.as_ref()
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Stopwatch;
+    use crate::units::Millisecond;
+
+    #[test]
+    fn test_laps() {
+        let start = Instant::now();
+        let ms = |n: u64| start + Duration::from_millis(n);
+        let mut stopwatch = Stopwatch::starting_at(start);
+
+        assert_eq!(
+            100.0,
+            stopwatch.lap_at(ms(100)).into_unit::<Millisecond>().value()
+        );
+        assert_eq!(
+            250.0,
+            stopwatch.lap_at(ms(350)).into_unit::<Millisecond>().value()
+        );
+        assert_eq!(0.35, stopwatch.total_at(ms(350)).value());
+        assert_eq!(2, stopwatch.laps());
+        assert!(stopwatch.lap_digest().is_none());
+    }
+
+    #[test]
+    fn test_pause_resume() {
+        let start = Instant::now();
+        let ms = |n: u64| start + Duration::from_millis(n);
+        let mut stopwatch = Stopwatch::starting_at(start).with_lap_digest();
+
+        stopwatch.pause_at(ms(100));
+        stopwatch.pause_at(ms(200));
+        assert!(!stopwatch.is_running());
+        assert_eq!(0.1, stopwatch.total_at(ms(500)).value());
+
+        stopwatch.resume_at(ms(1000));
+        stopwatch.resume_at(ms(1100));
+        assert!(stopwatch.is_running());
+        assert_eq!(
+            200.0,
+            stopwatch
+                .lap_at(ms(1100))
+                .into_unit::<Millisecond>()
+                .value()
+        );
+        assert_eq!(
+            400.0,
+            stopwatch
+                .lap_at(ms(1500))
+                .into_unit::<Millisecond>()
+                .value()
+        );
+
+        let digest = stopwatch.lap_digest().unwrap();
+        assert_eq!(2, digest.count());
+        assert_eq!(0.2, digest.min().unwrap().value());
+        assert_eq!(0.4, digest.max().unwrap().value());
+    }
+}
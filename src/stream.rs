@@ -0,0 +1,129 @@
+//! Instrumentation of asynchronous streams of data, measuring the
+//! throughput of the chunks flowing through them.
+//!
+//! ```
+//! use futures::{executor::block_on, stream, StreamExt};
+//! use strong_units::stream::ThroughputStreamExt;
+//!
+//! let chunks = stream::iter(vec![vec![0u8; 1000], vec![0u8; 500]]).measure_throughput();
+//! let handle = chunks.handle();
+//!
+//! block_on(chunks.for_each(|_| async {}));
+//! assert_eq!(1500.0, handle.total().value());
+//! ```
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::{meter::ThroughputHandle, units::Byte, Measurement};
+
+pin_project! {
+    /// Stream that records the size of the items of another one into a
+    /// [`ThroughputHandle`]. Created through [`ThroughputStreamExt`].
+    #[derive(Debug)]
+    pub struct MeasureThroughput<S, F> {
+        #[pin]
+        stream: S,
+        size: F,
+        handle: ThroughputHandle,
+    }
+}
+
+impl<S, F> MeasureThroughput<S, F> {
+    /// Returns a handle to the throughput of the stream, which can be
+    /// kept after the stream is moved into a pipeline.
+    pub fn handle(&self) -> ThroughputHandle {
+        self.handle.clone()
+    }
+
+    /// Returns the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S, F> Stream for MeasureThroughput<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> Measurement<Byte>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let item = this.stream.poll_next(cx);
+
+        if let Poll::Ready(Some(item)) = &item {
+            this.handle.record((this.size)(item));
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+fn chunk_size<T: AsRef<[u8]>>(chunk: &T) -> Measurement<Byte> {
+    Measurement::new(chunk.as_ref().len() as f64)
+}
+
+/// Measuring stream of chunks of data, created through
+/// [`ThroughputStreamExt::measure_throughput`].
+pub type MeasureChunks<S> = MeasureThroughput<S, fn(&<S as Stream>::Item) -> Measurement<Byte>>;
+
+/// Extension of streams for [measuring their throughput](MeasureThroughput).
+pub trait ThroughputStreamExt: Stream + Sized {
+    /// Measures the throughput of a stream of chunks of data, like
+    /// `Vec<u8>` or `Bytes`.
+    fn measure_throughput(self) -> MeasureChunks<Self>
+    where
+        Self::Item: AsRef<[u8]>,
+    {
+        self.measure_throughput_by(chunk_size as fn(&Self::Item) -> _)
+    }
+
+    /// Measures the throughput of a stream, taking the size of each
+    /// item from the given function, e.g for streams of results.
+    fn measure_throughput_by<F>(self, size: F) -> MeasureThroughput<Self, F>
+    where
+        F: FnMut(&Self::Item) -> Measurement<Byte>,
+    {
+        MeasureThroughput {
+            stream: self,
+            size,
+            handle: ThroughputHandle::new(),
+        }
+    }
+}
+
+impl<S: Stream> ThroughputStreamExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use super::ThroughputStreamExt;
+    use crate::{units::Kilobyte, Measurement};
+
+    #[test]
+    fn test_measure_throughput() {
+        let chunks: Vec<Result<Vec<u8>, ()>> = vec![Ok(vec![0; 2000]), Err(()), Ok(vec![0; 500])];
+        let stream = stream::iter(chunks).measure_throughput_by(|chunk| {
+            let size = chunk.as_ref().map_or(0, Vec::len);
+            Measurement::new(size as f64)
+        });
+        let handle = stream.handle();
+
+        let received = block_on(stream.collect::<Vec<_>>());
+        assert_eq!(3, received.len());
+        assert_eq!(2.5, handle.total().into_unit::<Kilobyte>().value());
+        assert!(handle.average_rate().value() > 0.0);
+    }
+}
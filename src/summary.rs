@@ -0,0 +1,210 @@
+//! Reports combining several labeled measurements, like the summary
+//! printed by command line tools at the end of a run.
+//!
+//! ```
+//! # #[cfg(feature = "rate")] {
+//! use strong_units::{
+//!     summary::SummaryFormatter,
+//!     units::{Bps, Byte, Second, BYTE_UNITS, RATE_UNITS},
+//!     Measurement,
+//! };
+//!
+//! let summary = SummaryFormatter::new()
+//!     .humanized_entry("transferred", Measurement::<Byte>::new(1_500_000.0), BYTE_UNITS)
+//!     .entry("elapsed", Measurement::<Second>::new(12.0))
+//!     .humanized_entry("peak rate", Measurement::<Bps>::new(2_000_000.0), RATE_UNITS);
+//!
+//! assert_eq!(
+//!     "transferred: 1.5 MB\n\
+//!      elapsed:     12 s\n\
+//!      peak rate:   2 Mbps",
+//!     summary.to_string()
+//! );
+//! # }
+//! ```
+
+use std::{borrow::Cow, fmt::Display};
+
+use crate::{
+    dynamic::{AnyMeasurement, DynUnit},
+    format::{self, MeasurementFormat},
+    humanize,
+};
+#[cfg(feature = "rate")]
+use crate::{
+    units::{Bit, Bps, Second, BYTE_UNITS, RATE_UNITS, TIME_UNITS},
+    FromUnit, MeasureUnit, Measurement,
+};
+
+/// How the entries of a summary are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SummaryLayout {
+    /// One entry per line, with the values aligned, e.g
+    /// "elapsed: 12 s\nrate:    2 Mbps".
+    #[default]
+    MultiLine,
+    /// All the entries in the same line, separated by commas, e.g
+    /// "elapsed: 12 s, rate: 2 Mbps".
+    SingleLine,
+}
+
+/// Builder of reports made of labeled measurements, displayed in the
+/// order they were added. The precision of the formatter, if any, is
+/// applied to all the values, unless the format of the summary sets
+/// its own.
+#[derive(Debug, Clone, Default)]
+pub struct SummaryFormatter {
+    entries: Vec<(Cow<'static, str>, AnyMeasurement)>,
+    layout: SummaryLayout,
+    format: Option<MeasurementFormat>,
+}
+
+impl SummaryFormatter {
+    /// Creates an empty summary, laid out in multiple lines.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a summary of a transfer of the given amount of data in
+    /// the given time, with the amount, the elapsed time and the
+    /// average rate, all of them expressed in the most readable unit.
+    #[cfg(feature = "rate")]
+    pub fn transfer<D, T>(total: Measurement<D>, elapsed: Measurement<T>) -> Self
+    where
+        D: MeasureUnit + 'static,
+        T: MeasureUnit + 'static,
+        Bit: FromUnit<D>,
+        Second: FromUnit<T>,
+        AnyMeasurement: From<Measurement<D>> + From<Measurement<T>>,
+    {
+        let rate = Measurement::<Bps>::new(
+            total.into_unit::<Bit>().value() / elapsed.into_unit::<Second>().value(),
+        );
+
+        Self::new()
+            .humanized_entry("total", total, BYTE_UNITS)
+            .humanized_entry("elapsed", elapsed, TIME_UNITS)
+            .humanized_entry("average rate", rate, RATE_UNITS)
+    }
+
+    /// Sets how the entries are laid out.
+    pub fn layout(mut self, layout: SummaryLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the format of the values, instead of the
+    /// [default one](format::default_format).
+    pub fn format(mut self, format: MeasurementFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Adds an entry with the given label, displaying the measurement
+    /// in its own unit.
+    pub fn entry(
+        mut self,
+        label: impl Into<Cow<'static, str>>,
+        measurement: impl Into<AnyMeasurement>,
+    ) -> Self {
+        self.entries.push((label.into(), measurement.into()));
+        self
+    }
+
+    /// Adds an entry with the given label, displaying the measurement
+    /// in the most readable of the given units. See
+    /// [`humanize`](humanize::humanize).
+    pub fn humanized_entry(
+        self,
+        label: impl Into<Cow<'static, str>>,
+        measurement: impl Into<AnyMeasurement>,
+        units: &[&'static dyn DynUnit],
+    ) -> Self {
+        self.entry(label, humanize::humanize(measurement.into(), units))
+    }
+
+    /// Returns the entries of the summary, in the order they were
+    /// added.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, AnyMeasurement)> {
+        self.entries
+            .iter()
+            .map(|(label, measurement)| (label.as_ref(), *measurement))
+    }
+}
+
+impl Display for SummaryFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = self.format.unwrap_or_else(format::default_format);
+        let width = self
+            .entries
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for (i, (label, measurement)) in self.entries.iter().enumerate() {
+            match self.layout {
+                SummaryLayout::MultiLine => {
+                    if i > 0 {
+                        f.write_str("\n")?;
+                    }
+                    let padding = width - label.chars().count();
+                    write!(f, "{}: {:padding$}", label, "")?;
+                }
+                SummaryLayout::SingleLine => {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}: ", label)?;
+                }
+            }
+
+            Display::fmt(&measurement.format(format), f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "rate"))]
+mod tests {
+    use super::{SummaryFormatter, SummaryLayout};
+    use crate::{
+        format::MeasurementFormat,
+        units::{Gigabyte, Mbps, Minute, Second, RATE_UNITS},
+        Measurement,
+    };
+
+    #[test]
+    fn test_transfer_summary() {
+        let summary = SummaryFormatter::transfer(
+            Measurement::<Gigabyte>::new(1.5),
+            Measurement::<Second>::new(120.0),
+        )
+        .humanized_entry("peak rate", Measurement::<Mbps>::new(180.0), RATE_UNITS);
+
+        assert_eq!(
+            "total:        1.50 GB\n\
+             elapsed:      2.00 min\n\
+             average rate: 100.00 Mbps\n\
+             peak rate:    180.00 Mbps",
+            format!("{:.2}", summary)
+        );
+    }
+
+    #[test]
+    fn test_single_line() {
+        let summary = SummaryFormatter::new()
+            .layout(SummaryLayout::SingleLine)
+            .format(MeasurementFormat::long())
+            .entry("elapsed", Measurement::<Minute>::new(1.0))
+            .entry("rate", Measurement::<Mbps>::new(2.5));
+
+        assert_eq!(
+            "elapsed: 1 minute, rate: 2.5 megabits per second",
+            summary.to_string()
+        );
+        assert_eq!(2, summary.entries().count());
+        assert_eq!("", SummaryFormatter::new().to_string());
+    }
+}
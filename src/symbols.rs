@@ -0,0 +1,207 @@
+//! Selection between the canonical symbols of the units, which may
+//! contain Unicode characters (µs, min²), and their ASCII fallbacks
+//! (us, min^2), for terminals and protocols that can't handle
+//! non-ASCII text. Both forms are accepted when parsing.
+
+use std::{
+    any::TypeId,
+    borrow::Cow,
+    collections::HashMap,
+    fmt::Display,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock, RwLock,
+    },
+};
+
+use crate::{MeasureUnit, Measurement};
+
+/// Style of the symbols used when displaying measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SymbolStyle {
+    /// The canonical symbols, which may contain Unicode characters.
+    #[default]
+    Unicode,
+    /// The ASCII fallback of the symbols.
+    Ascii,
+}
+
+static ASCII: AtomicBool = AtomicBool::new(false);
+
+/// Sets the style of the symbols used when displaying measurements,
+/// for the whole program.
+pub fn set_symbol_style(style: SymbolStyle) {
+    ASCII.store(style == SymbolStyle::Ascii, Ordering::Relaxed);
+}
+
+/// Returns the style of the symbols used when displaying measurements.
+pub fn symbol_style() -> SymbolStyle {
+    if ASCII.load(Ordering::Relaxed) {
+        SymbolStyle::Ascii
+    } else {
+        SymbolStyle::Unicode
+    }
+}
+
+/// Transliterates the non-ASCII characters commonly found in symbols
+/// into ASCII, e.g "µs" into "us", "Ω" into "ohm" or "m²" into "m^2".
+/// Other characters are left untouched.
+pub fn to_ascii(symbol: &str) -> Cow<'_, str> {
+    if symbol.is_ascii() {
+        return Cow::Borrowed(symbol);
+    }
+
+    let mut result = String::with_capacity(symbol.len() + 2);
+    let mut in_exponent = false;
+
+    for c in symbol.chars() {
+        let exponent = match c {
+            '⁻' => Some('-'),
+            '⁰' => Some('0'),
+            '¹' => Some('1'),
+            '²' => Some('2'),
+            '³' => Some('3'),
+            '⁴'..='⁹' => char::from_digit(c as u32 - '⁰' as u32, 10),
+            _ => None,
+        };
+
+        if let Some(exponent) = exponent {
+            // Consecutive superscripts form a single exponent.
+            if !in_exponent {
+                result.push('^');
+            }
+            result.push(exponent);
+            in_exponent = true;
+            continue;
+        }
+
+        in_exponent = false;
+        match c {
+            'µ' | 'μ' => result.push('u'),
+            'Ω' => result.push_str("ohm"),
+            '°' => result.push_str("deg"),
+            '·' => result.push('*'),
+            c => result.push(c),
+        }
+    }
+
+    Cow::Owned(result)
+}
+
+/// Symbols of the composed units already built, indexed by the type of
+/// the unit. They are leaked, so they can be handed out as `'static`;
+/// there is one per composed unit displayed by the program, at most.
+static COMPOSED: LazyLock<RwLock<HashMap<(TypeId, SymbolStyle), &'static str>>> =
+    LazyLock::new(Default::default);
+
+/// Returns the symbol of the composed unit U in the given style,
+/// building it through the given function only the first time, so
+/// displaying measurements of composed units doesn't allocate.
+pub(crate) fn composed_symbol<U: 'static>(
+    style: SymbolStyle,
+    build: impl FnOnce() -> String,
+) -> Cow<'static, str> {
+    let key = (TypeId::of::<U>(), style);
+    let cached = COMPOSED
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&key)
+        .copied();
+
+    if let Some(symbol) = cached {
+        return Cow::Borrowed(symbol);
+    }
+
+    // Built before taking the lock, since it may need the symbols of
+    // other composed units.
+    let symbol = build();
+    let symbol = *COMPOSED
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(key)
+        .or_insert_with(|| Box::leak(symbol.into_boxed_str()));
+
+    Cow::Borrowed(symbol)
+}
+
+/// Returns the symbol of the unit U in the given style.
+pub fn symbol_of<U: MeasureUnit>(style: SymbolStyle) -> Cow<'static, str> {
+    match style {
+        SymbolStyle::Unicode => U::symbol(),
+        SymbolStyle::Ascii => U::ascii_symbol(),
+    }
+}
+
+/// Displays a measurement using symbols of a specific style,
+/// regardless of the global one. Created through
+/// [`Measurement::with_symbols`].
+pub struct WithSymbols<U> {
+    measurement: Measurement<U>,
+    style: SymbolStyle,
+}
+
+impl<U: MeasureUnit> Display for WithSymbols<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <f64 as Display>::fmt(&self.measurement.value(), f)?;
+        f.write_str(" ")?;
+        f.write_str(&symbol_of::<U>(self.style))
+    }
+}
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Returns an object that displays this measurement using symbols
+    /// of the given style, instead of the global one.
+    pub fn with_symbols(self, style: SymbolStyle) -> WithSymbols<U> {
+        WithSymbols {
+            measurement: self,
+            style,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{to_ascii, SymbolStyle};
+    use crate::{
+        parse::parse_any,
+        units::{Microsecond, Minute, Second},
+        DivUnit, MeasureUnit, Measurement, PowUnit,
+    };
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!("us", to_ascii("µs"));
+        assert_eq!("ohm", to_ascii("Ω"));
+        assert_eq!("m^2", to_ascii("m²"));
+        assert_eq!("(Kb/s)^-12", to_ascii("(Kb/s)⁻¹²"));
+        assert_eq!("min", to_ascii("min"));
+    }
+
+    #[test]
+    fn test_with_symbols() {
+        let m = Measurement::<Microsecond>::new(1.5);
+        assert_eq!("1.5 µs", m.with_symbols(SymbolStyle::Unicode).to_string());
+        assert_eq!("1.5 us", m.with_symbols(SymbolStyle::Ascii).to_string());
+
+        let m = Measurement::<PowUnit<Minute, 2>>::new(4.0);
+        assert_eq!("4 min^2", m.with_symbols(SymbolStyle::Ascii).to_string());
+    }
+
+    #[test]
+    fn test_parse_ascii_fallback() {
+        assert_eq!("µs", parse_any("20 us").unwrap().unit().symbol());
+        assert_eq!("µs", parse_any("20µs").unwrap().unit().symbol());
+    }
+
+    #[test]
+    fn test_composed_symbols_cached() {
+        type Unit = DivUnit<PowUnit<Microsecond, 2>, Second>;
+
+        assert_eq!("µs²/s", Unit::symbol());
+        assert_eq!("us^2/s", Unit::ascii_symbol());
+        assert!(matches!(Unit::symbol(), Cow::Borrowed("µs²/s")));
+        assert!(matches!(Unit::ascii_symbol(), Cow::Borrowed("us^2/s")));
+    }
+}
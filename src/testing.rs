@@ -0,0 +1,122 @@
+//! Checks for the consistency of the conversions of a family of units,
+//! used through
+//! [`assert_conversion_consistency!`](crate::assert_conversion_consistency),
+//! so that typos in the factors of a unit definition are caught by
+//! tests, both in this crate and in the ones defining their own units.
+//!
+//! ```
+//! # #[cfg(feature = "time")] {
+//! use strong_units::{
+//!     assert_conversion_consistency,
+//!     units::{Hour, Millisecond, Minute, Second},
+//! };
+//!
+//! assert_conversion_consistency!(Second, Minute, Hour, Millisecond);
+//! # }
+//! ```
+
+use crate::{FromUnit, MeasureUnit, Measurement};
+
+/// Values each conversion is checked with.
+const SAMPLES: [f64; 5] = [0.0, 1.0, 0.001, 1234.5, -7.25];
+
+fn approx_eq(left: f64, right: f64) -> bool {
+    (left - right).abs() <= 1e-9 * left.abs().max(right.abs())
+}
+
+/// Asserts that converting values from A to B and back gives the same
+/// values, and that the scale of the conversion from A to B is positive
+/// and the inverse of the one from B to A, so both units are ordered the
+/// same way in both directions.
+#[track_caller]
+pub fn assert_symmetric<A: MeasureUnit, B: MeasureUnit>()
+where
+    A::AliasedUnit: FromUnit<B>,
+    B::AliasedUnit: FromUnit<A>,
+{
+    for value in SAMPLES {
+        let from = Measurement::<A>::new(value);
+        let back = from.into_unit::<B>().into_unit::<A>();
+        assert!(
+            approx_eq(value, back.value()),
+            "converting {} to {} and back gives {}",
+            from,
+            B::symbol(),
+            back
+        );
+    }
+
+    let scale = |from: fn(f64) -> f64| from(1.0) - from(0.0);
+    let forward = scale(|value| Measurement::<A>::new(value).into_unit::<B>().value());
+    let backward = scale(|value| Measurement::<B>::new(value).into_unit::<A>().value());
+    assert!(
+        forward.is_finite() && forward > 0.0,
+        "the scale of the conversion from {} to {} is {}",
+        A::symbol(),
+        B::symbol(),
+        forward
+    );
+    assert!(
+        approx_eq(forward * backward, 1.0),
+        "the scale from {} to {} is {}, but the one back is {}",
+        A::symbol(),
+        B::symbol(),
+        forward,
+        backward
+    );
+}
+
+/// Asserts that converting values from A to B and then to C gives the
+/// same values as converting them from A to C.
+#[track_caller]
+pub fn assert_transitive<A: MeasureUnit, B: MeasureUnit, C: MeasureUnit>()
+where
+    B::AliasedUnit: FromUnit<A>,
+    C::AliasedUnit: FromUnit<A> + FromUnit<B>,
+{
+    for value in SAMPLES {
+        let from = Measurement::<A>::new(value);
+        let through = from.into_unit::<B>().into_unit::<C>();
+        let direct = from.into_unit::<C>();
+        assert!(
+            approx_eq(direct.value(), through.value()),
+            "converting {} to {} gives {}, but through {} gives {}",
+            from,
+            C::symbol(),
+            direct,
+            B::symbol(),
+            through
+        );
+    }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use std::panic;
+
+    use crate::{
+        assert_conversion_consistency, define_nonlinear_conversion, define_unit,
+        units::{Hour, Microsecond, Millisecond, Minute, Nanosecond, Second},
+        FromUnit, Measurement,
+    };
+
+    assert_conversion_consistency!(
+        test_time_family: Second, Minute, Hour, Millisecond, Microsecond, Nanosecond
+    );
+
+    define_unit!(Span, "span");
+    define_unit!(Cubit, "cubit");
+    define_nonlinear_conversion!(Span <> Cubit, |x| x / 2.0, |x| x * 3.0);
+    define_nonlinear_conversion!(Span -> Span, |x| x);
+    define_nonlinear_conversion!(Cubit -> Cubit, |x| x);
+
+    #[test]
+    fn test_inconsistent_family() {
+        let error =
+            panic::catch_unwind(|| assert_conversion_consistency!(Span, Cubit)).unwrap_err();
+        assert_eq!(
+            Some("converting 1 span to cubit and back gives 1.5 span"),
+            error.downcast_ref::<String>().map(String::as_str)
+        );
+    }
+}
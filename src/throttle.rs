@@ -0,0 +1,190 @@
+//! Rate limiting of iterators, sleeping between items as needed so
+//! they are consumed at no more than a given rate, either of items
+//! (e.g 100 op/s) or of some amount carried by each item (e.g chunks
+//! of data at 10 Mbps).
+//!
+//! ```
+//! # #[cfg(feature = "rate")] {
+//! use strong_units::{
+//!     throttle::ThrottleExt,
+//!     units::{Byte, Gbps},
+//!     Measurement,
+//! };
+//!
+//! let chunks = vec![vec![0u8; 1024]; 4];
+//! let sent: usize = chunks
+//!     .into_iter()
+//!     .throttle_by(Measurement::<Gbps>::new(1.0), |chunk| {
+//!         Measurement::<Byte>::new(chunk.len() as f64)
+//!     })
+//!     .map(|chunk| chunk.len())
+//!     .sum();
+//! assert_eq!(4096, sent);
+//! # }
+//! ```
+
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    thread,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "count")]
+use crate::units::Operation;
+use crate::{units::Second, Canonical, DivUnit, FromUnit, MeasureUnit, Measurement};
+
+/// Iterator that yields the items of another one at no more than a
+/// given rate. Created through [`ThrottleExt`].
+///
+/// The first item is yielded right away, and each one after it waits
+/// until the amount yielded so far fits in the elapsed time at the
+/// given rate. If the consumer falls behind, items are yielded without
+/// waiting until it catches up, so the average rate since the start
+/// never exceeds the given one.
+pub struct Throttle<I, U, F> {
+    _marker: PhantomData<fn() -> U>,
+    iter: I,
+    cost: F,
+    rate: f64,
+    start: Option<Instant>,
+    yielded: f64,
+}
+
+impl<I, U, F> Throttle<I, U, F> {
+    fn new(iter: I, rate: f64, cost: F) -> Self {
+        Self {
+            _marker: PhantomData,
+            iter,
+            cost,
+            rate,
+            start: None,
+            yielded: 0.0,
+        }
+    }
+
+    /// Returns the amount yielded so far.
+    pub fn yielded(&self) -> Measurement<U> {
+        Measurement::new(self.yielded)
+    }
+
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, U, F> Iterator for Throttle<I, U, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> Measurement<U>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let start = *self.start.get_or_insert_with(Instant::now);
+
+        if self.rate > 0.0 {
+            let due = Duration::try_from_secs_f64(self.yielded / self.rate).ok();
+            let wait = due.and_then(|due| (start + due).checked_duration_since(Instant::now()));
+            if let Some(wait) = wait {
+                thread::sleep(wait);
+            }
+        }
+
+        self.yielded += (self.cost)(&item).value();
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Debug, U: MeasureUnit, F> Debug for Throttle<I, U, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Throttle")
+            .field("iter", &self.iter)
+            .field("rate", &self.rate)
+            .field("start", &self.start)
+            .field("yielded", &self.yielded)
+            .finish()
+    }
+}
+
+/// Iterator throttled to a rate of items, created through
+/// [`ThrottleExt::throttle`].
+#[cfg(feature = "count")]
+pub type ItemThrottle<I> =
+    Throttle<I, Operation, fn(&<I as Iterator>::Item) -> Measurement<Operation>>;
+
+#[cfg(feature = "count")]
+fn one_operation<T>(_: &T) -> Measurement<Operation> {
+    Measurement::new(1.0)
+}
+
+/// Extension of iterators for [throttling](Throttle) them.
+pub trait ThrottleExt: Iterator + Sized {
+    /// Yields the items at no more than the given rate of items, each
+    /// one counted as an operation, e.g 100 op/s.
+    #[cfg(feature = "count")]
+    fn throttle<R: MeasureUnit>(self, rate: Measurement<R>) -> ItemThrottle<Self>
+    where
+        DivUnit<Operation, Second>: FromUnit<R>,
+    {
+        self.throttle_by(rate, one_operation as fn(&Self::Item) -> _)
+    }
+
+    /// Yields the items at no more than the given rate of the amount
+    /// returned by `cost` for each one, e.g the size of chunks of data
+    /// sent at 10 Mbps.
+    fn throttle_by<U, R, F>(self, rate: Measurement<R>, cost: F) -> Throttle<Self, U, F>
+    where
+        U: MeasureUnit,
+        R: MeasureUnit,
+        F: FnMut(&Self::Item) -> Measurement<U>,
+        Canonical<DivUnit<U, Second>>: FromUnit<R>,
+    {
+        Throttle::new(self, rate.into_unit::<DivUnit<U, Second>>().value(), cost)
+    }
+}
+
+impl<I: Iterator> ThrottleExt for I {}
+
+#[cfg(all(test, feature = "count", feature = "rate"))]
+mod tests {
+    use std::time::Instant;
+
+    use super::ThrottleExt;
+    use crate::{
+        units::{Byte, InstantExt, Kbps, KopsPerSecond, Millisecond},
+        Measurement,
+    };
+
+    #[test]
+    fn test_throttle() {
+        let start = Instant::now();
+        let items: Vec<_> = (0..11)
+            .throttle(Measurement::<KopsPerSecond>::new(1.0))
+            .collect();
+
+        assert_eq!(11, items.len());
+        assert!(start.elapsed_measure::<Millisecond>().value() >= 10.0);
+    }
+
+    #[test]
+    fn test_throttle_by() {
+        let start = Instant::now();
+        let mut chunks = [10.0, 20.0, 30.0]
+            .into_iter()
+            .throttle_by(Measurement::<Kbps>::new(8.0), |size| {
+                Measurement::<Byte>::new(*size)
+            });
+
+        assert_eq!(3, chunks.by_ref().count());
+        assert_eq!(60.0, chunks.yielded().value());
+        // The last chunk waits for the previous 30 bytes to be sent.
+        assert!(start.elapsed_measure::<Millisecond>().value() >= 30.0);
+    }
+}
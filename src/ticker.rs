@@ -0,0 +1,165 @@
+//! Periodic ticks at a typed interval, for driving sampling loops like
+//! the ones feeding a [`RateCounter`](crate::meter::RateCounter).
+//!
+//! ```no_run
+//! # #[cfg(feature = "data")] {
+//! use strong_units::{
+//!     meter::RateCounter,
+//!     ticker::Ticker,
+//!     units::{Byte, Millisecond},
+//!     Measurement,
+//! };
+//!
+//! let mut counter = RateCounter::<Byte>::new();
+//! for (elapsed, index) in Ticker::new(Measurement::<Millisecond>::new(500.0)) {
+//!     println!("#{} at {:.1}: {:.0}", index, elapsed, counter.sample());
+//! }
+//! # }
+//! ```
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{units::Second, FromUnit, MeasureUnit, Measurement};
+
+/// Fires at a fixed interval since it was created, yielding the time
+/// elapsed since then and the index of each tick.
+///
+/// Ticks are scheduled at whole multiples of the interval, so they
+/// don't drift when the work between them takes a variable time. If a
+/// tick is late by more than an interval, the ticks missed in between
+/// are skipped, so the index of a tick is always its slot in the
+/// schedule.
+#[derive(Debug, Clone)]
+pub struct Ticker {
+    start: Instant,
+    interval: Duration,
+    next: u64,
+}
+
+impl Ticker {
+    /// Creates a ticker that fires every given interval, starting now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is not a positive duration.
+    pub fn new<T: MeasureUnit>(interval: Measurement<T>) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        Self::starting_at(interval, Instant::now())
+    }
+
+    /// Creates a ticker that fires every given interval, starting at
+    /// the given instant, which is when the first tick fires.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is not a positive duration.
+    pub fn starting_at<T: MeasureUnit>(interval: Measurement<T>, start: Instant) -> Self
+    where
+        Second: FromUnit<T>,
+    {
+        let interval = Duration::try_from_secs_f64(interval.into_unit::<Second>().value())
+            .ok()
+            .filter(|interval| !interval.is_zero())
+            .expect("the interval of a ticker must be a positive duration");
+
+        Self {
+            start,
+            interval,
+            next: 0,
+        }
+    }
+
+    /// Returns the interval between ticks.
+    pub fn interval(&self) -> Measurement<Second> {
+        self.interval.into()
+    }
+
+    /// Returns the instant the next tick is due at.
+    pub fn next_tick_at(&self) -> Instant {
+        self.start + self.interval.mul_f64(self.next as f64)
+    }
+
+    /// Returns the next tick if it is due at the given instant, without
+    /// waiting for it.
+    pub fn poll_at(&mut self, now: Instant) -> Option<(Measurement<Second>, u64)> {
+        if now < self.next_tick_at() {
+            return None;
+        }
+
+        let elapsed = now.saturating_duration_since(self.start);
+        let slot = (elapsed.as_secs_f64() / self.interval.as_secs_f64()).floor() as u64;
+        let index = slot.max(self.next);
+
+        self.next = index + 1;
+        Some((elapsed.into(), index))
+    }
+
+    /// Waits for the next tick, and returns the time elapsed since the
+    /// start of the ticker and the index of the tick.
+    pub fn tick(&mut self) -> (Measurement<Second>, u64) {
+        loop {
+            if let Some(tick) = self.poll_at(Instant::now()) {
+                return tick;
+            }
+
+            let due = self.next_tick_at();
+            thread::sleep(due.saturating_duration_since(Instant::now()));
+        }
+    }
+}
+
+impl Iterator for Ticker {
+    type Item = (Measurement<Second>, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.tick())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Ticker;
+    use crate::{units::Millisecond, Measurement};
+
+    #[test]
+    fn test_poll() {
+        let start = Instant::now();
+        let ms = |n: u64| start + Duration::from_millis(n);
+        let mut ticker = Ticker::starting_at(Measurement::<Millisecond>::new(100.0), start);
+
+        let tick = |ticker: &mut Ticker, n| {
+            ticker
+                .poll_at(ms(n))
+                .map(|(elapsed, index)| (elapsed.into_unit::<Millisecond>().value(), index))
+        };
+
+        assert_eq!(Some((0.0, 0)), tick(&mut ticker, 0));
+        assert_eq!(None, tick(&mut ticker, 50));
+        assert_eq!(Some((120.0, 1)), tick(&mut ticker, 120));
+        assert_eq!(ms(200), ticker.next_tick_at());
+
+        // Ticks 2 and 3 are missed.
+        assert_eq!(Some((450.0, 4)), tick(&mut ticker, 450));
+        assert_eq!(None, tick(&mut ticker, 499));
+        assert_eq!(Some((500.0, 5)), tick(&mut ticker, 500));
+    }
+
+    #[test]
+    fn test_tick() {
+        let start = Instant::now();
+        let ticks: Vec<u64> = Ticker::starting_at(Measurement::<Millisecond>::new(5.0), start)
+            .take(3)
+            .map(|(_, index)| index)
+            .collect();
+
+        assert_eq!(vec![0, 1, 2], ticks);
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,244 @@
+//! Middleware for tower services handling HTTP requests, like the ones
+//! of hyper or axum, tracking the rate of requests and the throughput
+//! of their bodies, for reporting them e.g in health endpoints.
+//!
+//! ```
+//! use futures::executor::block_on;
+//! use http_body_util::Full;
+//! use strong_units::tower::RateLayer;
+//! use tower::{service_fn, Layer, ServiceExt};
+//!
+//! let layer = RateLayer::new();
+//! let metrics = layer.metrics();
+//! let service = layer.layer(service_fn(|_request| async {
+//!     Ok::<_, ()>(http::Response::new(Full::new(&b"pong"[..])))
+//! }));
+//!
+//! let request = http::Request::new(Full::new(&b"ping"[..]));
+//! block_on(service.oneshot(request)).unwrap();
+//! assert_eq!(1.0, metrics.snapshot().requests.value());
+//! ```
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::{
+    http::MeasuredBody,
+    meter::{EwmaRate, ThroughputHandle},
+    units::{Bps, Byte, Request, RequestsPerSecond, Second},
+    Measurement,
+};
+
+/// Rates of the requests handled by the services wrapped by a
+/// [`RateLayer`]. Cloning it gives another handle to the same rates.
+#[derive(Debug, Clone)]
+pub struct ServiceMetrics {
+    requests: Arc<Mutex<EwmaRate<Request>>>,
+    received: ThroughputHandle,
+    sent: ThroughputHandle,
+}
+
+impl ServiceMetrics {
+    fn new() -> Self {
+        Self {
+            requests: Arc::new(Mutex::new(EwmaRate::new(Measurement::<Second>::new(1.0)))),
+            received: ThroughputHandle::new(),
+            sent: ThroughputHandle::new(),
+        }
+    }
+
+    fn record_request(&self) {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(Measurement::<Request>::new(1.0));
+    }
+
+    /// Returns the handle to the throughput of the request bodies.
+    pub fn received(&self) -> &ThroughputHandle {
+        &self.received
+    }
+
+    /// Returns the handle to the throughput of the response bodies.
+    pub fn sent(&self) -> &ThroughputHandle {
+        &self.sent
+    }
+
+    /// Returns the current rates, smoothed up to now.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let mut requests = self.requests.lock().unwrap_or_else(|e| e.into_inner());
+
+        MetricsSnapshot {
+            request_rate: requests.update().into_unit(),
+            requests: requests.counter().total(),
+            received: self.received.total(),
+            receive_rate: self.received.rate(),
+            sent: self.sent.total(),
+            send_rate: self.sent.rate(),
+        }
+    }
+}
+
+/// Rates of the requests handled by a service at some point, returned
+/// by [`ServiceMetrics::snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsSnapshot {
+    /// Requests handled since the layer was created.
+    pub requests: Measurement<Request>,
+    /// Live rate of requests.
+    pub request_rate: Measurement<RequestsPerSecond>,
+    /// Data received in request bodies.
+    pub received: Measurement<Byte>,
+    /// Live rate of the data received in request bodies.
+    pub receive_rate: Measurement<Bps>,
+    /// Data sent in response bodies.
+    pub sent: Measurement<Byte>,
+    /// Live rate of the data sent in response bodies.
+    pub send_rate: Measurement<Bps>,
+}
+
+/// Layer tracking the rates of the requests handled by the services it
+/// wraps. All the services wrapped by the same layer share its
+/// [metrics](Self::metrics), so a layer should be created for each
+/// service tracked separately.
+#[derive(Debug, Clone)]
+pub struct RateLayer {
+    metrics: ServiceMetrics,
+}
+
+impl RateLayer {
+    /// Creates a layer with new metrics.
+    pub fn new() -> Self {
+        Self {
+            metrics: ServiceMetrics::new(),
+        }
+    }
+
+    /// Returns a handle to the metrics of the wrapped services.
+    pub fn metrics(&self) -> ServiceMetrics {
+        self.metrics.clone()
+    }
+}
+
+impl Default for RateLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for RateLayer {
+    type Service = RateService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateService {
+            inner,
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+/// Service tracking the rates of the requests handled by another one.
+/// Created through [`RateLayer`]. The bodies of the requests and the
+/// responses are wrapped in [`MeasuredBody`].
+#[derive(Debug, Clone)]
+pub struct RateService<S> {
+    inner: S,
+    metrics: ServiceMetrics,
+}
+
+impl<S, B, RB> Service<http::Request<B>> for RateService<S>
+where
+    S: Service<http::Request<MeasuredBody<B>>, Response = http::Response<RB>>,
+{
+    type Response = http::Response<MeasuredBody<RB>>;
+    type Error = S::Error;
+    type Future = RateFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        self.metrics.record_request();
+        let received = self.metrics.received.clone();
+        let request = request.map(|body| MeasuredBody::with_handle(body, received));
+
+        RateFuture {
+            future: self.inner.call(request),
+            sent: self.metrics.sent.clone(),
+        }
+    }
+}
+
+pin_project! {
+    /// Response future of a [`RateService`].
+    #[derive(Debug)]
+    pub struct RateFuture<F> {
+        #[pin]
+        future: F,
+        sent: ThroughputHandle,
+    }
+}
+
+impl<F, RB, E> Future for RateFuture<F>
+where
+    F: Future<Output = Result<http::Response<RB>, E>>,
+{
+    type Output = Result<http::Response<MeasuredBody<RB>>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let sent = this.sent;
+
+        this.future.poll(cx).map(|result| {
+            result
+                .map(|response| response.map(|body| MeasuredBody::with_handle(body, sent.clone())))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use futures::executor::block_on;
+    use http_body_util::{BodyExt, Full};
+    use tower::{service_fn, Layer, ServiceExt};
+
+    use super::RateLayer;
+    use crate::http::MeasuredBody;
+
+    #[test]
+    fn test_rate_layer() {
+        let layer = RateLayer::new();
+        let metrics = layer.metrics();
+        let service = layer.layer(service_fn(
+            |request: http::Request<MeasuredBody<Full<Bytes>>>| async move {
+                let body = request.into_body().collect().await?.to_bytes();
+                Ok::<_, Infallible>(http::Response::new(Full::new(Bytes::from(body.repeat(2)))))
+            },
+        ));
+
+        for _ in 0..3 {
+            let request = http::Request::new(Full::new(Bytes::from_static(&[0; 100])));
+            let response = block_on(service.clone().oneshot(request)).unwrap();
+            block_on(response.into_body().collect()).unwrap();
+        }
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(3.0, snapshot.requests.value());
+        assert_eq!(300.0, snapshot.received.value());
+        assert_eq!(600.0, snapshot.sent.value());
+        assert!(snapshot.request_rate.value() > 0.0);
+        assert_eq!(600.0, metrics.sent().total().value());
+    }
+}
@@ -0,0 +1,159 @@
+//! Hooks for observing the conversions performed by
+//! [`Measurement::into_unit`](crate::Measurement::into_unit) and
+//! [`Measurement::checked_into_unit`](crate::Measurement::checked_into_unit),
+//! including the ones performed by arithmetic between measurements of
+//! different units. Conversions between units with the same canonical
+//! form keep values as they are, so they aren't traced. Only available
+//! when the `trace` feature is enabled.
+//!
+//! Hooks are installed per thread, so a hook can be scoped to a
+//! specific code path without observing conversions performed
+//! elsewhere in the application.
+
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use crate::MeasureUnit;
+
+/// Describes a single conversion between two units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionEvent {
+    /// Symbol of the unit the value was converted from.
+    pub from: Cow<'static, str>,
+    /// Symbol of the unit the value was converted into.
+    pub to: Cow<'static, str>,
+    /// Numerical value before the conversion.
+    pub input: f64,
+    /// Numerical value after the conversion.
+    pub output: f64,
+}
+
+type Hook = Rc<dyn Fn(&ConversionEvent)>;
+
+thread_local! {
+    static HOOK: RefCell<Option<Hook>> = RefCell::new(None);
+}
+
+/// Restores the previously installed hook when dropped, so scoped
+/// hooks are uninstalled even if the traced code panics.
+struct RestoreGuard(Option<Hook>);
+
+impl Drop for RestoreGuard {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        HOOK.with(|hook| *hook.borrow_mut() = previous);
+    }
+}
+
+/// Installs the given hook for the current thread, returning whether
+/// a hook was already installed (in which case it gets replaced).
+pub fn set_conversion_hook<H>(hook: H) -> bool
+where
+    H: Fn(&ConversionEvent) + 'static,
+{
+    HOOK.with(|current| current.borrow_mut().replace(Rc::new(hook)).is_some())
+}
+
+/// Removes the hook installed for the current thread, if any.
+pub fn clear_conversion_hook() {
+    HOOK.with(|current| current.borrow_mut().take());
+}
+
+/// Runs `f` with the given hook installed for the current thread,
+/// restoring the previously installed hook afterwards.
+pub fn with_conversion_hook<H, F, R>(hook: H, f: F) -> R
+where
+    H: Fn(&ConversionEvent) + 'static,
+    F: FnOnce() -> R,
+{
+    let previous = HOOK.with(|current| current.borrow_mut().replace(Rc::new(hook)));
+    let _guard = RestoreGuard(previous);
+    f()
+}
+
+/// Notifies the hook of the current thread, if any, about a
+/// conversion from the unit U into the unit V.
+pub(crate) fn notify<U: MeasureUnit, V: MeasureUnit>(input: f64, output: f64) {
+    // The hook is cloned out of the cell so it can perform conversions
+    // itself without hitting a re-entrant borrow.
+    let hook = HOOK.with(|current| current.borrow().clone());
+
+    if let Some(hook) = hook {
+        hook(&ConversionEvent {
+            from: U::symbol(),
+            to: V::symbol(),
+            input,
+            output,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::{clear_conversion_hook, set_conversion_hook, with_conversion_hook};
+    use crate::Measurement;
+
+    crate::define_unit!(Second, "s");
+    crate::define_unit!(Hour, "h");
+
+    crate::define_linear_conversions! {
+        quantity: "time";
+
+        (Second, 1),
+        (Hour, 3600 Second)
+    }
+
+    #[test]
+    fn test_scoped_hook() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = events.clone();
+
+        let r: Measurement<Second> = with_conversion_hook(
+            move |event| sink.borrow_mut().push(event.clone()),
+            || Measurement::<Hour>::new(2.0).into_unit(),
+        );
+        let _: Measurement<Second> = Measurement::<Hour>::new(1.0).into_unit();
+
+        let events = events.borrow();
+        assert_eq!(1, events.len());
+        assert_eq!("h", events[0].from);
+        assert_eq!("s", events[0].to);
+        assert_eq!(2.0, events[0].input);
+        assert_eq!(r.value(), events[0].output);
+    }
+
+    #[test]
+    fn test_arithmetic_is_traced() {
+        let count = Rc::new(RefCell::new(0));
+        let sink = count.clone();
+
+        assert!(!set_conversion_hook(move |_| *sink.borrow_mut() += 1));
+        let _ = Measurement::<Hour>::new(1.0) + Measurement::<Second>::new(1.0);
+        // Nothing is converted when adding measurements of the same unit.
+        let _ = Measurement::<Hour>::new(1.0) + Measurement::<Hour>::new(1.0);
+        let _ = Measurement::<Hour>::new(1.0).into_unit::<Hour>();
+        clear_conversion_hook();
+        let _ = Measurement::<Hour>::new(1.0) + Measurement::<Second>::new(1.0);
+
+        assert_eq!(1, *count.borrow());
+    }
+
+    #[test]
+    fn test_checked_conversions_are_traced() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = events.clone();
+
+        with_conversion_hook(
+            move |event| sink.borrow_mut().push(event.clone()),
+            || {
+                let _ = Measurement::<Hour, u64>::new(2).checked_into_unit::<Second>();
+                let _ = Measurement::<Hour, u64>::new(2).checked_into_unit::<Hour>();
+            },
+        );
+
+        let events = events.borrow();
+        assert_eq!(1, events.len());
+        assert_eq!(7200.0, events[0].output);
+    }
+}
@@ -0,0 +1,299 @@
+//! Type-level computation of the unit resulting from multiplying or
+//! dividing measurements. For products, units are classified by their
+//! [kind](MeasureUnit::Kind): quotients cancel their denominator out
+//! against the other operand, and any other pair of units is combined
+//! into a [`MulUnit`]. For quotients, the [dimensions](crate::dimension)
+//! of both units are [compared](Compare): units of the same dimension
+//! cancel out into a plain ratio, a quotient on the right whose
+//! numerator has the dimension of the left operand leaves its
+//! denominator, and any other pair of units is combined into a
+//! [`DivUnit`].
+
+use std::marker::PhantomData;
+
+use crate::{
+    dimension::Dimension, Canonical, DivUnit, FromUnit, MeasureUnit, Measurement, MulUnit, Number,
+    QuotientUnit,
+};
+
+/// Kind of the units that are not a quotient of two other units.
+pub struct Plain;
+
+/// Kind of the units that are the quotient of two other units, like
+/// [`DivUnit`](crate::DivUnit).
+pub struct Quotient;
+
+/// Kind of the canonical form of the unit U.
+pub type Kind<U> = <Canonical<U> as MeasureUnit>::Kind;
+
+type Numerator<U> = <Canonical<U> as QuotientUnit>::Numerator;
+type Denominator<U> = <Canonical<U> as QuotientUnit>::Denominator;
+
+/// Product of Self and the unit Rhs, given the kinds of both as K, so
+/// that the implementations for each pair of kinds don't overlap.
+pub trait UnitProduct<Rhs, K> {
+    type Output: MeasureUnit;
+
+    fn multiply<T: Number>(lhs: T, rhs: Measurement<Rhs, T>) -> T;
+}
+
+/// Plain units are combined, e.g km × km = km·km.
+impl<L: MeasureUnit, R: MeasureUnit> UnitProduct<R, (Plain, Plain)> for L {
+    type Output = MulUnit<L, R>;
+
+    #[inline]
+    fn multiply<T: Number>(lhs: T, rhs: Measurement<R, T>) -> T {
+        lhs * rhs.value()
+    }
+}
+
+/// The denominator of a quotient on the left cancels out against the
+/// right operand, e.g Kb/s × s = Kb.
+impl<L: MeasureUnit, R: MeasureUnit, K> UnitProduct<R, (Quotient, K)> for L
+where
+    Canonical<L>: QuotientUnit,
+    Canonical<Denominator<L>>: FromUnit<R>,
+{
+    type Output = Numerator<L>;
+
+    #[inline]
+    fn multiply<T: Number>(lhs: T, rhs: Measurement<R, T>) -> T {
+        lhs * rhs.into_unit::<Denominator<L>>().value()
+    }
+}
+
+/// The denominator of a quotient on the right cancels out against the
+/// left operand, e.g s × Kb/s = Kb.
+impl<L: MeasureUnit, R: MeasureUnit> UnitProduct<R, (Plain, Quotient)> for L
+where
+    Canonical<R>: QuotientUnit,
+    Canonical<Denominator<R>>: FromUnit<L>,
+{
+    type Output = Numerator<R>;
+
+    #[inline]
+    fn multiply<T: Number>(lhs: T, rhs: Measurement<R, T>) -> T {
+        Measurement::<L, T>::new(lhs)
+            .into_unit::<Denominator<R>>()
+            .value()
+            * rhs.value()
+    }
+}
+
+/// Identifier of the dimensions that can't be told apart from others at
+/// compile time, like the ones defined outside of this crate. They are
+/// never taken as the same as any other dimension, not even themselves.
+pub struct Unidentified;
+
+/// Identifier of a base dimension, given by its position N, counted
+/// through [`Zero`] and [`Succ`].
+pub struct BaseId<N> {
+    _n: PhantomData<N>,
+}
+
+/// Position of the first base dimension.
+pub struct Zero;
+
+/// Position following N.
+pub struct Succ<N> {
+    _n: PhantomData<N>,
+}
+
+/// Identifier of a [quotient](crate::dimension::Quotient) of dimensions.
+pub struct QuotientId<N, D> {
+    _n: PhantomData<N>,
+    _d: PhantomData<D>,
+}
+
+/// Identifier of a [product](crate::dimension::Product) of dimensions.
+pub struct ProductId<L, R> {
+    _l: PhantomData<L>,
+    _r: PhantomData<R>,
+}
+
+/// Identifier of the [reciprocal](crate::dimension::Reciprocal) of a
+/// dimension.
+pub struct ReciprocalId<D> {
+    _d: PhantomData<D>,
+}
+
+/// Result of comparing two identifiers that are the same one.
+pub struct Same;
+
+/// Result of comparing two identifiers that are not the same one.
+pub struct Different;
+
+/// Compares the identifier Self with Rhs, into either [`Same`] or
+/// [`Different`].
+pub trait Compare<Rhs> {
+    type Output;
+}
+
+/// Conjunction of the results of two comparisons.
+pub trait And<Rhs> {
+    type Output;
+}
+
+impl And<Same> for Same {
+    type Output = Same;
+}
+
+impl And<Different> for Same {
+    type Output = Different;
+}
+
+impl<R> And<R> for Different {
+    type Output = Different;
+}
+
+impl Compare<Zero> for Zero {
+    type Output = Same;
+}
+
+impl<N> Compare<Succ<N>> for Zero {
+    type Output = Different;
+}
+
+impl<N> Compare<Zero> for Succ<N> {
+    type Output = Different;
+}
+
+impl<N: Compare<M>, M> Compare<Succ<M>> for Succ<N> {
+    type Output = N::Output;
+}
+
+impl<N: Compare<M>, M> Compare<BaseId<M>> for BaseId<N> {
+    type Output = N::Output;
+}
+
+impl<N1: Compare<N2>, D1: Compare<D2>, N2, D2> Compare<QuotientId<N2, D2>> for QuotientId<N1, D1>
+where
+    N1::Output: And<D1::Output>,
+{
+    type Output = <N1::Output as And<D1::Output>>::Output;
+}
+
+impl<L1: Compare<L2>, R1: Compare<R2>, L2, R2> Compare<ProductId<L2, R2>> for ProductId<L1, R1>
+where
+    L1::Output: And<R1::Output>,
+{
+    type Output = <L1::Output as And<R1::Output>>::Output;
+}
+
+impl<D1: Compare<D2>, D2> Compare<ReciprocalId<D2>> for ReciprocalId<D1> {
+    type Output = D1::Output;
+}
+
+/// Identifiers of different shapes are never the same one.
+macro_rules! impl_different {
+    ($([$($param:ident),*] $lhs:ty, $rhs:ty;)*) => {
+        $(
+            impl<$($param),*> Compare<$rhs> for $lhs {
+                type Output = Different;
+            }
+        )*
+    };
+}
+
+impl_different! {
+    [] Unidentified, Unidentified;
+    [N] Unidentified, BaseId<N>;
+    [N, D] Unidentified, QuotientId<N, D>;
+    [L, R] Unidentified, ProductId<L, R>;
+    [D] Unidentified, ReciprocalId<D>;
+    [N] BaseId<N>, Unidentified;
+    [N, N1, D1] BaseId<N>, QuotientId<N1, D1>;
+    [N, L1, R1] BaseId<N>, ProductId<L1, R1>;
+    [N, D1] BaseId<N>, ReciprocalId<D1>;
+    [N, D] QuotientId<N, D>, Unidentified;
+    [N, D, N1] QuotientId<N, D>, BaseId<N1>;
+    [N, D, L1, R1] QuotientId<N, D>, ProductId<L1, R1>;
+    [N, D, D1] QuotientId<N, D>, ReciprocalId<D1>;
+    [L, R] ProductId<L, R>, Unidentified;
+    [L, R, N1] ProductId<L, R>, BaseId<N1>;
+    [L, R, N1, D1] ProductId<L, R>, QuotientId<N1, D1>;
+    [L, R, D1] ProductId<L, R>, ReciprocalId<D1>;
+    [D] ReciprocalId<D>, Unidentified;
+    [D, N1] ReciprocalId<D>, BaseId<N1>;
+    [D, N1, D1] ReciprocalId<D>, QuotientId<N1, D1>;
+    [D, L1, R1] ReciprocalId<D>, ProductId<L1, R1>;
+}
+
+/// Identifier of the dimension of the unit U.
+pub type DimensionId<U> = <<U as MeasureUnit>::Dimension as Dimension>::Id;
+
+/// Whether the units L and R have the same dimension, as either
+/// [`Same`] or [`Different`].
+pub type Comparison<L, R> = <DimensionId<L> as Compare<DimensionId<R>>>::Output;
+
+/// Implemented by the kinds of units, for comparing the dimension of
+/// the unit L with the one of the numerator of the unit R, which is
+/// always [`Different`] if R is not a quotient.
+pub trait CompareNumerator<L, R> {
+    type Output;
+}
+
+impl<L, R> CompareNumerator<L, R> for Plain {
+    type Output = Different;
+}
+
+impl<L: MeasureUnit, R: MeasureUnit> CompareNumerator<L, R> for Quotient
+where
+    Canonical<R>: QuotientUnit,
+    DimensionId<L>: Compare<DimensionId<Numerator<R>>>,
+{
+    type Output = Comparison<L, Numerator<R>>;
+}
+
+/// Whether the unit L has the same dimension as the numerator of the
+/// unit R, as either [`Same`] or [`Different`].
+pub type NumeratorComparison<L, R> = <Kind<R> as CompareNumerator<L, R>>::Output;
+
+/// Quotient of Self and the unit Rhs for values of type T, given the
+/// comparisons of the dimension of Self with the ones of Rhs and of its
+/// numerator as K, so that the implementations for each result don't
+/// overlap.
+pub trait UnitQuotient<Rhs, T, K>: Sized {
+    type Output;
+
+    fn divide(lhs: Measurement<Self, T>, rhs: Measurement<Rhs, T>) -> Self::Output;
+}
+
+/// Units of different dimensions are combined, e.g KiB ÷ s = KiB/s.
+impl<N: MeasureUnit, D: MeasureUnit, T: Number> UnitQuotient<D, T, (Different, Different)> for N {
+    type Output = Measurement<DivUnit<N, D>, T>;
+
+    #[inline]
+    fn divide(lhs: Measurement<N, T>, rhs: Measurement<D, T>) -> Self::Output {
+        Measurement::new(lhs.value() / rhs.value())
+    }
+}
+
+/// Units of the same dimension cancel out into the ratio of both
+/// measurements, e.g 1 KB ÷ 1 B = 1000.
+impl<N: MeasureUnit, D: MeasureUnit, T: Number, K> UnitQuotient<D, T, (Same, K)> for N
+where
+    Canonical<N>: FromUnit<D>,
+{
+    type Output = f64;
+
+    #[inline]
+    fn divide(lhs: Measurement<N, T>, rhs: Measurement<D, T>) -> f64 {
+        lhs.ratio(rhs)
+    }
+}
+
+/// The numerator of a quotient on the right cancels out against the
+/// left operand, leaving its denominator, e.g MB ÷ Mb/s = s.
+impl<N: MeasureUnit, D: MeasureUnit, T: Number> UnitQuotient<D, T, (Different, Same)> for N
+where
+    Canonical<D>: QuotientUnit,
+    Canonical<Numerator<D>>: FromUnit<N>,
+{
+    type Output = Measurement<Denominator<D>, T>;
+
+    #[inline]
+    fn divide(lhs: Measurement<N, T>, rhs: Measurement<D, T>) -> Self::Output {
+        Measurement::new(lhs.into_unit::<Numerator<D>>().value() / rhs.value())
+    }
+}
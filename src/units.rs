@@ -1,85 +1,249 @@
-use crate::{define_alias, define_linear_conversions, define_unit, DivUnit};
+//! Built-in units, organized in one submodule per quantity. Each
+//! family of units is gated behind its own cargo feature, so only the
+//! conversions actually used get compiled. All the units are also
+//! re-exported from this module.
 
-// Time
-define_unit!(Second, "s");
-define_unit!(Minute, "min");
-define_unit!(Hour, "h");
+#[cfg(feature = "time")]
+pub mod time;
 
-define_linear_conversions! {
-    (Second, 1),
-    (Minute, 60),
-    (Hour, 3600)
-}
+#[cfg(feature = "data")]
+pub mod data;
+
+#[cfg(feature = "rate")]
+pub mod rate;
+
+#[cfg(feature = "currency")]
+pub mod currency;
+
+#[cfg(feature = "photometric")]
+pub mod photometric;
+
+#[cfg(feature = "radiation")]
+pub mod radiation;
+
+#[cfg(feature = "mass")]
+pub mod mass;
+
+#[cfg(feature = "volume")]
+pub mod volume;
+
+#[cfg(feature = "chemistry")]
+pub mod chemistry;
+
+#[cfg(feature = "audio")]
+pub mod audio;
+
+#[cfg(feature = "length")]
+pub mod length;
+
+#[cfg(feature = "area")]
+pub mod area;
+
+#[cfg(feature = "motion")]
+pub mod motion;
+
+#[cfg(feature = "graphics")]
+pub mod graphics;
+
+#[cfg(feature = "mechanics")]
+pub mod mechanics;
+
+#[cfg(feature = "flow")]
+pub mod flow;
+
+#[cfg(feature = "nautical")]
+pub mod nautical;
+
+#[cfg(feature = "imperial")]
+pub mod imperial;
+
+#[cfg(feature = "typography")]
+pub mod typography;
+
+#[cfg(feature = "clock")]
+pub mod clock;
+
+#[cfg(feature = "count")]
+pub mod count;
+
+#[cfg(feature = "storage")]
+pub mod storage;
+
+#[cfg(feature = "temperature")]
+pub mod temperature;
+
+#[cfg(feature = "time")]
+pub use time::*;
+
+#[cfg(feature = "data")]
+pub use data::*;
 
-// Bits
-define_unit!(Bit, "b");
-define_unit!(Kilobit, "Kb");
-define_unit!(Megabit, "Mb");
-define_unit!(Gigabit, "Gb");
-define_unit!(Terabit, "Tb");
-define_unit!(Petabit, "Pb");
-define_unit!(Exabit, "Eb");
-define_unit!(Zettabit, "Zb");
-define_unit!(Yottabit, "Yb");
-
-// Bytes
-define_unit!(Byte, "B");
-define_unit!(Kilobyte, "KB");
-define_unit!(Megabyte, "MB");
-define_unit!(Gigabyte, "GB");
-define_unit!(Terabyte, "TB");
-define_unit!(Petabyte, "PB");
-define_unit!(Exabyte, "EB");
-define_unit!(Zettabyte, "ZB");
-define_unit!(Yottabyte, "YB");
-
-// Bytes (power of 2)
-define_unit!(Kibibyte, "KiB");
-define_unit!(Mebibyte, "MiB");
-define_unit!(Gibibyte, "GiB");
-define_unit!(Tebibyte, "TiB");
-define_unit!(Pebibyte, "PiB");
-define_unit!(Exbibyte, "EiB");
-define_unit!(Zebibyte, "ZiB");
-define_unit!(Yobibyte, "YiB");
-
-define_linear_conversions! {
-    // Bits
-    (Bit     , 1.0),
-    (Kilobit , 1_000.0),
-    (Megabit , 1_000_000.0),
-    (Gigabit , 1_000_000_000.0),
-    (Terabit , 1_000_000_000_000.0),
-    (Petabit , 1_000_000_000_000_000.0),
-    (Exabit  , 1_000_000_000_000_000_000.0),
-    (Zettabit, 1_000_000_000_000_000_000_000.0),
-    (Yottabit, 1_000_000_000_000_000_000_000_000.0),
-
-    // Bytes
-    (Byte     , 8.0),
-    (Kilobyte , 8_000.0),
-    (Megabyte , 8_000_000.0),
-    (Gigabyte , 8_000_000_000.0),
-    (Terabyte , 8_000_000_000_000.0),
-    (Petabyte , 8_000_000_000_000_000.0),
-    (Exabyte  , 8_000_000_000_000_000_000.0),
-    (Zettabyte, 8_000_000_000_000_000_000_000.0),
-    (Yottabyte, 8_000_000_000_000_000_000_000_000.0),
-
-    // Bytes (power of 2)
-    (Kibibyte, 8_192.0),
-    (Mebibyte, 8_388_608.0),
-    (Gibibyte, 8_589_934_592.0),
-    (Tebibyte, 8_796_093_022_208.0),
-    (Pebibyte, 9_007_199_254_740_992.0),
-    (Exbibyte, 9_223_372_036_854_775_808.0),
-    (Zebibyte, 9_444_732_965_739_290_427_392.0),
-    (Yobibyte, 9_671_406_556_917_033_397_649_408.0)
+#[cfg(feature = "rate")]
+pub use rate::*;
+
+#[cfg(feature = "currency")]
+pub use currency::*;
+
+#[cfg(feature = "photometric")]
+pub use photometric::*;
+
+#[cfg(feature = "radiation")]
+pub use radiation::*;
+
+#[cfg(feature = "mass")]
+pub use mass::*;
+
+#[cfg(feature = "volume")]
+pub use volume::*;
+
+#[cfg(feature = "chemistry")]
+pub use chemistry::*;
+
+#[cfg(feature = "audio")]
+pub use audio::*;
+
+#[cfg(feature = "length")]
+pub use length::*;
+
+#[cfg(feature = "area")]
+pub use area::*;
+
+#[cfg(feature = "motion")]
+pub use motion::*;
+
+#[cfg(feature = "graphics")]
+pub use graphics::*;
+
+#[cfg(feature = "mechanics")]
+pub use mechanics::*;
+
+#[cfg(feature = "flow")]
+pub use flow::*;
+
+#[cfg(feature = "nautical")]
+pub use nautical::*;
+
+#[cfg(feature = "imperial")]
+pub use imperial::*;
+
+#[cfg(feature = "typography")]
+pub use typography::*;
+
+#[cfg(feature = "clock")]
+pub use clock::*;
+
+#[cfg(feature = "count")]
+pub use count::*;
+
+#[cfg(feature = "storage")]
+pub use storage::*;
+
+#[cfg(feature = "temperature")]
+pub use temperature::*;
+
+use crate::{dynamic::DynUnit, registry};
+
+/// Sets of the built-in units enabled through features, each one
+/// sorted from smallest to largest.
+pub const UNIT_SETS: &[&[&dyn DynUnit]] = &[
+    #[cfg(feature = "time")]
+    TIME_UNITS,
+    #[cfg(feature = "data")]
+    BIT_UNITS,
+    #[cfg(feature = "data")]
+    BYTE_UNITS,
+    #[cfg(feature = "data")]
+    BINARY_BYTE_UNITS,
+    #[cfg(feature = "data")]
+    STORAGE_BLOCK_UNITS,
+    #[cfg(feature = "rate")]
+    RATE_UNITS,
+    #[cfg(feature = "rate")]
+    SYMBOL_RATE_UNITS,
+    #[cfg(feature = "photometric")]
+    LUMINOUS_INTENSITY_UNITS,
+    #[cfg(feature = "photometric")]
+    LUMINOUS_FLUX_UNITS,
+    #[cfg(feature = "photometric")]
+    ILLUMINANCE_UNITS,
+    #[cfg(feature = "radiation")]
+    RADIOACTIVITY_UNITS,
+    #[cfg(feature = "radiation")]
+    ABSORBED_DOSE_UNITS,
+    #[cfg(feature = "radiation")]
+    EQUIVALENT_DOSE_UNITS,
+    #[cfg(feature = "temperature")]
+    TEMPERATURE_UNITS,
+    #[cfg(feature = "mass")]
+    MASS_UNITS,
+    #[cfg(feature = "volume")]
+    VOLUME_UNITS,
+    #[cfg(feature = "chemistry")]
+    AMOUNT_OF_SUBSTANCE_UNITS,
+    #[cfg(feature = "chemistry")]
+    MOLAR_CONCENTRATION_UNITS,
+    #[cfg(feature = "chemistry")]
+    MASS_CONCENTRATION_UNITS,
+    #[cfg(feature = "audio")]
+    SAMPLE_UNITS,
+    #[cfg(feature = "audio")]
+    SAMPLE_RATE_UNITS,
+    #[cfg(feature = "length")]
+    LENGTH_UNITS,
+    #[cfg(feature = "area")]
+    AREA_UNITS,
+    #[cfg(feature = "motion")]
+    SPEED_UNITS,
+    #[cfg(feature = "motion")]
+    ACCELERATION_UNITS,
+    #[cfg(feature = "graphics")]
+    PIXEL_UNITS,
+    #[cfg(feature = "graphics")]
+    PIXEL_RATE_UNITS,
+    #[cfg(feature = "mechanics")]
+    FORCE_UNITS,
+    #[cfg(feature = "mechanics")]
+    TORQUE_UNITS,
+    #[cfg(feature = "flow")]
+    FLOW_RATE_UNITS,
+    #[cfg(feature = "nautical")]
+    NAUTICAL_LENGTH_UNITS,
+    #[cfg(feature = "nautical")]
+    NAUTICAL_SPEED_UNITS,
+    #[cfg(feature = "imperial")]
+    IMPERIAL_LENGTH_UNITS,
+    #[cfg(feature = "imperial")]
+    IMPERIAL_MASS_UNITS,
+    #[cfg(feature = "imperial")]
+    IMPERIAL_VOLUME_UNITS,
+    #[cfg(feature = "typography")]
+    TYPOGRAPHIC_UNITS,
+    #[cfg(feature = "clock")]
+    CYCLE_UNITS,
+    #[cfg(feature = "clock")]
+    CLOCK_RATE_UNITS,
+    #[cfg(feature = "count")]
+    OPERATION_UNITS,
+    #[cfg(feature = "count")]
+    OPERATION_THROUGHPUT_UNITS,
+    #[cfg(feature = "count")]
+    REQUEST_UNITS,
+    #[cfg(feature = "count")]
+    REQUEST_RATE_UNITS,
+];
+
+/// Looks up the built-in unit with the given symbol, or with the given
+/// ASCII fallback of its symbol.
+pub fn find_builtin_unit(symbol: &str) -> Option<&'static dyn DynUnit> {
+    UNIT_SETS
+        .iter()
+        .flat_map(|units| units.iter())
+        .find(|unit| unit.symbol() == symbol || unit.ascii_symbol() == symbol)
+        .copied()
 }
 
-// Transmission speed
-define_alias!(DivUnit<Bit    , Second> as Bps , "bps");
-define_alias!(DivUnit<Kilobit, Second> as Kbps, "Kbps");
-define_alias!(DivUnit<Megabit, Second> as Mbps, "Mbps");
-define_alias!(DivUnit<Gigabit, Second> as Gbps, "Gbps");
-define_alias!(DivUnit<Terabit, Second> as Tbps, "Tbps");
+/// Looks up the unit with the given symbol, either built-in or
+/// [registered at runtime](crate::registry::register_unit).
+pub fn find_unit(symbol: &str) -> Option<&'static dyn DynUnit> {
+    find_builtin_unit(symbol).or_else(|| registry::find_registered_unit(symbol))
+}
@@ -0,0 +1,48 @@
+//! Units of area, defined as the square of the units of length.
+
+use super::length::{Centimeter, Kilometer, Meter, Millimeter};
+use crate::{define_alias, dyn_units, dynamic::DynUnit, PowUnit};
+
+define_alias!(PowUnit<Millimeter, 2> as SquareMillimeter, "mm²");
+define_alias!(PowUnit<Centimeter, 2> as SquareCentimeter, "cm²");
+define_alias!(PowUnit<Meter     , 2> as SquareMeter     , "m²");
+define_alias!(PowUnit<Kilometer , 2> as SquareKilometer , "km²");
+
+/// All the units of area, sorted from smallest to largest.
+pub const AREA_UNITS: &[&dyn DynUnit] = dyn_units![
+    SquareMillimeter,
+    SquareCentimeter,
+    SquareMeter,
+    SquareKilometer
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{SquareCentimeter, SquareKilometer, SquareMeter};
+    use crate::{
+        units::{Kilometer, Meter},
+        MeasureUnit, Measurement,
+    };
+
+    #[test]
+    fn test_areas() {
+        let plot = Measurement::<Meter>::new(20.0).powi::<2>();
+
+        assert_eq!(400.0, plot.into_unit::<SquareMeter>().value());
+        assert_eq!(4_000_000.0, plot.into_unit::<SquareCentimeter>().value());
+        assert_eq!(
+            Measurement::<SquareKilometer>::new(0.5),
+            Measurement::<SquareMeter>::new(500_000.0)
+        );
+        assert_eq!("400 m²", plot.into_unit::<SquareMeter>().to_string());
+        assert_eq!("m^2", SquareMeter::ascii_symbol());
+
+        let side = Measurement::<Kilometer>::new(0.5);
+        assert_eq!(
+            250_000.0,
+            (side * Measurement::<Meter>::new(500.0))
+                .into_square::<Meter>()
+                .raw_in::<SquareMeter>()
+        );
+    }
+}
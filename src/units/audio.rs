@@ -0,0 +1,198 @@
+//! Units of digital audio: counts of samples and the sample rates
+//! derived from them, plus calculators relating the duration, the
+//! number of samples and the size of uncompressed audio of a given
+//! format.
+
+use super::{
+    data::{Bit, Byte},
+    rate::Bps,
+    time::Second,
+};
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::Samples, dyn_units,
+    dynamic::DynUnit, DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+// Sample counts
+define_unit!(Sample, "Sa", name: "sample", dimension: Samples);
+define_unit!(Kilosample, "kSa", name: "kilosample", dimension: Samples);
+define_unit!(Megasample, "MSa", name: "megasample", dimension: Samples);
+
+define_linear_conversions! {
+    quantity: "samples";
+
+    (Sample    , 1.0),
+    (Kilosample, 1_000.0),
+    (Megasample, 1_000_000.0)
+}
+
+// Sample rates
+define_alias!(DivUnit<Sample    , Second> as Sps , "Sa/s");
+define_alias!(DivUnit<Kilosample, Second> as KSps, "kSa/s");
+define_alias!(DivUnit<Megasample, Second> as MSps, "MSa/s");
+
+/// All the units of sample counts, sorted from smallest to largest.
+pub const SAMPLE_UNITS: &[&dyn DynUnit] = dyn_units![Sample, Kilosample, Megasample];
+
+/// All the units of sample rates, sorted from smallest to largest.
+pub const SAMPLE_RATE_UNITS: &[&dyn DynUnit] = dyn_units![Sps, KSps, MSps];
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Splits this bitrate, shared by the given number of channels, into
+    /// the bitrate of each one of them.
+    pub fn per_channel(self, channels: u32) -> Self
+    where
+        DivUnit<Bit, Second>: FromUnit<U>,
+    {
+        self / channels
+    }
+
+    /// Returns the total bitrate of the given number of channels, each
+    /// one having this bitrate.
+    pub fn for_channels(self, channels: u32) -> Self
+    where
+        DivUnit<Bit, Second>: FromUnit<U>,
+    {
+        self * channels
+    }
+}
+
+/// Format of uncompressed (PCM) audio. Samples are counted per channel,
+/// so one second of 44.1 kSa/s stereo audio has 44100 samples, but
+/// twice as many are stored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AudioFormat {
+    sample_rate: Measurement<Sps>,
+    bits_per_sample: u32,
+    channels: u32,
+}
+
+impl AudioFormat {
+    /// Format of audio CDs: 44.1 kSa/s, 16 bits and two channels.
+    pub fn cd() -> Self {
+        Self::new(Measurement::<KSps>::new(44.1), 16, 2)
+    }
+
+    /// Creates a new format with the given sample rate, bits per
+    /// sample and number of channels.
+    pub fn new<R: MeasureUnit>(
+        sample_rate: Measurement<R>,
+        bits_per_sample: u32,
+        channels: u32,
+    ) -> Self
+    where
+        DivUnit<Sample, Second>: FromUnit<R>,
+    {
+        Self {
+            sample_rate: sample_rate.into_unit(),
+            bits_per_sample,
+            channels,
+        }
+    }
+
+    /// Returns the number of samples per second of each channel.
+    pub fn sample_rate(&self) -> Measurement<Sps> {
+        self.sample_rate
+    }
+
+    /// Returns the size of each sample, in bits.
+    pub fn bits_per_sample(&self) -> u32 {
+        self.bits_per_sample
+    }
+
+    /// Returns the number of channels.
+    pub fn channels(&self) -> u32 {
+        self.channels
+    }
+
+    /// Returns the bitrate of each channel.
+    pub fn bitrate_per_channel(&self) -> Measurement<Bps> {
+        Measurement::new(self.sample_rate.value() * self.bits_per_sample as f64)
+    }
+
+    /// Returns the bitrate of all the channels together.
+    pub fn bitrate(&self) -> Measurement<Bps> {
+        self.bitrate_per_channel().for_channels(self.channels)
+    }
+
+    /// Returns the number of samples of each channel in audio of the
+    /// given duration.
+    pub fn samples_in<T: MeasureUnit>(&self, duration: Measurement<T>) -> Measurement<Sample>
+    where
+        Second: FromUnit<T>,
+    {
+        Measurement::new(duration.into_unit::<Second>().value() * self.sample_rate.value())
+    }
+
+    /// Returns the duration of audio with the given number of samples
+    /// per channel.
+    pub fn duration_of<S: MeasureUnit>(&self, samples: Measurement<S>) -> Measurement<Second>
+    where
+        Sample: FromUnit<S>,
+    {
+        Measurement::new(samples.into_unit::<Sample>().value() / self.sample_rate.value())
+    }
+
+    /// Returns the size of audio of the given duration.
+    pub fn size_of<T: MeasureUnit>(&self, duration: Measurement<T>) -> Measurement<Byte>
+    where
+        Second: FromUnit<T>,
+    {
+        let bits = duration.into_unit::<Second>().value() * self.bitrate().value();
+        Measurement::<Bit>::new(bits).into_unit()
+    }
+
+    /// Returns the duration of audio of the given size.
+    pub fn duration_of_size<D: MeasureUnit>(&self, size: Measurement<D>) -> Measurement<Second>
+    where
+        Bit: FromUnit<D>,
+    {
+        Measurement::new(size.into_unit::<Bit>().value() / self.bitrate().value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AudioFormat, KSps, Kilosample, Sample};
+    use crate::{
+        units::{Kbps, Megabyte, Minute, Second},
+        Measurement,
+    };
+
+    #[test]
+    fn test_bitrates() {
+        let cd = AudioFormat::cd();
+
+        assert_eq!(1411.2, cd.bitrate().into_unit::<Kbps>().value());
+        assert_eq!(705.6, cd.bitrate_per_channel().into_unit::<Kbps>().value());
+        assert_eq!(
+            160.0,
+            Measurement::<Kbps>::new(320.0).per_channel(2).value()
+        );
+    }
+
+    #[test]
+    fn test_calculators() {
+        let format = AudioFormat::new(Measurement::<KSps>::new(48.0), 24, 2);
+
+        assert_eq!(
+            2880.0,
+            format
+                .samples_in(Measurement::<Minute>::new(1.0))
+                .into_unit::<Kilosample>()
+                .value()
+        );
+        assert_eq!(
+            0.5,
+            format
+                .duration_of(Measurement::<Sample>::new(24_000.0))
+                .value()
+        );
+        let size = format.size_of(Measurement::<Minute>::new(1.0));
+        assert!((size.into_unit::<Megabyte>().value() - 17.28).abs() < 1e-9);
+        assert_eq!(
+            Measurement::<Second>::new(60.0),
+            format.duration_of_size(Measurement::<Megabyte>::new(17.28))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Units of amount of substance, and the concentrations derived from
+//! them. Amounts and masses measure different quantities, so they
+//! only convert between them given the molar mass of the substance.
+
+use super::{
+    mass::{Gram, Milligram},
+    volume::{Deciliter, Liter},
+};
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::AmountOfSubstance, dyn_units,
+    dynamic::DynUnit, DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+// Amount of substance
+define_unit!(Micromole, "µmol", ascii: "umol", name: "micromole", dimension: AmountOfSubstance);
+define_unit!(Millimole, "mmol", name: "millimole", dimension: AmountOfSubstance);
+define_unit!(Mole, "mol", name: "mole", dimension: AmountOfSubstance);
+
+define_linear_conversions! {
+    quantity: "amount of substance";
+
+    (Micromole, 0.000_001),
+    (Millimole, 0.001),
+    (Mole     , 1.0)
+}
+
+// Molar concentration
+define_alias!(DivUnit<Micromole, Liter> as UmolPerL, "µmol/L");
+define_alias!(DivUnit<Millimole, Liter> as MmolPerL, "mmol/L");
+define_alias!(DivUnit<Mole     , Liter> as MolPerL , "mol/L");
+
+// Mass concentration
+define_alias!(DivUnit<Milligram, Deciliter> as MgPerDl, "mg/dL");
+define_alias!(DivUnit<Gram     , Liter    > as GPerL  , "g/L");
+
+// Molar mass
+define_alias!(DivUnit<Gram, Mole> as GPerMol, "g/mol");
+
+/// All the units of amount of substance, sorted from smallest to largest.
+pub const AMOUNT_OF_SUBSTANCE_UNITS: &[&dyn DynUnit] = dyn_units![Micromole, Millimole, Mole];
+
+/// All the units of molar concentration, sorted from smallest to largest.
+pub const MOLAR_CONCENTRATION_UNITS: &[&dyn DynUnit] = dyn_units![UmolPerL, MmolPerL, MolPerL];
+
+/// All the units of mass concentration, sorted from smallest to largest.
+pub const MASS_CONCENTRATION_UNITS: &[&dyn DynUnit] = dyn_units![MgPerDl, GPerL];
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Converts this amount of substance into the mass it weighs,
+    /// given the molar mass of the substance.
+    pub fn to_mass<M: MeasureUnit, MM: MeasureUnit>(
+        self,
+        molar_mass: Measurement<MM>,
+    ) -> Measurement<M>
+    where
+        Mole: FromUnit<U>,
+        DivUnit<Gram, Mole>: FromUnit<MM>,
+        M::AliasedUnit: FromUnit<Gram>,
+    {
+        let moles = self.into_unit::<Mole>().value();
+        Measurement::<Gram>::new(moles * molar_mass.into_unit::<GPerMol>().value()).into_unit()
+    }
+
+    /// Converts this mass into the amount of substance it contains,
+    /// given the molar mass of the substance.
+    pub fn to_amount<N: MeasureUnit, MM: MeasureUnit>(
+        self,
+        molar_mass: Measurement<MM>,
+    ) -> Measurement<N>
+    where
+        Gram: FromUnit<U>,
+        DivUnit<Gram, Mole>: FromUnit<MM>,
+        N::AliasedUnit: FromUnit<Mole>,
+    {
+        let grams = self.into_unit::<Gram>().value();
+        Measurement::<Mole>::new(grams / molar_mass.into_unit::<GPerMol>().value()).into_unit()
+    }
+
+    /// Converts this molar concentration into a mass concentration,
+    /// e.g from mmol/L into mg/dL, given the molar mass of the solute.
+    pub fn to_mass_concentration<C: MeasureUnit, MM: MeasureUnit>(
+        self,
+        molar_mass: Measurement<MM>,
+    ) -> Measurement<C>
+    where
+        DivUnit<Mole, Liter>: FromUnit<U>,
+        DivUnit<Gram, Mole>: FromUnit<MM>,
+        C::AliasedUnit: FromUnit<DivUnit<Gram, Liter>>,
+    {
+        let molarity = self.into_unit::<MolPerL>().value();
+        Measurement::<DivUnit<Gram, Liter>>::new(
+            molarity * molar_mass.into_unit::<GPerMol>().value(),
+        )
+        .into_unit()
+    }
+
+    /// Converts this mass concentration into a molar concentration,
+    /// e.g from mg/dL into mmol/L, given the molar mass of the solute.
+    pub fn to_molar_concentration<C: MeasureUnit, MM: MeasureUnit>(
+        self,
+        molar_mass: Measurement<MM>,
+    ) -> Measurement<C>
+    where
+        DivUnit<Gram, Liter>: FromUnit<U>,
+        DivUnit<Gram, Mole>: FromUnit<MM>,
+        C::AliasedUnit: FromUnit<DivUnit<Mole, Liter>>,
+    {
+        let density = self.into_unit::<GPerL>().value();
+        Measurement::<DivUnit<Mole, Liter>>::new(
+            density / molar_mass.into_unit::<GPerMol>().value(),
+        )
+        .into_unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GPerMol, MgPerDl, Millimole, MmolPerL, Mole, UmolPerL};
+    use crate::{
+        units::{Gram, Milligram},
+        Measurement,
+    };
+
+    fn glucose_molar_mass() -> Measurement<GPerMol> {
+        Measurement::new(180.156)
+    }
+
+    #[test]
+    fn test_molar_mass() {
+        let mass: Measurement<Gram> = Measurement::<Mole>::new(0.5).to_mass(glucose_molar_mass());
+        assert_eq!(90.078, mass.value());
+
+        let amount: Measurement<Millimole> =
+            Measurement::<Milligram>::new(180.156).to_amount(glucose_molar_mass());
+        assert!((amount.value() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_concentrations() {
+        let glucose = Measurement::<MmolPerL>::new(5.5);
+        assert!((glucose.into_unit::<UmolPerL>().value() - 5500.0).abs() < 1e-9);
+
+        let glucose: Measurement<MgPerDl> = glucose.to_mass_concentration(glucose_molar_mass());
+        assert!((glucose.value() - 99.0858).abs() < 1e-9);
+
+        let glucose: Measurement<MmolPerL> = glucose.to_molar_concentration(glucose_molar_mass());
+        assert!((glucose.value() - 5.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,92 @@
+//! Counts of clock cycles, and clock rates defined as aliases of
+//! cycles over time, so that a clock rate multiplied by a duration
+//! gives the cycles elapsed.
+
+use super::time::Second;
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::Cycles, dyn_units,
+    dynamic::DynUnit, DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+// Cycle counts
+define_unit!(Cycle, "cyc", name: "cycle", dimension: Cycles);
+define_unit!(Kilocycle, "kcyc", name: "kilocycle", dimension: Cycles);
+define_unit!(Megacycle, "Mcyc", name: "megacycle", dimension: Cycles);
+define_unit!(Gigacycle, "Gcyc", name: "gigacycle", dimension: Cycles);
+
+define_linear_conversions! {
+    quantity: "cycles";
+
+    (Cycle    , 1.0),
+    (Kilocycle, 1_000.0),
+    (Megacycle, 1_000_000.0),
+    (Gigacycle, 1_000_000_000.0)
+}
+
+// Clock rates
+define_alias!(DivUnit<Cycle    , Second> as Hertz    , "Hz");
+define_alias!(DivUnit<Kilocycle, Second> as Kilohertz, "kHz");
+define_alias!(DivUnit<Megacycle, Second> as Megahertz, "MHz");
+define_alias!(DivUnit<Gigacycle, Second> as Gigahertz, "GHz");
+
+/// All the units of cycle counts, sorted from smallest to largest.
+pub const CYCLE_UNITS: &[&dyn DynUnit] = dyn_units![Cycle, Kilocycle, Megacycle, Gigacycle];
+
+/// All the units of clock rates, sorted from smallest to largest.
+pub const CLOCK_RATE_UNITS: &[&dyn DynUnit] = dyn_units![Hertz, Kilohertz, Megahertz, Gigahertz];
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Converts this count of cycles into the time they take at the
+    /// given clock rate.
+    pub fn duration_at<F: MeasureUnit>(self, clock_rate: Measurement<F>) -> Measurement<Second>
+    where
+        Cycle: FromUnit<U>,
+        DivUnit<Cycle, Second>: FromUnit<F>,
+    {
+        let cycles = self.into_unit::<Cycle>().value();
+        Measurement::new(cycles / clock_rate.into_unit::<Hertz>().value())
+    }
+
+    /// Converts this duration into the cycles that elapse during it at
+    /// the given clock rate.
+    pub fn cycles_at<F: MeasureUnit>(self, clock_rate: Measurement<F>) -> Measurement<Cycle>
+    where
+        Second: FromUnit<U>,
+        DivUnit<Cycle, Second>: FromUnit<F>,
+    {
+        clock_rate.into_unit::<Hertz>() * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cycle, Gigahertz, Megacycle, Megahertz};
+    use crate::{
+        units::{Microsecond, Millisecond},
+        Measurement,
+    };
+
+    #[test]
+    fn test_clock_rates() {
+        let clock = Measurement::<Gigahertz>::new(3.2);
+        assert_eq!(3200.0, clock.into_unit::<Megahertz>().value());
+
+        let cycles: Measurement<Megacycle> =
+            (clock * Measurement::<Millisecond>::new(1.0)).into_unit();
+        assert_eq!(3.2, cycles.value());
+    }
+
+    #[test]
+    fn test_cycles_and_time() {
+        let clock = Measurement::<Megahertz>::new(16.0);
+
+        let duration = Measurement::<Cycle>::new(400.0).duration_at(clock);
+        assert_eq!(25.0, duration.into_unit::<Microsecond>().value());
+        assert_eq!(
+            160.0,
+            Measurement::<Microsecond>::new(10.0)
+                .cycles_at(clock)
+                .value()
+        );
+    }
+}
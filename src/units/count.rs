@@ -0,0 +1,57 @@
+//! Counts of discrete operations or requests, and the rates at which
+//! they are performed, e.g the throughput of a benchmarked function or
+//! the load of a service.
+
+use super::time::{Minute, Second};
+use crate::{
+    define_alias, define_linear_conversions, define_unit,
+    dimension::{Operations, Requests},
+    dyn_units,
+    dynamic::DynUnit,
+    DivUnit,
+};
+
+// Operation counts
+define_unit!(Operation, "op", name: "operation", dimension: Operations);
+define_unit!(Kilooperation, "kop", name: "kilooperation", dimension: Operations);
+define_unit!(Megaoperation, "Mop", name: "megaoperation", dimension: Operations);
+
+define_linear_conversions! {
+    quantity: "operations";
+
+    (Operation    , 1.0),
+    (Kilooperation, 1_000.0),
+    (Megaoperation, 1_000_000.0)
+}
+
+// Operation rates
+define_alias!(DivUnit<Operation    , Second> as OpsPerSecond , "op/s");
+define_alias!(DivUnit<Kilooperation, Second> as KopsPerSecond, "kop/s");
+define_alias!(DivUnit<Megaoperation, Second> as MopsPerSecond, "Mop/s");
+
+// Request counts
+define_unit!(Request, "req", name: "request", dimension: Requests);
+
+define_linear_conversions! {
+    quantity: "requests";
+
+    (Request, 1.0)
+}
+
+// Request rates
+define_alias!(DivUnit<Request, Second> as RequestsPerSecond, "req/s");
+define_alias!(DivUnit<Request, Minute> as RequestsPerMinute, "req/min");
+
+/// All the units of operation counts, sorted from smallest to largest.
+pub const OPERATION_UNITS: &[&dyn DynUnit] = dyn_units![Operation, Kilooperation, Megaoperation];
+
+/// All the units of operation throughput, sorted from smallest to
+/// largest.
+pub const OPERATION_THROUGHPUT_UNITS: &[&dyn DynUnit] =
+    dyn_units![OpsPerSecond, KopsPerSecond, MopsPerSecond];
+
+/// All the units of request counts.
+pub const REQUEST_UNITS: &[&dyn DynUnit] = dyn_units![Request];
+
+/// All the units of request rates, sorted from smallest to largest.
+pub const REQUEST_RATE_UNITS: &[&dyn DynUnit] = dyn_units![RequestsPerMinute, RequestsPerSecond];
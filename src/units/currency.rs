@@ -0,0 +1,239 @@
+//! Units of money. Exchange rates change constantly, so unlike other
+//! quantities currencies don't convert between them through
+//! compile-time factors, but through an [`ExchangeRateProvider`]
+//! supplied at runtime.
+
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use crate::{
+    define_linear_conversions, define_unit, dimension::Money, DivUnit, MeasureUnit, Measurement,
+};
+
+/// Unit of a currency, identified by its ISO 4217 code.
+pub trait CurrencyUnit: MeasureUnit {
+    const CODE: &'static str;
+}
+
+/// Unit of a monetary quantity, either an amount of a currency or a
+/// price per unit of something else, like EUR/GB.
+pub trait MonetaryUnit: MeasureUnit {
+    /// The currency the quantity is expressed in.
+    type Currency: CurrencyUnit;
+
+    /// The same unit, but expressed in the currency C.
+    type Exchanged<C: CurrencyUnit>: MeasureUnit;
+}
+
+impl<C: CurrencyUnit, D: MeasureUnit> MonetaryUnit for DivUnit<C, D> {
+    type Currency = C;
+    type Exchanged<E: CurrencyUnit> = DivUnit<E, D>;
+}
+
+macro_rules! define_currencies {
+    ($($id:ident, $code:literal, $name:literal $(, $plural:literal)?);* $(;)?) => {
+        $(
+            define_unit!($id, $code, name: $name $(, plural: $plural)?, dimension: Money);
+            // Only the identity conversion, so prices can still be
+            // converted between units of their denominator.
+            define_linear_conversions!(($id, 1));
+
+            impl CurrencyUnit for $id {
+                const CODE: &'static str = $code;
+            }
+
+            impl MonetaryUnit for $id {
+                type Currency = $id;
+                type Exchanged<C: CurrencyUnit> = C;
+            }
+        )*
+    };
+}
+
+define_currencies! {
+    Eur, "EUR", "euro";
+    Usd, "USD", "US dollar";
+    Gbp, "GBP", "pound sterling", "pounds sterling";
+    Jpy, "JPY", "yen", "yen";
+    Chf, "CHF", "Swiss franc";
+    Cny, "CNY", "yuan", "yuan";
+}
+
+/// Source of the exchange rates between currencies.
+pub trait ExchangeRateProvider {
+    /// Returns how many units of the currency `to` are worth one unit
+    /// of the currency `from`, given their ISO 4217 codes, or None if
+    /// the rate is not known.
+    fn rate(&self, from: &str, to: &str) -> Option<f64>;
+}
+
+/// Error returned when a monetary quantity cannot be exchanged into
+/// another currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExchangeError {
+    /// The provider doesn't know the rate between both currencies.
+    MissingRate { from: String, to: String },
+    /// The provider returned a rate that is not a positive number.
+    InvalidRate { from: String, to: String },
+}
+
+impl Display for ExchangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExchangeError::MissingRate { from, to } => {
+                write!(f, "missing exchange rate from {} to {}", from, to)
+            }
+            ExchangeError::InvalidRate { from, to } => {
+                write!(f, "invalid exchange rate from {} to {}", from, to)
+            }
+        }
+    }
+}
+
+impl Error for ExchangeError {}
+
+/// Provider of a fixed set of exchange rates. The inverse of every
+/// rate is also known, unless set explicitly.
+#[derive(Debug, Clone, Default)]
+pub struct FixedRates {
+    /// Rates between each pair of currencies, along with whether they
+    /// were set explicitly or derived from the inverse one.
+    rates: HashMap<(String, String), (f64, bool)>,
+}
+
+impl FixedRates {
+    /// Creates a provider without any known rate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many units of the currency T are worth one unit of
+    /// the currency F.
+    pub fn set<F: CurrencyUnit, T: CurrencyUnit>(&mut self, rate: f64) -> &mut Self {
+        self.rates
+            .insert((F::CODE.to_owned(), T::CODE.to_owned()), (rate, true));
+
+        let inverse = self
+            .rates
+            .entry((T::CODE.to_owned(), F::CODE.to_owned()))
+            .or_insert((0.0, false));
+        if !inverse.1 {
+            *inverse = (1.0 / rate, false);
+        }
+        self
+    }
+
+    /// Same as [`FixedRates::set`], but taking and returning the
+    /// provider by value.
+    pub fn with<F: CurrencyUnit, T: CurrencyUnit>(mut self, rate: f64) -> Self {
+        self.set::<F, T>(rate);
+        self
+    }
+}
+
+impl ExchangeRateProvider for FixedRates {
+    fn rate(&self, from: &str, to: &str) -> Option<f64> {
+        self.rates
+            .get(&(from.to_owned(), to.to_owned()))
+            .map(|(rate, _)| *rate)
+    }
+}
+
+impl<U: MonetaryUnit> Measurement<U> {
+    /// Exchanges this quantity into the currency C, using the rates of
+    /// the given provider. The provider is not queried when both
+    /// currencies are the same.
+    pub fn exchange<C, P>(self, provider: &P) -> Result<Measurement<U::Exchanged<C>>, ExchangeError>
+    where
+        C: CurrencyUnit,
+        P: ExchangeRateProvider + ?Sized,
+    {
+        let (from, to) = (<U::Currency as CurrencyUnit>::CODE, C::CODE);
+        if from == to {
+            return Ok(Measurement::new(self.value()));
+        }
+
+        match provider.rate(from, to) {
+            Some(rate) if rate.is_finite() && rate > 0.0 => {
+                Ok(Measurement::new(self.value() * rate))
+            }
+            Some(_) => Err(ExchangeError::InvalidRate {
+                from: from.to_owned(),
+                to: to.to_owned(),
+            }),
+            None => Err(ExchangeError::MissingRate {
+                from: from.to_owned(),
+                to: to.to_owned(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Eur, ExchangeError, ExchangeRateProvider, FixedRates, Gbp, Jpy, Usd};
+    use crate::Measurement;
+
+    fn rates() -> FixedRates {
+        FixedRates::new()
+            .with::<Eur, Usd>(1.25)
+            .with::<Usd, Jpy>(150.0)
+    }
+
+    #[test]
+    fn test_exchange() {
+        let price = Measurement::<Eur>::new(10.0);
+
+        assert_eq!(12.5, price.exchange::<Usd, _>(&rates()).unwrap().value());
+        assert_eq!(10.0, price.exchange::<Eur, _>(&rates()).unwrap().value());
+        assert_eq!(
+            8.0,
+            Measurement::<Usd>::new(10.0)
+                .exchange::<Eur, _>(&rates())
+                .unwrap()
+                .value()
+        );
+    }
+
+    #[test]
+    fn test_update_rate() {
+        let mut rates = rates();
+        rates.set::<Eur, Usd>(1.6);
+        assert_eq!(Some(0.625), rates.rate("USD", "EUR"));
+
+        rates.set::<Usd, Eur>(0.5).set::<Eur, Usd>(2.5);
+        assert_eq!(Some(2.5), rates.rate("EUR", "USD"));
+        assert_eq!(Some(0.5), rates.rate("USD", "EUR"));
+    }
+
+    #[test]
+    fn test_missing_rate() {
+        let error = Measurement::<Eur>::new(1.0)
+            .exchange::<Gbp, _>(&rates())
+            .unwrap_err();
+
+        assert_eq!(
+            ExchangeError::MissingRate {
+                from: "EUR".to_owned(),
+                to: "GBP".to_owned()
+            },
+            error
+        );
+        assert_eq!("missing exchange rate from EUR to GBP", error.to_string());
+    }
+
+    #[cfg(feature = "data")]
+    #[test]
+    fn test_price_per_unit() {
+        use crate::{
+            units::{Gigabyte, Terabyte},
+            DivUnit,
+        };
+
+        let price = Measurement::<DivUnit<Usd, Gigabyte>>::new(0.02);
+        let price: Measurement<DivUnit<Eur, Gigabyte>> = price.exchange(&rates()).unwrap();
+        assert_eq!("0.016 EUR/GB", price.to_string());
+
+        let price: Measurement<DivUnit<Eur, Terabyte>> = price.into_unit();
+        assert_eq!(16.0, price.value());
+    }
+}
@@ -0,0 +1,351 @@
+//! Units of digital information, both decimal and binary prefixed.
+
+use std::borrow::Cow;
+
+use crate::{
+    define_linear_conversions, define_unit,
+    dimension::{Data, SameDimension},
+    dyn_units,
+    dynamic::DynUnit,
+    type_logic, FromUnit, FromUnitLinear, LinearUnit, MeasureUnit, Measurement,
+};
+
+// Bits
+define_unit!(Bit, "b", name: "bit", dimension: Data);
+define_unit!(Kilobit, "Kb", name: "kilobit", dimension: Data);
+define_unit!(Megabit, "Mb", name: "megabit", dimension: Data);
+define_unit!(Gigabit, "Gb", name: "gigabit", dimension: Data);
+define_unit!(Terabit, "Tb", name: "terabit", dimension: Data);
+define_unit!(Petabit, "Pb", name: "petabit", dimension: Data);
+define_unit!(Exabit, "Eb", name: "exabit", dimension: Data);
+define_unit!(Zettabit, "Zb", name: "zettabit", dimension: Data);
+define_unit!(Yottabit, "Yb", name: "yottabit", dimension: Data);
+
+// Bytes
+define_unit!(Byte, "B", name: "byte", dimension: Data);
+define_unit!(Kilobyte, "KB", name: "kilobyte", dimension: Data);
+define_unit!(Megabyte, "MB", name: "megabyte", dimension: Data);
+define_unit!(Gigabyte, "GB", name: "gigabyte", dimension: Data);
+define_unit!(Terabyte, "TB", name: "terabyte", dimension: Data);
+define_unit!(Petabyte, "PB", name: "petabyte", dimension: Data);
+define_unit!(Exabyte, "EB", name: "exabyte", dimension: Data);
+define_unit!(Zettabyte, "ZB", name: "zettabyte", dimension: Data);
+define_unit!(Yottabyte, "YB", name: "yottabyte", dimension: Data);
+
+// Bytes (power of 2)
+define_unit!(Kibibyte, "KiB", name: "kibibyte", dimension: Data);
+define_unit!(Mebibyte, "MiB", name: "mebibyte", dimension: Data);
+define_unit!(Gibibyte, "GiB", name: "gibibyte", dimension: Data);
+define_unit!(Tebibyte, "TiB", name: "tebibyte", dimension: Data);
+define_unit!(Pebibyte, "PiB", name: "pebibyte", dimension: Data);
+define_unit!(Exbibyte, "EiB", name: "exbibyte", dimension: Data);
+define_unit!(Zebibyte, "ZiB", name: "zebibyte", dimension: Data);
+define_unit!(Yobibyte, "YiB", name: "yobibyte", dimension: Data);
+
+// Storage
+define_unit!(Sector, "sector", name: "sector", dimension: Data);
+define_unit!(Page, "page", name: "page", dimension: Data);
+
+define_linear_conversions! {
+    quantity: "data";
+
+    // Bits
+    (Bit     , 1.0),
+    (Kilobit , 1_000.0),
+    (Megabit , 1_000_000.0),
+    (Gigabit , 1_000_000_000.0),
+    (Terabit , 1_000_000_000_000.0),
+    (Petabit , 1_000_000_000_000_000.0),
+    (Exabit  , 1_000_000_000_000_000_000.0),
+    (Zettabit, 1_000_000_000_000_000_000_000.0),
+    (Yottabit, 1_000_000_000_000_000_000_000_000.0),
+
+    // Bytes
+    (Byte     , 8.0),
+    (Kilobyte , 8_000.0),
+    (Megabyte , 8_000_000.0),
+    (Gigabyte , 8_000_000_000.0),
+    (Terabyte , 8_000_000_000_000.0),
+    (Petabyte , 8_000_000_000_000_000.0),
+    (Exabyte  , 8_000_000_000_000_000_000.0),
+    (Zettabyte, 8_000_000_000_000_000_000_000.0),
+    (Yottabyte, 8_000_000_000_000_000_000_000_000.0),
+
+    // Bytes (power of 2)
+    (Kibibyte, 8_192.0),
+    (Mebibyte, 8_388_608.0),
+    (Gibibyte, 8_589_934_592.0),
+    (Tebibyte, 8_796_093_022_208.0),
+    (Pebibyte, 9_007_199_254_740_992.0),
+    (Exbibyte, 9_223_372_036_854_775_808.0),
+    (Zebibyte, 9_444_732_965_739_290_427_392.0),
+    (Yobibyte, 9_671_406_556_917_033_397_649_408.0),
+
+    // Storage
+    (Sector, 4_096.0),
+    (Page  , 32_768.0)
+}
+
+/// All the units of bits, sorted from smallest to largest.
+pub const BIT_UNITS: &[&dyn DynUnit] =
+    dyn_units![Bit, Kilobit, Megabit, Gigabit, Terabit, Petabit, Exabit, Zettabit, Yottabit];
+
+/// All the decimal-prefixed units of bytes, sorted from smallest to largest.
+pub const BYTE_UNITS: &[&dyn DynUnit] = dyn_units![
+    Byte, Kilobyte, Megabyte, Gigabyte, Terabyte, Petabyte, Exabyte, Zettabyte, Yottabyte,
+];
+
+/// All the binary-prefixed units of bytes, sorted from smallest to largest.
+pub const BINARY_BYTE_UNITS: &[&dyn DynUnit] = dyn_units![
+    Byte, Kibibyte, Mebibyte, Gibibyte, Tebibyte, Pebibyte, Exbibyte, Zebibyte, Yobibyte,
+];
+
+/// All the units of storage blocks with a fixed size, sorted from
+/// smallest to largest.
+pub const STORAGE_BLOCK_UNITS: &[&dyn DynUnit] = dyn_units![Sector, Page];
+
+/// Block of SIZE bytes, for devices and filesystems whose block size
+/// is not a sector or a page, e.g `Block<8192>`. Blocks convert into
+/// any unit of data, but only bytes convert into blocks.
+pub struct Block<const SIZE: u64>;
+
+impl<const SIZE: u64> Block<SIZE> {
+    const SYMBOL: &'static Label = &Label::new("block(", SIZE, ")");
+    const NAME: &'static Label = &Label::new("", SIZE, "-byte block");
+    const PLURAL_NAME: &'static Label = &Label::new("", SIZE, "-byte blocks");
+}
+
+/// Text made of a number between a prefix and a suffix, built at
+/// compile time, so the symbol and the names of each block size don't
+/// have to be formatted every time they are displayed.
+struct Label {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl Label {
+    const fn new(prefix: &str, number: u64, suffix: &str) -> Self {
+        let mut digits = [0; 20];
+        let mut start = digits.len();
+        let mut rest = number;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+
+        let label = Self {
+            bytes: [0; 32],
+            len: 0,
+        };
+        label
+            .push(prefix.as_bytes())
+            .push(digits.split_at(start).1)
+            .push(suffix.as_bytes())
+    }
+
+    const fn push(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+
+        self
+    }
+
+    fn as_str(&'static self) -> Cow<'static, str> {
+        // Only built from strings and ASCII digits.
+        Cow::Borrowed(std::str::from_utf8(&self.bytes[..self.len]).unwrap())
+    }
+}
+
+impl<const SIZE: u64> MeasureUnit for Block<SIZE> {
+    type AliasedUnit = Self;
+    type Kind = type_logic::Plain;
+    type Dimension = Data;
+
+    fn symbol() -> Cow<'static, str> {
+        Self::SYMBOL.as_str()
+    }
+
+    fn name() -> Cow<'static, str> {
+        Self::NAME.as_str()
+    }
+
+    fn plural_name() -> Cow<'static, str> {
+        Self::PLURAL_NAME.as_str()
+    }
+}
+
+impl<const SIZE: u64> LinearUnit for Block<SIZE> {
+    const FACTOR_TO_BASE: f64 = SIZE as f64 * 8.0;
+
+    fn quantity() -> Cow<'static, str> {
+        "data".into()
+    }
+}
+
+impl<T, const SIZE: u64> FromUnit<Block<SIZE>> for T
+where
+    T: FromUnit<Byte> + SameDimension<Block<SIZE>>,
+{
+    #[inline]
+    fn from_value(input: Measurement<Block<SIZE>>) -> Measurement<Self> {
+        T::from_value(Measurement::<Byte>::new(input.value() * SIZE as f64))
+    }
+}
+
+unsafe impl<T, const SIZE: u64> FromUnitLinear<Block<SIZE>> for T where
+    T: FromUnitLinear<Byte> + SameDimension<Block<SIZE>>
+{
+}
+
+impl<const SIZE: u64> FromUnit<Byte> for Block<SIZE> {
+    #[inline]
+    fn from_value(input: Measurement<Byte>) -> Measurement<Self> {
+        Measurement::new(input.value() / SIZE as f64)
+    }
+}
+
+unsafe impl<const SIZE: u64> FromUnitLinear<Byte> for Block<SIZE> {}
+
+/// Returns the given amount of data as a whole number of bytes, rounded
+/// through the given function. Negative and NaN amounts are zero.
+fn whole_bytes<U: MeasureUnit>(data: Measurement<U>, round: fn(f64) -> f64) -> u64
+where
+    Byte: FromUnit<U>,
+{
+    round(data.into_unit::<Byte>().value()) as u64
+}
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Returns the number of chunks of the given size needed to hold
+    /// this amount of data, e.g the number of pages a buffer spans.
+    /// Both are taken as whole numbers of bytes, so it is computed
+    /// exactly. Returns zero if the size is less than a byte.
+    pub fn count_of<A: MeasureUnit>(self, size: Measurement<A>) -> u64
+    where
+        Byte: FromUnit<U> + FromUnit<A>,
+    {
+        let size = whole_bytes(size, f64::round);
+        if size == 0 {
+            return 0;
+        }
+
+        whole_bytes(self, f64::ceil).div_ceil(size)
+    }
+
+    /// Rounds this amount of data up to the closest multiple of the
+    /// given alignment. Both are taken as whole numbers of bytes, so it
+    /// is computed exactly. Returns it unchanged if the alignment is
+    /// less than a byte.
+    pub fn align_up<A: MeasureUnit>(self, alignment: Measurement<A>) -> Self
+    where
+        Byte: FromUnit<U> + FromUnit<A>,
+        U::AliasedUnit: FromUnit<Byte>,
+    {
+        self.align(alignment, |bytes, alignment| {
+            bytes.div_ceil(alignment).saturating_mul(alignment)
+        })
+    }
+
+    /// Rounds this amount of data down to the closest multiple of the
+    /// given alignment. Both are taken as whole numbers of bytes, so it
+    /// is computed exactly. Returns it unchanged if the alignment is
+    /// less than a byte.
+    pub fn align_down<A: MeasureUnit>(self, alignment: Measurement<A>) -> Self
+    where
+        Byte: FromUnit<U> + FromUnit<A>,
+        U::AliasedUnit: FromUnit<Byte>,
+    {
+        self.align(alignment, |bytes, alignment| bytes - bytes % alignment)
+    }
+
+    fn align<A: MeasureUnit>(self, alignment: Measurement<A>, round: fn(u64, u64) -> u64) -> Self
+    where
+        Byte: FromUnit<U> + FromUnit<A>,
+        U::AliasedUnit: FromUnit<Byte>,
+    {
+        let alignment = whole_bytes(alignment, f64::round);
+        if alignment == 0 {
+            return self;
+        }
+
+        let bytes = whole_bytes(self, f64::round);
+        Measurement::<Byte>::new(round(bytes, alignment) as f64).into_unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{Block, Byte, Kibibyte, Page, Sector};
+    use crate::{units::find_unit, MeasureUnit, Measurement};
+
+    #[test]
+    fn test_storage_units() {
+        assert_eq!(
+            8.0,
+            Measurement::<Page>::new(1.0).into_unit::<Sector>().value()
+        );
+        assert_eq!(
+            2.0,
+            Measurement::<Block<8192>>::new(1.0)
+                .into_unit::<Page>()
+                .value()
+        );
+        assert_eq!(
+            3.0,
+            Measurement::<Byte>::new(3.0 * 65536.0)
+                .into_unit::<Block<65536>>()
+                .value()
+        );
+    }
+
+    #[test]
+    fn test_storage_symbols() {
+        assert_eq!(
+            "2 block(8192)",
+            Measurement::<Block<8192>>::new(2.0).to_string()
+        );
+        assert_eq!("8192-byte blocks", Block::<8192>::plural_name());
+        assert_eq!(
+            "block(18446744073709551615)",
+            Block::<{ u64::MAX }>::symbol()
+        );
+        assert!(matches!(
+            Block::<512>::name(),
+            Cow::Borrowed("512-byte block")
+        ));
+        assert_eq!("sector", find_unit("sector").unwrap().symbol());
+        assert_eq!(32_768.0, find_unit("page").unwrap().value_to_base(1.0));
+    }
+
+    #[test]
+    fn test_alignment() {
+        let size = Measurement::<Byte>::new(5000.0);
+        let page = Measurement::<Page>::new(1.0);
+
+        assert_eq!(8192.0, size.align_up(page).value());
+        assert_eq!(4096.0, size.align_down(page).value());
+        assert_eq!(2, size.count_of(page));
+        assert_eq!(10, size.count_of(Measurement::<Sector>::new(1.0)));
+        assert_eq!(1, size.count_of(Measurement::<Block<8192>>::new(1.0)));
+
+        let size = Measurement::<Kibibyte>::new(12.0);
+        assert_eq!(12.0, size.align_up(page).value());
+        assert_eq!(size, size.align_down(Measurement::<Byte>::new(0.0)));
+
+        // Large sizes, in blocks of 1 TiB.
+        let size = Measurement::<Byte>::new(2f64.powi(60) + 4096.0);
+        let block = Measurement::<Block<{ 1 << 40 }>>::new(1.0);
+        assert_eq!(2f64.powi(60), size.align_down(block).value());
+        assert_eq!((1 << 20) + 1, size.count_of(block));
+    }
+}
@@ -0,0 +1,52 @@
+//! Volumetric flow rates, defined as aliases of volume over time.
+//! Multiplying a flow rate by a duration gives the volume that flowed.
+
+use super::{
+    time::{Hour, Minute, Second},
+    volume::{CubicMeter, Liter, UsGallon},
+};
+use crate::{define_alias, dyn_units, dynamic::DynUnit, DivUnit};
+
+define_alias!(DivUnit<Liter     , Minute> as LitersPerMinute     , "L/min");
+define_alias!(DivUnit<UsGallon  , Minute> as GallonsPerMinute    , "gal/min");
+define_alias!(DivUnit<Liter     , Second> as LitersPerSecond     , "L/s");
+define_alias!(DivUnit<CubicMeter, Hour  > as CubicMetersPerHour  , "m³/h");
+define_alias!(DivUnit<CubicMeter, Second> as CubicMetersPerSecond, "m³/s");
+
+/// All the units of volumetric flow rate, sorted from smallest to largest.
+pub const FLOW_RATE_UNITS: &[&dyn DynUnit] = dyn_units![
+    LitersPerMinute,
+    GallonsPerMinute,
+    LitersPerSecond,
+    CubicMetersPerHour,
+    CubicMetersPerSecond,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{CubicMetersPerHour, GallonsPerMinute, LitersPerMinute, LitersPerSecond};
+    use crate::{
+        units::{Hour, Liter, Minute, UsGallon},
+        Measurement,
+    };
+
+    #[test]
+    fn test_conversions() {
+        let flow = Measurement::<LitersPerSecond>::new(2.5);
+
+        assert_eq!(150.0, flow.into_unit::<LitersPerMinute>().value());
+        assert_eq!(9.0, flow.into_unit::<CubicMetersPerHour>().value());
+        assert!((flow.into_unit::<GallonsPerMinute>().value() - 39.626).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_volume_over_time() {
+        let flow = Measurement::<LitersPerMinute>::new(12.0);
+        let volume: Measurement<Liter> = flow * Measurement::<Hour>::new(0.5);
+        assert_eq!(360.0, volume.value());
+
+        let volume: Measurement<UsGallon> =
+            Measurement::<GallonsPerMinute>::new(5.0) * Measurement::<Minute>::new(3.0);
+        assert_eq!(15.0, volume.value());
+    }
+}
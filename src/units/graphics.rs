@@ -0,0 +1,150 @@
+//! Units of raster graphics: counts of pixels, pixel densities and the
+//! rates at which pixels are processed.
+
+use super::{length::Inch, time::Second};
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::Pixels, dyn_units,
+    dynamic::DynUnit, DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+// Pixel counts
+define_unit!(Pixel, "px", name: "pixel", dimension: Pixels);
+define_unit!(Kilopixel, "kpx", name: "kilopixel", dimension: Pixels);
+define_unit!(Megapixel, "MP", name: "megapixel", dimension: Pixels);
+define_unit!(Gigapixel, "GP", name: "gigapixel", dimension: Pixels);
+
+define_linear_conversions! {
+    quantity: "pixels";
+
+    (Pixel    , 1.0),
+    (Kilopixel, 1_000.0),
+    (Megapixel, 1_000_000.0),
+    (Gigapixel, 1_000_000_000.0)
+}
+
+// Pixel densities
+define_alias!(DivUnit<Pixel, Inch> as Ppi, "ppi");
+define_alias!(DivUnit<Pixel, Inch> as Dpi, "dpi");
+
+// Pixel rates
+define_alias!(DivUnit<Pixel    , Second> as PixelsPerSecond    , "px/s");
+define_alias!(DivUnit<Megapixel, Second> as MegapixelsPerSecond, "MP/s");
+define_alias!(DivUnit<Gigapixel, Second> as GigapixelsPerSecond, "GP/s");
+
+/// All the units of pixel counts, sorted from smallest to largest.
+pub const PIXEL_UNITS: &[&dyn DynUnit] = dyn_units![Pixel, Kilopixel, Megapixel, Gigapixel];
+
+/// All the units of pixel rates, sorted from smallest to largest.
+pub const PIXEL_RATE_UNITS: &[&dyn DynUnit] =
+    dyn_units![PixelsPerSecond, MegapixelsPerSecond, GigapixelsPerSecond];
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Converts this count of pixels into the length they span when
+    /// laid out in a line at the given pixel density.
+    pub fn to_length<L: MeasureUnit, D: MeasureUnit>(
+        self,
+        density: Measurement<D>,
+    ) -> Measurement<L>
+    where
+        Pixel: FromUnit<U>,
+        DivUnit<Pixel, Inch>: FromUnit<D>,
+        L::AliasedUnit: FromUnit<Inch>,
+    {
+        let pixels = self.into_unit::<Pixel>().value();
+        Measurement::<Inch>::new(pixels / density.into_unit::<Ppi>().value()).into_unit()
+    }
+
+    /// Converts this length into the count of pixels that span it at
+    /// the given pixel density.
+    pub fn to_pixels<D: MeasureUnit>(self, density: Measurement<D>) -> Measurement<Pixel>
+    where
+        Inch: FromUnit<U>,
+        DivUnit<Pixel, Inch>: FromUnit<D>,
+    {
+        let inches = self.into_unit::<Inch>().value();
+        Measurement::new(inches * density.into_unit::<Ppi>().value())
+    }
+}
+
+/// Dimensions of a raster image, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimensions {
+    width: u32,
+    height: u32,
+}
+
+impl Dimensions {
+    /// Creates the dimensions of an image of the given width and height.
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Returns the width of the image.
+    pub fn width(&self) -> Measurement<Pixel> {
+        Measurement::new(self.width as f64)
+    }
+
+    /// Returns the height of the image.
+    pub fn height(&self) -> Measurement<Pixel> {
+        Measurement::new(self.height as f64)
+    }
+
+    /// Returns the total number of pixels of the image.
+    pub fn pixels(&self) -> Measurement<Pixel> {
+        Measurement::new(self.width as f64 * self.height as f64)
+    }
+
+    /// Returns the physical width and height of the image when printed
+    /// or displayed at the given pixel density.
+    pub fn size_at<L: MeasureUnit, D: MeasureUnit>(
+        &self,
+        density: Measurement<D>,
+    ) -> (Measurement<L>, Measurement<L>)
+    where
+        DivUnit<Pixel, Inch>: FromUnit<D>,
+        L::AliasedUnit: FromUnit<Inch>,
+    {
+        (
+            self.width().to_length(density),
+            self.height().to_length(density),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dimensions, Dpi, Megapixel, MegapixelsPerSecond, Pixel, PixelsPerSecond};
+    use crate::{
+        units::{Centimeter, Inch},
+        Measurement,
+    };
+
+    #[test]
+    fn test_densities() {
+        let dpi = Measurement::<Dpi>::new(300.0);
+
+        let width: Measurement<Inch> = Measurement::<Pixel>::new(2550.0).to_length(dpi);
+        assert_eq!(8.5, width.value());
+        assert_eq!(
+            600.0,
+            Measurement::<Centimeter>::new(5.08).to_pixels(dpi).value()
+        );
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let full_hd = Dimensions::new(1920, 1080);
+        assert_eq!(2.0736, full_hd.pixels().into_unit::<Megapixel>().value());
+
+        let (width, height) = full_hd.size_at::<Inch, _>(Measurement::<Dpi>::new(96.0));
+        assert_eq!((20.0, 11.25), (width.value(), height.value()));
+
+        // 60 frames per second.
+        let throughput =
+            Measurement::<MegapixelsPerSecond>::new(full_hd.pixels().value() * 60.0 / 1e6);
+        assert_eq!(
+            124_416_000.0,
+            throughput.into_unit::<PixelsPerSecond>().value()
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Imperial and US customary units of length, mass and volume. They
+//! measure the same quantities as the metric units, and convert from
+//! and into them. Units of volume differ between both systems, so they
+//! are defined separately for each one.
+
+use super::{length::Meter, mass::Gram, volume::Liter};
+use crate::{
+    define_linear_conversions, define_unit,
+    dimension::{Length, Mass, Volume},
+    dyn_units,
+    dynamic::DynUnit,
+};
+
+// Length
+define_unit!(Foot, "ft", name: "foot", plural: "feet", dimension: Length);
+define_unit!(Yard, "yd", name: "yard", dimension: Length);
+define_unit!(Mile, "mi", name: "mile", dimension: Length);
+
+// Mass
+define_unit!(Ounce, "oz", name: "ounce", dimension: Mass);
+define_unit!(Pound, "lb", name: "pound", dimension: Mass);
+define_unit!(Stone, "st", name: "stone", plural: "stone", dimension: Mass);
+
+// Volume
+define_unit!(ImperialFluidOunce, "imp fl oz", name: "imperial fluid ounce", dimension: Volume);
+define_unit!(UsFluidOunce, "US fl oz", name: "US fluid ounce", dimension: Volume);
+define_unit!(UsPint, "US pt", name: "US pint", dimension: Volume);
+define_unit!(ImperialPint, "imp pt", name: "imperial pint", dimension: Volume);
+define_unit!(ImperialGallon, "imp gal", name: "imperial gallon", dimension: Volume);
+
+define_linear_conversions! {
+    quantity: "length";
+    extends: Meter;
+
+    (Foot, 0.304_8),
+    (Yard, 0.914_4),
+    (Mile, 1_609.344)
+}
+
+define_linear_conversions! {
+    quantity: "mass";
+    extends: Gram;
+
+    (Ounce, 28.349_523_125),
+    (Pound, 453.592_37),
+    (Stone, 6_350.293_18)
+}
+
+define_linear_conversions! {
+    quantity: "volume";
+    extends: Liter;
+
+    (ImperialFluidOunce, 0.028_413_062_5),
+    (UsFluidOunce      , 0.029_573_529_562_5),
+    (UsPint            , 0.473_176_473),
+    (ImperialPint      , 0.568_261_25),
+    (ImperialGallon    , 4.546_09)
+}
+
+/// All the imperial units of length, sorted from smallest to largest.
+pub const IMPERIAL_LENGTH_UNITS: &[&dyn DynUnit] = dyn_units![Foot, Yard, Mile];
+
+/// All the imperial units of mass, sorted from smallest to largest.
+pub const IMPERIAL_MASS_UNITS: &[&dyn DynUnit] = dyn_units![Ounce, Pound, Stone];
+
+/// All the imperial and US customary units of volume, sorted from
+/// smallest to largest.
+pub const IMPERIAL_VOLUME_UNITS: &[&dyn DynUnit] = dyn_units![
+    ImperialFluidOunce,
+    UsFluidOunce,
+    UsPint,
+    ImperialPint,
+    ImperialGallon,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Foot, ImperialFluidOunce, ImperialGallon, ImperialPint, Mile, Ounce, Pound, Stone,
+        UsFluidOunce, UsPint, Yard,
+    };
+    use crate::{
+        units::{Inch, Kilogram, Kilometer, Liter, UsGallon},
+        Measurement,
+    };
+
+    fn close(expected: f64, actual: f64) -> bool {
+        (expected - actual).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_length() {
+        let marathon = Measurement::<Kilometer>::new(42.195);
+
+        assert!((marathon.into_unit::<Mile>().value() - 26.219).abs() < 0.001);
+        assert!(close(
+            3.0,
+            Measurement::<Yard>::new(1.0).into_unit::<Foot>().value()
+        ));
+        assert!(close(
+            12.0,
+            Measurement::<Foot>::new(1.0).into_unit::<Inch>().value()
+        ));
+    }
+
+    #[test]
+    fn test_mass() {
+        assert!(close(
+            14.0,
+            Measurement::<Stone>::new(1.0).into_unit::<Pound>().value()
+        ));
+        assert!(close(
+            16.0,
+            Measurement::<Pound>::new(1.0).into_unit::<Ounce>().value()
+        ));
+        assert!(close(
+            0.453_592_37,
+            Measurement::<Pound>::new(1.0)
+                .into_unit::<Kilogram>()
+                .value()
+        ));
+    }
+
+    #[test]
+    fn test_us_and_imperial_volumes() {
+        let us_gallon = Measurement::<UsGallon>::new(1.0);
+        let imperial_gallon = Measurement::<ImperialGallon>::new(1.0);
+
+        assert!(close(128.0, us_gallon.into_unit::<UsFluidOunce>().value()));
+        assert!(close(
+            160.0,
+            imperial_gallon.into_unit::<ImperialFluidOunce>().value()
+        ));
+        assert!(close(
+            4.546_09,
+            imperial_gallon.into_unit::<Liter>().value()
+        ));
+        assert!(Measurement::<ImperialPint>::new(1.0) > Measurement::<UsPint>::new(1.0));
+    }
+
+    #[cfg(feature = "nautical")]
+    #[test]
+    fn test_nautical_lengths() {
+        use crate::units::{Fathom, NauticalMile};
+
+        assert!(close(
+            6.0,
+            Measurement::<Fathom>::new(1.0).into_unit::<Foot>().value()
+        ));
+        assert!(close(
+            1.150_779_448,
+            Measurement::<NauticalMile>::new(1.0)
+                .into_unit::<Mile>()
+                .value()
+        ));
+    }
+}
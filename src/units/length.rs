@@ -0,0 +1,56 @@
+//! Metric units of length, plus the inch, which most device and paper
+//! sizes are still given in.
+
+use crate::{
+    define_linear_conversions, define_unit, dimension::Length, dyn_units, dynamic::DynUnit,
+};
+
+define_unit!(Nanometer, "nm", name: "nanometer", dimension: Length);
+define_unit!(Micrometer, "µm", ascii: "um", name: "micrometer", dimension: Length);
+define_unit!(Millimeter, "mm", name: "millimeter", dimension: Length);
+define_unit!(Centimeter, "cm", name: "centimeter", dimension: Length);
+define_unit!(Inch, "in", name: "inch", plural: "inches", dimension: Length);
+define_unit!(Meter, "m", name: "meter", dimension: Length);
+define_unit!(Kilometer, "km", name: "kilometer", dimension: Length);
+
+define_linear_conversions! {
+    quantity: "length";
+
+    (Nanometer , 0.000_000_001),
+    (Micrometer, 0.000_001),
+    (Millimeter, 0.001),
+    (Centimeter, 0.01),
+    (Inch      , 0.0254),
+    (Meter     , 1.0),
+    (Kilometer , 1_000.0)
+}
+
+/// All the units of length, sorted from smallest to largest.
+pub const LENGTH_UNITS: &[&dyn DynUnit] =
+    dyn_units![Nanometer, Micrometer, Millimeter, Centimeter, Inch, Meter, Kilometer];
+
+#[cfg(test)]
+mod tests {
+    use super::{Centimeter, Inch, Kilometer, Meter, Micrometer, Millimeter};
+    use crate::{parse::parse_as, MeasureUnit, Measurement};
+
+    #[test]
+    fn test_lengths() {
+        assert_eq!(
+            25.4,
+            Measurement::<Inch>::new(1.0)
+                .into_unit::<Millimeter>()
+                .value()
+        );
+        assert_eq!(
+            Measurement::<Kilometer>::new(1.5),
+            Measurement::<Meter>::new(1500.0)
+        );
+        assert_eq!(
+            Measurement::<Centimeter>::new(0.5),
+            parse_as::<Centimeter>("5000 um").unwrap()
+        );
+        assert_eq!("inches", Inch::plural_name());
+        assert_eq!("um", Micrometer::ascii_symbol());
+    }
+}
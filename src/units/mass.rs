@@ -0,0 +1,48 @@
+//! Metric units of mass.
+
+use crate::{define_linear_conversions, define_unit, dimension::Mass, dyn_units, dynamic::DynUnit};
+
+define_unit!(Microgram, "µg", ascii: "ug", name: "microgram", dimension: Mass);
+define_unit!(Milligram, "mg", name: "milligram", dimension: Mass);
+define_unit!(Gram, "g", name: "gram", dimension: Mass);
+define_unit!(Kilogram, "kg", name: "kilogram", dimension: Mass);
+define_unit!(Tonne, "t", name: "tonne", dimension: Mass);
+
+define_linear_conversions! {
+    quantity: "mass";
+
+    (Microgram, 0.000_001),
+    (Milligram, 0.001),
+    (Gram     , 1.0),
+    (Kilogram , 1_000.0),
+    (Tonne    , 1_000_000.0)
+}
+
+/// All the units of mass, sorted from smallest to largest.
+pub const MASS_UNITS: &[&dyn DynUnit] = dyn_units![Microgram, Milligram, Gram, Kilogram, Tonne];
+
+#[cfg(test)]
+mod tests {
+    use super::{Gram, Kilogram, Microgram, Milligram, Tonne};
+    use crate::{parse::parse_as, MeasureUnit, Measurement};
+
+    #[test]
+    fn test_masses() {
+        assert_eq!(
+            2500.0,
+            Measurement::<Kilogram>::new(2.5)
+                .into_unit::<Gram>()
+                .value()
+        );
+        assert_eq!(
+            Measurement::<Tonne>::new(1.5),
+            Measurement::<Kilogram>::new(1500.0)
+        );
+        assert_eq!(
+            Measurement::<Milligram>::new(0.25),
+            parse_as::<Milligram>("250 µg").unwrap()
+        );
+        assert_eq!("ug", Microgram::ascii_symbol());
+        assert_eq!("3 kg", Measurement::<Kilogram>::new(3.0).to_string());
+    }
+}
@@ -0,0 +1,58 @@
+//! Units of force, and of torque defined as the product of a force and
+//! a length.
+
+use super::length::{Inch, Meter};
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::Force, dyn_units,
+    dynamic::DynUnit, MulUnit,
+};
+
+// Force
+define_unit!(Newton, "N", name: "newton", dimension: Force);
+define_unit!(PoundForce, "lbf", name: "pound-force", plural: "pounds-force", dimension: Force);
+define_unit!(KilogramForce, "kgf", name: "kilogram-force", plural: "kilograms-force", dimension: Force);
+define_unit!(Kilonewton, "kN", name: "kilonewton", dimension: Force);
+
+define_linear_conversions! {
+    quantity: "force";
+
+    (Newton       , 1.0),
+    (PoundForce   , 4.448_221_615_260_5),
+    (KilogramForce, 9.806_65),
+    (Kilonewton   , 1_000.0)
+}
+
+// Torque
+define_alias!(MulUnit<PoundForce, Inch > as PoundForceInch , "lbf·in");
+define_alias!(MulUnit<Newton    , Meter> as NewtonMeter    , "N·m");
+define_alias!(MulUnit<Kilonewton, Meter> as KilonewtonMeter, "kN·m");
+
+/// All the units of force, sorted from smallest to largest.
+pub const FORCE_UNITS: &[&dyn DynUnit] = dyn_units![Newton, PoundForce, KilogramForce, Kilonewton];
+
+/// All the units of torque, sorted from smallest to largest.
+pub const TORQUE_UNITS: &[&dyn DynUnit] = dyn_units![PoundForceInch, NewtonMeter, KilonewtonMeter];
+
+#[cfg(test)]
+mod tests {
+    use super::{KilonewtonMeter, Newton, NewtonMeter, PoundForce, PoundForceInch};
+    use crate::{units::Centimeter, Measurement, MulUnit};
+
+    #[test]
+    fn test_force() {
+        let force = Measurement::<PoundForce>::new(10.0);
+        assert_eq!(44.482_216_152_605, force.into_unit::<Newton>().value());
+    }
+
+    #[test]
+    fn test_torque() {
+        let torque = Measurement::<NewtonMeter>::new(1500.0);
+        assert_eq!(1.5, torque.into_unit::<KilonewtonMeter>().value());
+        assert_eq!(
+            150_000.0,
+            torque.into_unit::<MulUnit<Newton, Centimeter>>().value()
+        );
+        assert!((torque.into_unit::<PoundForceInch>().value() - 13_276.12).abs() < 0.01);
+        assert_eq!("1500 N·m", torque.to_string());
+    }
+}
@@ -0,0 +1,58 @@
+//! Units of speed and acceleration, defined as units of length divided
+//! by units of time and by their squares.
+
+use super::{
+    length::{Kilometer, Meter},
+    time::{Hour, Second},
+};
+use crate::{define_alias, dyn_units, dynamic::DynUnit, DivUnit, PowUnit};
+
+// Speed
+define_alias!(DivUnit<Kilometer, Hour  > as KilometerPerHour, "km/h");
+define_alias!(DivUnit<Meter    , Second> as MeterPerSecond  , "m/s");
+
+// Acceleration
+define_alias!(DivUnit<Kilometer, PowUnit<Hour  , 2>> as KilometerPerHourSquared, "km/h²");
+define_alias!(DivUnit<Meter    , PowUnit<Second, 2>> as MeterPerSecondSquared  , "m/s²");
+
+/// All the units of speed, sorted from smallest to largest.
+pub const SPEED_UNITS: &[&dyn DynUnit] = dyn_units![KilometerPerHour, MeterPerSecond];
+
+/// All the units of acceleration, sorted from smallest to largest.
+pub const ACCELERATION_UNITS: &[&dyn DynUnit] =
+    dyn_units![KilometerPerHourSquared, MeterPerSecondSquared];
+
+#[cfg(test)]
+mod tests {
+    use super::{KilometerPerHour, KilometerPerHourSquared, MeterPerSecond, MeterPerSecondSquared};
+    use crate::{
+        units::{Minute, Second},
+        MeasureUnit, Measurement, PowUnit,
+    };
+
+    #[test]
+    fn test_speed() {
+        let speed = Measurement::<KilometerPerHour>::new(90.0);
+
+        assert_eq!(25.0, speed.into_unit::<MeterPerSecond>().value());
+        assert_eq!("90 km/h", speed.to_string());
+    }
+
+    #[test]
+    fn test_acceleration() {
+        let gravity = Measurement::<MeterPerSecondSquared>::new(9.806_65);
+        let in_kmh2 = gravity.into_unit::<KilometerPerHourSquared>();
+
+        assert!((in_kmh2.value() - 127_094.184).abs() < 1e-6);
+        assert_eq!("m/s^2", MeterPerSecondSquared::ascii_symbol());
+    }
+
+    #[test]
+    fn test_frequency() {
+        let frequency = Measurement::<PowUnit<Second, -1>>::new(2.0);
+
+        assert_eq!(120.0, frequency.into_unit::<PowUnit<Minute, -1>>().value());
+        assert_eq!("2 s⁻¹", frequency.to_string());
+        assert_eq!("s^-1", PowUnit::<Second, -1>::ascii_symbol());
+    }
+}
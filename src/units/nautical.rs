@@ -0,0 +1,74 @@
+//! Nautical units of length and speed, used in marine and aviation
+//! navigation. They measure the same quantities as the metric units,
+//! and convert from and into them.
+
+use super::{length::Meter, time::Hour};
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::Length, dyn_units,
+    dynamic::DynUnit, DivUnit,
+};
+
+define_unit!(Fathom, "ftm", name: "fathom", dimension: Length);
+define_unit!(Cable, "cb", name: "cable", dimension: Length);
+define_unit!(NauticalMile, "NM", name: "nautical mile", dimension: Length);
+
+define_linear_conversions! {
+    quantity: "length";
+    extends: Meter;
+
+    (Fathom      , 1.828_8),
+    (Cable       , 185.2),
+    (NauticalMile, 1_852.0)
+}
+
+// Speed
+define_alias!(DivUnit<NauticalMile, Hour> as Knot, "kn");
+
+/// All the nautical units of length, sorted from smallest to largest.
+pub const NAUTICAL_LENGTH_UNITS: &[&dyn DynUnit] = dyn_units![Fathom, Cable, NauticalMile];
+
+/// All the nautical units of speed, sorted from smallest to largest.
+pub const NAUTICAL_SPEED_UNITS: &[&dyn DynUnit] = dyn_units![Knot];
+
+#[cfg(test)]
+mod tests {
+    use super::{Cable, Fathom, Knot, NauticalMile};
+    use crate::{
+        units::{Hour, Kilometer, Meter, Second},
+        DivUnit, Measurement,
+    };
+
+    #[test]
+    fn test_lengths() {
+        assert_eq!(
+            1852.0,
+            Measurement::<NauticalMile>::new(1.0)
+                .into_unit::<Meter>()
+                .value()
+        );
+        assert_eq!(
+            10.0,
+            Measurement::<NauticalMile>::new(1.0)
+                .into_unit::<Cable>()
+                .value()
+        );
+        assert_eq!(
+            100.0,
+            Measurement::<Meter>::new(182.88)
+                .into_unit::<Fathom>()
+                .value()
+        );
+    }
+
+    #[test]
+    fn test_knots() {
+        let speed = Measurement::<Knot>::new(20.0);
+
+        assert_eq!(37.04, speed.into_unit::<DivUnit<Kilometer, Hour>>().value());
+        assert_eq!(
+            Measurement::<DivUnit<Meter, Second>>::new(1852.0 / 180.0),
+            speed
+        );
+        assert_eq!("20 kn", speed.to_string());
+    }
+}
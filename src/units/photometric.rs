@@ -0,0 +1,151 @@
+//! Photometric units, measuring light as perceived by the human eye.
+//! Illuminance is luminous flux per area, so its units convert into
+//! and from quotients like `DivUnit<Lumen, SquareMeter>`.
+
+use crate::{
+    define_linear_conversions, define_unit,
+    dimension::{Illuminance, LuminousFlux, LuminousIntensity, SameDimension},
+    dyn_units,
+    dynamic::DynUnit,
+    Canonical, DivUnit, FromUnit, FromUnitLinear, LinearUnit, MeasureUnit, Measurement,
+    ScaleFactor, ScaledUnit,
+};
+
+// Luminous intensity
+define_unit!(Millicandela, "mcd", name: "millicandela", dimension: LuminousIntensity);
+define_unit!(Candela, "cd", name: "candela", dimension: LuminousIntensity);
+
+// Luminous flux
+define_unit!(Lumen, "lm", name: "lumen", dimension: LuminousFlux);
+define_unit!(Kilolumen, "klm", name: "kilolumen", dimension: LuminousFlux);
+
+// Illuminance
+define_unit!(Lux, "lx", name: "lux", plural: "lux", dimension: Illuminance);
+define_unit!(Footcandle, "fc", name: "foot-candle", dimension: Illuminance);
+define_unit!(Kilolux, "klx", name: "kilolux", plural: "kilolux", dimension: Illuminance);
+define_unit!(Phot, "ph", name: "phot", dimension: Illuminance);
+
+define_linear_conversions! {
+    quantity: "luminous intensity";
+
+    (Millicandela, 0.001),
+    (Candela     , 1.0)
+}
+
+define_linear_conversions! {
+    quantity: "luminous flux";
+
+    (Lumen    , 1.0),
+    (Kilolumen, 1_000.0)
+}
+
+define_linear_conversions! {
+    quantity: "illuminance";
+
+    (Lux       , 1.0),
+    // One lumen per square foot.
+    (Footcandle, 10.763_910_416_709_722),
+    (Kilolux   , 1_000.0),
+    (Phot      , 10_000.0)
+}
+
+impl<U, N, D> FromUnit<DivUnit<N, D>> for U
+where
+    U: ScaledUnit<Family = Lux> + SameDimension<DivUnit<N, D>>,
+    N: MeasureUnit,
+    D: MeasureUnit,
+    Canonical<DivUnit<N, D>>: LinearUnit,
+{
+    #[inline]
+    fn from_value(input: Measurement<DivUnit<N, D>>) -> Measurement<Self> {
+        Measurement::new(input.value() * ScaleFactor::<Canonical<DivUnit<N, D>>, U>::VALUE)
+    }
+}
+
+unsafe impl<U, N, D> FromUnitLinear<DivUnit<N, D>> for U
+where
+    U: ScaledUnit<Family = Lux> + SameDimension<DivUnit<N, D>>,
+    N: MeasureUnit,
+    D: MeasureUnit,
+    Canonical<DivUnit<N, D>>: LinearUnit,
+{
+}
+
+impl<U, N, D> FromUnit<U> for DivUnit<N, D>
+where
+    U: ScaledUnit<Family = Lux>,
+    N: MeasureUnit,
+    D: MeasureUnit,
+    Canonical<DivUnit<N, D>>: LinearUnit,
+    Self: SameDimension<U>,
+{
+    #[inline]
+    fn from_value(input: Measurement<U>) -> Measurement<Self> {
+        Measurement::new(input.value() * ScaleFactor::<U, Canonical<Self>>::VALUE)
+    }
+}
+
+unsafe impl<U, N, D> FromUnitLinear<U> for DivUnit<N, D>
+where
+    U: ScaledUnit<Family = Lux>,
+    N: MeasureUnit,
+    D: MeasureUnit,
+    Canonical<DivUnit<N, D>>: LinearUnit,
+    Self: SameDimension<U>,
+{
+}
+
+/// All the units of luminous intensity, sorted from smallest to largest.
+pub const LUMINOUS_INTENSITY_UNITS: &[&dyn DynUnit] = dyn_units![Millicandela, Candela];
+
+/// All the units of luminous flux, sorted from smallest to largest.
+pub const LUMINOUS_FLUX_UNITS: &[&dyn DynUnit] = dyn_units![Lumen, Kilolumen];
+
+/// All the units of illuminance, sorted from smallest to largest.
+pub const ILLUMINANCE_UNITS: &[&dyn DynUnit] = dyn_units![Lux, Footcandle, Kilolux, Phot];
+
+#[cfg(test)]
+mod tests {
+    use super::{Footcandle, Kilolux, Lux, Phot};
+    use crate::Measurement;
+
+    #[test]
+    fn test_illuminance() {
+        let daylight = Measurement::<Kilolux>::new(10.0);
+
+        assert_eq!(1.0, daylight.into_unit::<Phot>().value());
+        assert_eq!(10_000.0, daylight.into_unit::<Lux>().value());
+        assert!((daylight.into_unit::<Footcandle>().value() - 929.03).abs() < 0.01);
+    }
+
+    #[cfg(feature = "area")]
+    #[test]
+    fn test_flux_per_area() {
+        use super::Lumen;
+        use crate::{
+            units::{SquareCentimeter, SquareMeter},
+            DivUnit,
+        };
+
+        let lamp = Measurement::<Lumen>::new(800.0);
+        let lit: Measurement<Lux> = (lamp / Measurement::<SquareMeter>::new(4.0)).into_unit();
+        assert_eq!(200.0, lit.value());
+
+        assert_eq!(
+            0.02,
+            lit.into_unit::<DivUnit<Lumen, SquareCentimeter>>().value()
+        );
+        assert_eq!(
+            1.0,
+            Measurement::<DivUnit<Lumen, SquareCentimeter>>::new(1.0)
+                .into_unit::<Phot>()
+                .value()
+        );
+        assert_eq!(
+            0.2,
+            lit.into_unit::<DivUnit<Lumen, SquareMeter>>()
+                .into_unit::<Kilolux>()
+                .value()
+        );
+    }
+}
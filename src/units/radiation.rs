@@ -0,0 +1,121 @@
+//! Units of ionizing radiation. Absorbed and equivalent doses are kept
+//! as different quantities, so grays can't be converted into sieverts
+//! by mistake, even if both are one joule per kilogram.
+
+use crate::{
+    define_linear_conversions, define_unit,
+    dimension::{AbsorbedDose, EquivalentDose, Radioactivity},
+    dyn_units,
+    dynamic::DynUnit,
+};
+
+// Activity
+define_unit!(Becquerel, "Bq", name: "becquerel", dimension: Radioactivity);
+define_unit!(Kilobecquerel, "kBq", name: "kilobecquerel", dimension: Radioactivity);
+define_unit!(Megabecquerel, "MBq", name: "megabecquerel", dimension: Radioactivity);
+define_unit!(Gigabecquerel, "GBq", name: "gigabecquerel", dimension: Radioactivity);
+define_unit!(Microcurie, "µCi", ascii: "uCi", name: "microcurie", dimension: Radioactivity);
+define_unit!(Millicurie, "mCi", name: "millicurie", dimension: Radioactivity);
+define_unit!(Curie, "Ci", name: "curie", dimension: Radioactivity);
+
+// Absorbed dose
+define_unit!(Milligray, "mGy", name: "milligray", dimension: AbsorbedDose);
+define_unit!(Rad, "rad", name: "rad", dimension: AbsorbedDose);
+define_unit!(Gray, "Gy", name: "gray", dimension: AbsorbedDose);
+
+// Equivalent dose
+define_unit!(Microsievert, "µSv", ascii: "uSv", name: "microsievert", dimension: EquivalentDose);
+define_unit!(Millirem, "mrem", name: "millirem", dimension: EquivalentDose);
+define_unit!(Millisievert, "mSv", name: "millisievert", dimension: EquivalentDose);
+define_unit!(Rem, "rem", name: "rem", dimension: EquivalentDose);
+define_unit!(Sievert, "Sv", name: "sievert", dimension: EquivalentDose);
+
+define_linear_conversions! {
+    quantity: "radioactivity";
+
+    (Becquerel    , 1.0),
+    (Kilobecquerel, 1_000.0),
+    (Megabecquerel, 1_000_000.0),
+    (Gigabecquerel, 1_000_000_000.0),
+    (Microcurie   , 37_000.0),
+    (Millicurie   , 37_000_000.0),
+    (Curie        , 37_000_000_000.0)
+}
+
+define_linear_conversions! {
+    quantity: "absorbed dose";
+
+    (Milligray, 0.001),
+    (Rad      , 0.01),
+    (Gray     , 1.0)
+}
+
+define_linear_conversions! {
+    quantity: "equivalent dose";
+
+    (Microsievert, 0.000_001),
+    (Millirem    , 0.000_01),
+    (Millisievert, 0.001),
+    (Rem         , 0.01),
+    (Sievert     , 1.0)
+}
+
+/// All the units of radioactivity, sorted from smallest to largest.
+pub const RADIOACTIVITY_UNITS: &[&dyn DynUnit] = dyn_units![
+    Becquerel,
+    Kilobecquerel,
+    Microcurie,
+    Megabecquerel,
+    Millicurie,
+    Gigabecquerel,
+    Curie,
+];
+
+/// All the units of absorbed dose, sorted from smallest to largest.
+pub const ABSORBED_DOSE_UNITS: &[&dyn DynUnit] = dyn_units![Milligray, Rad, Gray];
+
+/// All the units of equivalent dose, sorted from smallest to largest.
+pub const EQUIVALENT_DOSE_UNITS: &[&dyn DynUnit] =
+    dyn_units![Microsievert, Millirem, Millisievert, Rem, Sievert];
+
+#[cfg(test)]
+mod tests {
+    use super::{Becquerel, Curie, Gray, Millicurie, Millirem, Rad, Rem, Sievert};
+    use crate::{dynamic::AnyMeasurement, Measurement};
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            250.0,
+            Measurement::<Gray>::new(2.5).into_unit::<Rad>().value()
+        );
+        assert_eq!(
+            0.5,
+            Measurement::<Rem>::new(50.0).into_unit::<Sievert>().value()
+        );
+        assert_eq!(
+            100.0,
+            Measurement::<Rem>::new(0.1).into_unit::<Millirem>().value()
+        );
+        assert_eq!(
+            3.7e7,
+            Measurement::<Millicurie>::new(1.0)
+                .into_unit::<Becquerel>()
+                .value()
+        );
+        assert_eq!(
+            0.001,
+            Measurement::<Millicurie>::new(1.0)
+                .into_unit::<Curie>()
+                .value()
+        );
+    }
+
+    #[test]
+    fn test_doses_are_different_quantities() {
+        let absorbed: AnyMeasurement = Measurement::<Gray>::new(1.0).into();
+        let equivalent: AnyMeasurement = Measurement::<Sievert>::new(1.0).into();
+
+        assert!(!absorbed.same_quantity(&equivalent));
+    }
+}
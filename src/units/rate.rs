@@ -0,0 +1,74 @@
+//! Data transmission rates, defined as aliases of data over time, and
+//! symbol rates, which only convert into data rates given the number
+//! of bits carried by each symbol.
+
+use super::{
+    data::{Bit, Gigabit, Kilobit, Megabit, Terabit},
+    time::Second,
+};
+use crate::{
+    define_alias, define_linear_conversions, define_unit, dimension::SymbolRate, dyn_units,
+    dynamic::DynUnit, DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+// Transmission speed
+define_alias!(DivUnit<Bit    , Second> as Bps , "bps");
+define_alias!(DivUnit<Kilobit, Second> as Kbps, "Kbps");
+define_alias!(DivUnit<Megabit, Second> as Mbps, "Mbps");
+define_alias!(DivUnit<Gigabit, Second> as Gbps, "Gbps");
+define_alias!(DivUnit<Terabit, Second> as Tbps, "Tbps");
+
+// Symbol rate
+define_unit!(Baud, "Bd", name: "baud", plural: "baud", dimension: SymbolRate);
+define_unit!(Kilobaud, "kBd", name: "kilobaud", plural: "kilobaud", dimension: SymbolRate);
+define_unit!(Megabaud, "MBd", name: "megabaud", plural: "megabaud", dimension: SymbolRate);
+
+define_linear_conversions! {
+    quantity: "symbol rate";
+
+    (Baud    , 1.0),
+    (Kilobaud, 1_000.0),
+    (Megabaud, 1_000_000.0)
+}
+
+/// All the units of transmission speed, sorted from smallest to largest.
+pub const RATE_UNITS: &[&dyn DynUnit] = dyn_units![Bps, Kbps, Mbps, Gbps, Tbps];
+
+/// All the units of symbol rate, sorted from smallest to largest.
+pub const SYMBOL_RATE_UNITS: &[&dyn DynUnit] = dyn_units![Baud, Kilobaud, Megabaud];
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Converts this symbol rate into the data rate it carries, given
+    /// the number of bits encoded in each symbol.
+    pub fn bit_rate_at(self, bits_per_symbol: f64) -> Measurement<Bps>
+    where
+        Baud: FromUnit<U>,
+    {
+        Measurement::new(self.into_unit::<Baud>().value() * bits_per_symbol)
+    }
+
+    /// Converts this data rate into the symbol rate needed to carry
+    /// it, given the number of bits encoded in each symbol.
+    pub fn symbol_rate_at(self, bits_per_symbol: f64) -> Measurement<Baud>
+    where
+        DivUnit<Bit, Second>: FromUnit<U>,
+    {
+        Measurement::new(self.into_unit::<Bps>().value() / bits_per_symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kbps, Kilobaud, Mbps};
+    use crate::Measurement;
+
+    #[test]
+    fn test_symbol_rates() {
+        // 16-QAM carries 4 bits per symbol.
+        let symbols = Measurement::<Kilobaud>::new(2_400.0);
+        assert_eq!(9.6, symbols.bit_rate_at(4.0).into_unit::<Mbps>().value());
+
+        let symbols = Measurement::<Kbps>::new(9.6).symbol_rate_at(1.0);
+        assert_eq!(9.6, symbols.into_unit::<Kilobaud>().value());
+    }
+}
@@ -0,0 +1,116 @@
+//! Rates of input/output operations, and their relationship with the
+//! latency and bandwidth of storage devices.
+
+use super::{
+    count::{KopsPerSecond, MopsPerSecond, Operation, OpsPerSecond},
+    data::Bit,
+    rate::Bps,
+    time::Second,
+};
+use crate::{DivUnit, FromUnit, MeasureUnit, Measurement};
+
+// Operation rates, under the names they are known by in storage. They
+// are the same units as the operation throughput ones, so they are not
+// listed again.
+pub type Iops = OpsPerSecond;
+pub type Kiops = KopsPerSecond;
+pub type Miops = MopsPerSecond;
+
+impl<U: MeasureUnit> Measurement<U> {
+    /// Returns the average latency of each operation when this rate of
+    /// operations is sustained with the given number of them in flight
+    /// (Little's law).
+    pub fn latency_at(self, queue_depth: u32) -> Measurement<Second>
+    where
+        DivUnit<Operation, Second>: FromUnit<U>,
+    {
+        Measurement::new(queue_depth as f64 / self.into_unit::<Iops>().value())
+    }
+
+    /// Returns the bandwidth of this rate of operations, each one
+    /// transferring the given amount of data.
+    pub fn bandwidth_at<D: MeasureUnit>(self, io_size: Measurement<D>) -> Measurement<Bps>
+    where
+        DivUnit<Operation, Second>: FromUnit<U>,
+        Bit: FromUnit<D>,
+    {
+        Measurement::new(self.into_unit::<Iops>().value() * io_size.into_unit::<Bit>().value())
+    }
+}
+
+/// Returns the rate of operations reached when each one takes the
+/// given average latency, with the given number of them in flight
+/// (Little's law).
+pub fn iops_for_latency<T: MeasureUnit>(
+    latency: Measurement<T>,
+    queue_depth: u32,
+) -> Measurement<Iops>
+where
+    Second: FromUnit<T>,
+{
+    Measurement::new(queue_depth as f64 / latency.into_unit::<Second>().value())
+}
+
+/// Returns the rate of operations needed to reach the given bandwidth,
+/// each one transferring the given amount of data.
+pub fn iops_for_bandwidth<R, D>(
+    bandwidth: Measurement<R>,
+    io_size: Measurement<D>,
+) -> Measurement<Iops>
+where
+    R: MeasureUnit,
+    D: MeasureUnit,
+    DivUnit<Bit, Second>: FromUnit<R>,
+    Bit: FromUnit<D>,
+{
+    Measurement::new(bandwidth.into_unit::<Bps>().value() / io_size.into_unit::<Bit>().value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{iops_for_bandwidth, iops_for_latency, Iops, Kiops};
+    use crate::{
+        units::{find_unit, Kibibyte, Mebibyte, Microsecond, Page, Second, UNIT_SETS},
+        DivUnit, Measurement,
+    };
+
+    #[test]
+    fn test_latency() {
+        let iops = Measurement::<Kiops>::new(200.0);
+
+        let latency = iops.latency_at(32).into_unit::<Microsecond>();
+        assert!((latency.value() - 160.0).abs() < 1e-9);
+
+        let iops = iops_for_latency(latency, 32).into_unit::<Kiops>();
+        assert!((iops.value() - 200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bandwidth() {
+        let iops = Measurement::<Iops>::new(25_600.0);
+        let bandwidth = iops.bandwidth_at(Measurement::<Kibibyte>::new(4.0));
+
+        assert_eq!(
+            100.0,
+            bandwidth.into_unit::<DivUnit<Mebibyte, Second>>().value()
+        );
+        assert_eq!(
+            iops,
+            iops_for_bandwidth(bandwidth, Measurement::<Page>::new(1.0))
+        );
+    }
+
+    #[test]
+    fn test_rates_listed_once() {
+        assert_eq!("1.5 kop/s", Measurement::<Kiops>::new(1.5).to_string());
+        assert_eq!(
+            1,
+            UNIT_SETS
+                .iter()
+                .flat_map(|units| units.iter())
+                .filter(|unit| unit.quantity() == find_unit("op/s").unwrap().quantity())
+                .filter(|unit| unit.value_to_base(1.0) == 1000.0)
+                .count()
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Units of thermodynamic temperature. Unlike most units, they are
+//! related by an offset besides a factor (0 °C is 273.15 K), so they
+//! are converted through
+//! [`define_affine_conversions!`](crate::define_affine_conversions),
+//! and can't be converted when composed into other units, like degrees
+//! Celsius per hour into kelvins per hour.
+
+use crate::{
+    define_affine_conversions, define_unit, dimension::Temperature, dyn_units, dynamic::DynUnit,
+};
+
+define_unit!(Kelvin, "K", name: "kelvin", dimension: Temperature);
+define_unit!(Celsius, "°C", name: "degree Celsius", plural: "degrees Celsius", dimension: Temperature);
+define_unit!(Fahrenheit, "°F", name: "degree Fahrenheit", plural: "degrees Fahrenheit", dimension: Temperature);
+define_unit!(Rankine, "°R", name: "degree Rankine", plural: "degrees Rankine", dimension: Temperature);
+
+// Relative to the rankine, whose degree is the smallest, so the factors
+// between the degrees of all the units are exact (1.8 and 1).
+define_affine_conversions! {
+    quantity: "temperature";
+
+    (Rankine   , 1  , 0),
+    (Kelvin    , 1.8, 0),
+    (Celsius   , 1.8, 491.67),
+    (Fahrenheit, 1  , 459.67)
+}
+
+/// All the units of temperature, sorted by the size of their degrees
+/// and then by their zero.
+pub const TEMPERATURE_UNITS: &[&dyn DynUnit] = dyn_units![Rankine, Fahrenheit, Kelvin, Celsius];
+
+#[cfg(test)]
+mod tests {
+    use super::{Celsius, Fahrenheit, Kelvin, Rankine};
+    use crate::{
+        dynamic::dyn_unit,
+        parse::parse_any,
+        registry::{conversion_factor, convert, factor_table, register_unit, RegisterError},
+        MeasureUnit, Measurement,
+    };
+
+    #[test]
+    fn test_conversions() {
+        let boiling = Measurement::<Celsius>::new(100.0);
+
+        assert_eq!(212.0, boiling.into_unit::<Fahrenheit>().value());
+        assert_eq!(373.15, boiling.into_unit::<Kelvin>().value());
+        assert_eq!(
+            0.0,
+            Measurement::<Fahrenheit>::new(32.0)
+                .into_unit::<Celsius>()
+                .value()
+        );
+        assert_eq!(
+            -40.0,
+            Measurement::<Celsius>::new(-40.0)
+                .into_unit::<Fahrenheit>()
+                .value()
+        );
+        assert_eq!(
+            37.0,
+            Measurement::<Fahrenheit>::new(98.6)
+                .into_unit::<Celsius>()
+                .value()
+        );
+        assert_eq!(
+            -459.67,
+            Measurement::<Kelvin>::new(0.0)
+                .into_unit::<Fahrenheit>()
+                .value()
+        );
+        assert_eq!(
+            491.67,
+            Measurement::<Celsius>::new(0.0)
+                .into_unit::<Rankine>()
+                .value()
+        );
+    }
+
+    #[test]
+    fn test_comparisons() {
+        assert!(Measurement::<Celsius>::new(20.0) > Measurement::<Fahrenheit>::new(60.0));
+        assert_eq!(
+            Measurement::<Kelvin>::new(273.15),
+            Measurement::<Celsius>::new(0.0)
+        );
+    }
+
+    #[test]
+    fn test_symbols() {
+        assert_eq!("21.5 °C", Measurement::<Celsius>::new(21.5).to_string());
+        assert_eq!("451 °F", Measurement::<Fahrenheit>::new(451.0).to_string());
+        assert_eq!("4 K", Measurement::<Kelvin>::new(4.0).to_string());
+        assert_eq!("degC", Celsius::ascii_symbol());
+        assert_eq!("degrees Fahrenheit", Fahrenheit::plural_name());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_composed_symbols() {
+        use crate::{
+            units::{Hour, Minute},
+            DivUnit,
+        };
+
+        let cooling = Measurement::<DivUnit<Celsius, Hour>>::new(-3.0);
+        assert_eq!(
+            "-0.05 °C/min",
+            cooling.into_unit::<DivUnit<Celsius, Minute>>().to_string()
+        );
+    }
+
+    #[test]
+    fn test_dynamic() {
+        let room = parse_any("20 °C").unwrap();
+        assert_eq!("temperature", room.quantity());
+        assert_eq!("20 °C", room.to_string());
+        assert_eq!(
+            Some(Measurement::<Celsius>::new(20.0)),
+            room.downcast::<Measurement<Celsius>>()
+        );
+
+        let fahrenheit: Measurement<Fahrenheit> = room.try_into().unwrap();
+        assert!((fahrenheit.value() - 68.0).abs() < 1e-9);
+        assert!(parse_any("-273.15 degC").unwrap().base_value().abs() < 1e-9);
+        assert_eq!("degrees Celsius", dyn_unit::<Celsius>().plural_name());
+        assert!(dyn_unit::<Kelvin>().is_linear());
+        assert!(!dyn_unit::<Celsius>().is_linear());
+
+        // Units related by an offset have no factor, and can't be
+        // composed into quotients nor define new units.
+        assert!(parse_any("3 °C/h").is_err());
+        assert_eq!(None, conversion_factor("°C", "°F"));
+        assert_eq!(Some(1.8), conversion_factor("K", "°R"));
+        assert!((convert(100.0, "°C", "°F").unwrap() - 212.0).abs() < 1e-9);
+        assert_eq!(
+            Err(RegisterError::InvalidFactor("body".to_owned())),
+            register_unit("body", Measurement::<Celsius>::new(37.0)).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_factor_table() {
+        let table = factor_table("temperature").unwrap();
+        let symbols: Vec<_> = table
+            .units
+            .iter()
+            .map(|unit| unit.symbol.as_str())
+            .collect();
+
+        assert_eq!(vec!["°R", "°F", "K", "°C"], symbols);
+        assert!((table.factor("°C", "°F").unwrap() - 1.8).abs() < 1e-9);
+        assert!((table.convert(100.0, "°C", "°F").unwrap() - 212.0).abs() < 1e-9);
+        assert!((table.convert(32.0, "°F", "K").unwrap() - 273.15).abs() < 1e-9);
+    }
+}
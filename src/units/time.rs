@@ -0,0 +1,316 @@
+//! Units of time, and their interoperability with [`Duration`],
+//! [`Instant`] and [`SystemTime`].
+
+use std::{
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::{Duration, Instant, SystemTime, SystemTimeError},
+};
+
+use crate::{
+    define_linear_conversions, define_unit, dimension::Time, dyn_units, dynamic::DynUnit, FromUnit,
+    MeasureUnit, Measurement,
+};
+
+// Time
+define_unit!(Nanosecond, "ns", name: "nanosecond", dimension: Time);
+define_unit!(Microsecond, "µs", ascii: "us", name: "microsecond", dimension: Time);
+define_unit!(Millisecond, "ms", name: "millisecond", dimension: Time);
+define_unit!(Second, "s", name: "second", dimension: Time);
+define_unit!(Minute, "min", name: "minute", dimension: Time);
+define_unit!(Hour, "h", name: "hour", dimension: Time);
+define_unit!(Day, "d", name: "day", dimension: Time);
+
+define_linear_conversions! {
+    quantity: "time";
+
+    (Nanosecond, 0.000_000_001),
+    (Microsecond, 0.000_001),
+    (Millisecond, 0.001),
+    (Second, 1),
+    (Minute, 60 Second),
+    (Hour, 60 Minute),
+    (Day, 24 Hour)
+}
+
+/// All the units of time, sorted from smallest to largest.
+pub const TIME_UNITS: &[&dyn DynUnit] = dyn_units![
+    Nanosecond,
+    Microsecond,
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day
+];
+
+impl<U: MeasureUnit> From<Duration> for Measurement<U>
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    fn from(duration: Duration) -> Self {
+        Measurement::<Second>::new(duration.as_secs_f64()).into_unit()
+    }
+}
+
+impl<U: MeasureUnit> Add<Duration> for Measurement<U>
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Duration) -> Self::Output {
+        Measurement::new(self.value() + Self::from(rhs).value())
+    }
+}
+
+impl<U: MeasureUnit> AddAssign<Duration> for Measurement<U>
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl<U: MeasureUnit> Sub<Duration> for Measurement<U>
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self::Output {
+        Measurement::new(self.value() - Self::from(rhs).value())
+    }
+}
+
+impl<U: MeasureUnit> SubAssign<Duration> for Measurement<U>
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// Adding a measurement to a duration gives a measurement, since the
+/// result may be negative, which durations can't represent.
+impl<U: MeasureUnit> Add<Measurement<U>> for Duration
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    type Output = Measurement<U>;
+
+    #[inline]
+    fn add(self, rhs: Measurement<U>) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// Subtracting a measurement from a duration gives a measurement,
+/// since the result may be negative, which durations can't represent.
+impl<U: MeasureUnit> Sub<Measurement<U>> for Duration
+where
+    U::AliasedUnit: FromUnit<Second>,
+{
+    type Output = Measurement<U>;
+
+    #[inline]
+    fn sub(self, rhs: Measurement<U>) -> Self::Output {
+        Measurement::new(Measurement::<U>::from(self).value() - rhs.value())
+    }
+}
+
+/// Extension of [`Instant`] for measuring the time elapsed since then
+/// directly in a unit of time, e.g
+/// `start.elapsed_measure::<Millisecond>()`.
+pub trait InstantExt {
+    /// Returns the time elapsed since this instant.
+    fn elapsed_measure<U: MeasureUnit>(&self) -> Measurement<U>
+    where
+        U::AliasedUnit: FromUnit<Second>;
+
+    /// Returns the time elapsed from the given instant until this one,
+    /// or zero if the given instant is later.
+    fn measure_since<U: MeasureUnit>(&self, earlier: Instant) -> Measurement<U>
+    where
+        U::AliasedUnit: FromUnit<Second>;
+}
+
+impl InstantExt for Instant {
+    fn elapsed_measure<U: MeasureUnit>(&self) -> Measurement<U>
+    where
+        U::AliasedUnit: FromUnit<Second>,
+    {
+        self.elapsed().into()
+    }
+
+    fn measure_since<U: MeasureUnit>(&self, earlier: Instant) -> Measurement<U>
+    where
+        U::AliasedUnit: FromUnit<Second>,
+    {
+        self.saturating_duration_since(earlier).into()
+    }
+}
+
+/// Returns the time elapsed from `earlier` to `later`, or an error if
+/// `later` is actually before `earlier`, which may happen with wall
+/// clock times (see [`SystemTime::duration_since`]).
+pub fn duration_between(
+    earlier: SystemTime,
+    later: SystemTime,
+) -> Result<Measurement<Second>, SystemTimeError> {
+    later.duration_since(earlier).map(Measurement::from)
+}
+
+/// Shifts the given time by the given amount of seconds, forward if
+/// positive and backward if negative.
+///
+/// # Panics
+///
+/// Panics if the seconds are not finite, or the resulting time can't
+/// be represented, like adding a `Duration` to a `SystemTime` does.
+fn shift(time: SystemTime, seconds: f64) -> SystemTime {
+    let duration = Duration::from_secs_f64(seconds.abs());
+    if seconds >= 0.0 {
+        time + duration
+    } else {
+        time - duration
+    }
+}
+
+/// Adding a measurement of time to a `SystemTime` moves it forward, or
+/// backward if the measurement is negative.
+impl<U: MeasureUnit> Add<Measurement<U>> for SystemTime
+where
+    Second: FromUnit<U>,
+{
+    type Output = SystemTime;
+
+    #[inline]
+    fn add(self, rhs: Measurement<U>) -> Self::Output {
+        shift(self, rhs.into_unit::<Second>().value())
+    }
+}
+
+impl<U: MeasureUnit> AddAssign<Measurement<U>> for SystemTime
+where
+    Second: FromUnit<U>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Measurement<U>) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtracting a measurement of time from a `SystemTime` moves it
+/// backward, or forward if the measurement is negative.
+impl<U: MeasureUnit> Sub<Measurement<U>> for SystemTime
+where
+    Second: FromUnit<U>,
+{
+    type Output = SystemTime;
+
+    #[inline]
+    fn sub(self, rhs: Measurement<U>) -> Self::Output {
+        shift(self, -rhs.into_unit::<Second>().value())
+    }
+}
+
+impl<U: MeasureUnit> SubAssign<Measurement<U>> for SystemTime
+where
+    Second: FromUnit<U>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Measurement<U>) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant, SystemTime};
+
+    use super::{duration_between, Day, Hour, InstantExt, Millisecond, Minute, Second};
+    use crate::{LinearUnit, Measurement};
+
+    #[test]
+    fn test_chained_factors() {
+        assert_eq!(3600.0, Hour::FACTOR_TO_BASE);
+        assert_eq!(86_400.0, Day::FACTOR_TO_BASE);
+        assert_eq!(
+            2160.0,
+            Measurement::<Day>::new(1.5).into_unit::<Minute>().value()
+        );
+        assert_eq!(
+            0.5,
+            Measurement::<Minute>::new(720.0).into_unit::<Day>().value()
+        );
+    }
+
+    #[test]
+    fn test_duration_arithmetic() {
+        let timeout = Measurement::<Second>::new(30.0);
+
+        assert_eq!(30.5, (timeout + Duration::from_millis(500)).value());
+        assert_eq!(29.5, (timeout - Duration::from_millis(500)).value());
+        assert_eq!(
+            Measurement::<Millisecond>::new(-1500.0),
+            Duration::from_secs(1) - Measurement::<Millisecond>::new(2500.0)
+        );
+        assert_eq!(
+            Measurement::<Minute>::new(1.5),
+            Duration::from_secs(30) + Measurement::<Minute>::new(1.0)
+        );
+
+        let mut elapsed = Measurement::<Millisecond>::new(0.0);
+        elapsed += Duration::from_micros(1500);
+        elapsed += Duration::from_millis(3);
+        elapsed -= Duration::from_millis(1);
+        assert_eq!(3.5, elapsed.value());
+        assert_eq!(
+            250.0,
+            Measurement::<Millisecond>::from(Duration::from_millis(250)).value()
+        );
+    }
+
+    #[test]
+    fn test_system_time() {
+        let now = SystemTime::now();
+        let later = now + Measurement::<Minute>::new(1.5);
+
+        assert_eq!(now + Duration::from_secs(90), later);
+        assert_eq!(
+            Measurement::<Second>::new(90.0),
+            duration_between(now, later).unwrap()
+        );
+        assert_eq!(
+            90.0,
+            duration_between(later, now)
+                .unwrap_err()
+                .duration()
+                .as_secs_f64()
+        );
+
+        assert_eq!(now, later - Measurement::<Second>::new(90.0));
+        assert_eq!(now, later + Measurement::<Millisecond>::new(-90_000.0));
+
+        let mut deadline = now;
+        deadline += Measurement::<Hour>::new(1.0);
+        deadline -= Measurement::<Minute>::new(30.0);
+        assert_eq!(now + Duration::from_secs(1800), deadline);
+    }
+
+    #[test]
+    fn test_instant_ext() {
+        let start = Instant::now();
+        let later = start + Duration::from_millis(1500);
+
+        assert_eq!(1500.0, later.measure_since::<Millisecond>(start).value());
+        assert_eq!(0.0, start.measure_since::<Second>(later).value());
+        assert!(start.elapsed_measure::<Second>().value() >= 0.0);
+    }
+}
@@ -0,0 +1,168 @@
+//! Units of typography, and conversions between them and pixels or
+//! ems, which depend on the pixel density and font size in use.
+
+use super::{
+    graphics::{Pixel, Ppi},
+    length::{Inch, Meter},
+};
+use crate::{
+    define_linear_conversions, define_unit, dimension::Length, dyn_units, dynamic::DynUnit,
+    DivUnit, FromUnit, MeasureUnit, Measurement,
+};
+
+define_unit!(Point, "pt", name: "point", dimension: Length);
+define_unit!(Pica, "pc", name: "pica", dimension: Length);
+
+define_linear_conversions! {
+    quantity: "length";
+    extends: Meter;
+
+    // 1/72 and 1/6 of an inch.
+    (Point, 0.000_352_777_777_777_777_76),
+    (Pica , 0.004_233_333_333_333_333)
+}
+
+/// All the typographic units of length, sorted from smallest to largest.
+pub const TYPOGRAPHIC_UNITS: &[&dyn DynUnit] = dyn_units![Point, Pica];
+
+/// Pixel density and font size that relate typographic lengths with
+/// pixels and ems.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Typesetting {
+    density: Measurement<Ppi>,
+    font_size: Measurement<Point>,
+}
+
+impl Default for Typesetting {
+    /// The CSS reference: 96 pixels per inch, and a font size of 16
+    /// pixels (12 pt).
+    fn default() -> Self {
+        Self::new(
+            Measurement::<Ppi>::new(96.0),
+            Measurement::<Point>::new(12.0),
+        )
+    }
+}
+
+impl Typesetting {
+    /// Creates a new typesetting with the given pixel density and font
+    /// size.
+    pub fn new<D: MeasureUnit, F: MeasureUnit>(
+        density: Measurement<D>,
+        font_size: Measurement<F>,
+    ) -> Self
+    where
+        DivUnit<Pixel, Inch>: FromUnit<D>,
+        Point: FromUnit<F>,
+    {
+        Self {
+            density: density.into_unit(),
+            font_size: font_size.into_unit(),
+        }
+    }
+
+    /// Returns the same typesetting, but with the given pixel density.
+    pub fn with_density<D: MeasureUnit>(self, density: Measurement<D>) -> Self
+    where
+        DivUnit<Pixel, Inch>: FromUnit<D>,
+    {
+        Self::new(density, self.font_size)
+    }
+
+    /// Returns the same typesetting, but with the given font size.
+    pub fn with_font_size<F: MeasureUnit>(self, font_size: Measurement<F>) -> Self
+    where
+        Point: FromUnit<F>,
+    {
+        Self::new(self.density, font_size)
+    }
+
+    /// Returns the pixel density.
+    pub fn density(&self) -> Measurement<Ppi> {
+        self.density
+    }
+
+    /// Returns the font size.
+    pub fn font_size(&self) -> Measurement<Point> {
+        self.font_size
+    }
+
+    /// Converts the given length into pixels.
+    pub fn to_pixels<L: MeasureUnit>(&self, length: Measurement<L>) -> Measurement<Pixel>
+    where
+        Inch: FromUnit<L>,
+    {
+        length.to_pixels(self.density)
+    }
+
+    /// Converts the given count of pixels into a length.
+    pub fn from_pixels<L: MeasureUnit, P: MeasureUnit>(
+        &self,
+        pixels: Measurement<P>,
+    ) -> Measurement<L>
+    where
+        Pixel: FromUnit<P>,
+        L::AliasedUnit: FromUnit<Inch>,
+    {
+        pixels.to_length(self.density)
+    }
+
+    /// Converts the given length into ems, i.e into multiples of the
+    /// font size.
+    pub fn to_ems<L: MeasureUnit>(&self, length: Measurement<L>) -> f64
+    where
+        Point: FromUnit<L>,
+    {
+        length.into_unit::<Point>().value() / self.font_size.value()
+    }
+
+    /// Converts the given number of ems into a length.
+    pub fn from_ems<L: MeasureUnit>(&self, ems: f64) -> Measurement<L>
+    where
+        L::AliasedUnit: FromUnit<Point>,
+    {
+        (self.font_size * ems).into_unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pica, Point, Typesetting};
+    use crate::{
+        units::{Dpi, Inch, Millimeter, Pixel},
+        Measurement,
+    };
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            72.0,
+            Measurement::<Inch>::new(1.0).into_unit::<Point>().value()
+        );
+        assert!((Measurement::<Pica>::new(1.0).into_unit::<Point>().value() - 12.0).abs() < 1e-9);
+        assert!(
+            (Measurement::<Point>::new(1.0)
+                .into_unit::<Millimeter>()
+                .value()
+                - 0.3528)
+                .abs()
+                < 1e-4
+        );
+    }
+
+    #[test]
+    fn test_typesetting() {
+        let css = Typesetting::default();
+
+        assert_eq!(16.0, css.to_pixels(css.font_size()).value());
+        assert_eq!(2.0, css.to_ems(Measurement::<Pica>::new(2.0)));
+
+        let length: Measurement<Point> = css.from_pixels(Measurement::<Pixel>::new(4.0));
+        assert_eq!(3.0, length.value());
+
+        let retina = css.with_density(Measurement::<Dpi>::new(192.0));
+        let length: Measurement<Point> = retina.from_ems(1.5);
+        assert_eq!(18.0, length.value());
+        assert_eq!(48.0, retina.to_pixels(length).value());
+    }
+}
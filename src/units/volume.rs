@@ -0,0 +1,61 @@
+//! Metric units of volume, plus the US gallon, which most flow rates
+//! are still given in.
+
+use crate::{
+    define_linear_conversions, define_unit, dimension::Volume, dyn_units, dynamic::DynUnit,
+};
+
+define_unit!(Milliliter, "mL", name: "milliliter", dimension: Volume);
+define_unit!(Centiliter, "cL", name: "centiliter", dimension: Volume);
+define_unit!(Deciliter, "dL", name: "deciliter", dimension: Volume);
+define_unit!(Liter, "L", name: "liter", dimension: Volume);
+define_unit!(UsGallon, "gal", name: "US gallon", dimension: Volume);
+define_unit!(CubicMeter, "m³", name: "cubic meter", dimension: Volume);
+
+define_linear_conversions! {
+    quantity: "volume";
+
+    (Milliliter, 0.001),
+    (Centiliter, 0.01),
+    (Deciliter , 0.1),
+    (Liter     , 1.0),
+    (UsGallon  , 3.785_411_784),
+    (CubicMeter, 1_000.0)
+}
+
+/// All the units of volume, sorted from smallest to largest.
+pub const VOLUME_UNITS: &[&dyn DynUnit] =
+    dyn_units![Milliliter, Centiliter, Deciliter, Liter, UsGallon, CubicMeter];
+
+#[cfg(test)]
+mod tests {
+    use super::{CubicMeter, Deciliter, Liter, Milliliter, UsGallon};
+    use crate::{parse::parse_as, Measurement};
+
+    #[test]
+    fn test_volumes() {
+        assert_eq!(
+            330.0,
+            Measurement::<Deciliter>::new(3.3)
+                .into_unit::<Milliliter>()
+                .value()
+        );
+        assert_eq!(
+            Measurement::<CubicMeter>::new(2.0),
+            Measurement::<Liter>::new(2000.0)
+        );
+        assert!(
+            (Measurement::<UsGallon>::new(10.0)
+                .into_unit::<Liter>()
+                .value()
+                - 37.854_117_84)
+                .abs()
+                < 1e-9
+        );
+        assert_eq!(
+            Measurement::<Liter>::new(1.5),
+            parse_as::<Liter>("1500 mL").unwrap()
+        );
+        assert_eq!("2 m³", Measurement::<CubicMeter>::new(2.0).to_string());
+    }
+}
@@ -0,0 +1,206 @@
+//! Small fixed-size vectors whose components are measurements of the
+//! same unit, for representing positions, velocities and the like
+//! without depending on a full linear algebra crate.
+
+use std::{
+    fmt::{Debug, Display},
+    ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
+};
+
+use crate::{FromUnit, MeasureUnit, Measurement, PowUnit, Scalar};
+
+macro_rules! define_vector {
+    ($(#[$doc:meta])* $name:ident { $($field:ident),+ }) => {
+        $(#[$doc])*
+        pub struct $name<U> {
+            $(pub $field: Measurement<U>,)+
+        }
+
+        impl<U> $name<U> {
+            /// Creates a new vector from its components.
+            #[inline]
+            pub fn new($($field: Measurement<U>),+) -> Self {
+                Self { $($field),+ }
+            }
+
+            /// Creates a new vector from the raw values of its components.
+            #[inline]
+            pub fn from_values($($field: f64),+) -> Self {
+                Self { $($field: Measurement::new($field)),+ }
+            }
+
+            /// Returns the dot product of both vectors, whose unit is the
+            /// square of the unit of the vectors.
+            #[inline]
+            pub fn dot(self, other: Self) -> Measurement<PowUnit<U, 2>> {
+                Measurement::new(0.0 $(+ self.$field.value() * other.$field.value())+)
+            }
+
+            /// Returns the euclidean norm (length) of the vector.
+            #[inline]
+            pub fn norm(self) -> Measurement<U> {
+                self.dot(self).sqrt()
+            }
+
+            /// Converts all the components of the vector into the unit V.
+            #[inline]
+            pub fn into_unit<V: MeasureUnit>(self) -> $name<V>
+            where
+                U: MeasureUnit,
+                V::AliasedUnit: FromUnit<U>,
+            {
+                $name { $($field: self.$field.into_unit()),+ }
+            }
+        }
+
+        impl<U> Copy for $name<U> {}
+
+        impl<U> Clone for $name<U> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<U> Default for $name<U> {
+            fn default() -> Self {
+                Self { $($field: Measurement::default()),+ }
+            }
+        }
+
+        impl<U: MeasureUnit> Debug for $name<U> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(&format!("{}<{}>", stringify!($name), U::symbol()))
+                    $(.field(stringify!($field), &self.$field.value()))+
+                    .finish()
+            }
+        }
+
+        impl<U: MeasureUnit> Display for $name<U> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("(")?;
+                for (i, component) in [$(self.$field),+].iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    Display::fmt(component, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+
+        impl<Lhs: MeasureUnit, Rhs: MeasureUnit> PartialEq<$name<Rhs>> for $name<Lhs>
+        where
+            Lhs::AliasedUnit: FromUnit<Rhs>,
+        {
+            fn eq(&self, other: &$name<Rhs>) -> bool {
+                true $(&& self.$field == other.$field)+
+            }
+        }
+
+        impl<Lhs: MeasureUnit, Rhs: MeasureUnit> Add<$name<Rhs>> for $name<Lhs>
+        where
+            Lhs::AliasedUnit: FromUnit<Rhs>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: $name<Rhs>) -> Self::Output {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<Lhs: MeasureUnit, Rhs: MeasureUnit> AddAssign<$name<Rhs>> for $name<Lhs>
+        where
+            Lhs::AliasedUnit: FromUnit<Rhs>,
+        {
+            #[inline]
+            fn add_assign(&mut self, rhs: $name<Rhs>) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<Lhs: MeasureUnit, Rhs: MeasureUnit> Sub<$name<Rhs>> for $name<Lhs>
+        where
+            Lhs::AliasedUnit: FromUnit<Rhs>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: $name<Rhs>) -> Self::Output {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<Lhs: MeasureUnit, Rhs: MeasureUnit> SubAssign<$name<Rhs>> for $name<Lhs>
+        where
+            Lhs::AliasedUnit: FromUnit<Rhs>,
+        {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $name<Rhs>) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<U, S: Scalar> Mul<S> for $name<U> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: S) -> Self::Output {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<U, S: Scalar> Div<S> for $name<U> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: S) -> Self::Output {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+    };
+}
+
+define_vector! {
+    /// Two-dimensional vector of measurements of the unit U.
+    Vec2 { x, y }
+}
+
+define_vector! {
+    /// Three-dimensional vector of measurements of the unit U.
+    Vec3 { x, y, z }
+}
+
+#[cfg(all(test, feature = "time"))]
+mod tests {
+    use super::{Vec2, Vec3};
+    use crate::units::{Minute, Second};
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let a: Vec2<Minute> = Vec2::from_values(1.0, 2.0);
+        let b: Vec2<Second> = Vec2::from_values(60.0, 120.0);
+
+        assert_eq!(Vec2::<Minute>::from_values(2.0, 4.0), a + b);
+        assert_eq!(Vec2::<Minute>::from_values(0.0, 0.0), a - b);
+        assert_eq!(Vec2::<Minute>::from_values(3.0, 6.0), a * 3u32);
+        assert_eq!(Vec2::<Minute>::from_values(0.5, 1.0), a / 2.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_norm_and_dot() {
+        let v: Vec3<Minute> = Vec3::from_values(2.0, 3.0, 6.0);
+
+        assert_eq!(7.0, v.norm().value());
+        assert_eq!(49.0, v.dot(v).value());
+        assert_eq!("49 min²", v.dot(v).to_string());
+        assert_eq!(420.0, v.into_unit::<Second>().norm().value());
+    }
+
+    #[test]
+    fn test_display() {
+        let v: Vec3<Second> = Vec3::from_values(1.0, 2.5, -3.0);
+        assert_eq!("(1 s, 2.5 s, -3 s)", v.to_string());
+    }
+}
@@ -0,0 +1,87 @@
+//! Kani proof harnesses for the conversions between units. They are
+//! only compiled when running `cargo kani`, and verify that:
+//!
+//! - Linear conversions are monotonic.
+//! - Converting into a unit and back stays within a bounded relative
+//!   error from the original value.
+//! - Conversions from and into aliases resolve without looping.
+
+use crate::{units::*, DivUnit, FromUnit, MeasureUnit, Measurement};
+
+/// Maximum absolute value considered by the harnesses, far below the
+/// point where the conversion factors could overflow.
+const MAX_VALUE: f64 = 1e12;
+
+/// Maximum relative error tolerated after a round-trip conversion.
+const MAX_RELATIVE_ERROR: f64 = 1e-9;
+
+fn any_value() -> f64 {
+    let value: f64 = kani::any();
+    kani::assume(value.is_finite() && value.abs() <= MAX_VALUE);
+    value
+}
+
+fn check_monotonic<A: MeasureUnit, B: MeasureUnit>()
+where
+    B::AliasedUnit: FromUnit<A>,
+{
+    let (x, y) = (any_value(), any_value());
+    kani::assume(x <= y);
+
+    let x: Measurement<B> = Measurement::<A>::new(x).into_unit();
+    let y: Measurement<B> = Measurement::<A>::new(y).into_unit();
+    assert!(x.value() <= y.value());
+}
+
+fn check_round_trip<A: MeasureUnit, B: MeasureUnit>()
+where
+    A::AliasedUnit: FromUnit<B>,
+    B::AliasedUnit: FromUnit<A>,
+{
+    let value = any_value();
+    let converted: Measurement<B> = Measurement::<A>::new(value).into_unit();
+    let back: Measurement<A> = converted.into_unit();
+
+    assert!((back.value() - value).abs() <= value.abs() * MAX_RELATIVE_ERROR);
+}
+
+macro_rules! harnesses {
+    ($($name:ident: $a:ty => $b:ty),* $(,)?) => {
+        $(
+            mod $name {
+                use super::*;
+
+                #[kani::proof]
+                fn monotonic() {
+                    check_monotonic::<$a, $b>();
+                }
+
+                #[kani::proof]
+                fn round_trip() {
+                    check_round_trip::<$a, $b>();
+                }
+            }
+        )*
+    };
+}
+
+harnesses! {
+    hour_second: Hour => Second,
+    minute_hour: Minute => Hour,
+    gigabit_byte: Gigabit => Byte,
+    kibibyte_kilobyte: Kibibyte => Kilobyte,
+    kilobit_per_second_megabit_per_hour: DivUnit<Kilobit, Second> => DivUnit<Megabit, Hour>,
+    mbps_kbps: Mbps => Kbps,
+}
+
+/// Alias resolution is a single, non-recursive step, so it must
+/// complete without unwinding any loop.
+#[kani::proof]
+#[kani::unwind(1)]
+fn alias_resolution_terminates() {
+    let value = any_value();
+    let unaliased: Measurement<DivUnit<Megabit, Second>> =
+        Measurement::<Gbps>::new(value).into_unit();
+    let aliased: Measurement<Kbps> = unaliased.into_unit();
+    let _: Measurement<Tbps> = aliased.into_unit();
+}
@@ -0,0 +1,101 @@
+//! JavaScript bindings through wasm-bindgen, enabled by the `wasm`
+//! feature. Sizes are exchanged with JS as numbers of bytes, rates as
+//! bits per second and durations as seconds.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    dynamic::{dyn_unit, AnyMeasurement, DynUnit},
+    humanize::humanize,
+    parse::parse_any,
+    units::{find_unit, Bps, Byte, Second, BINARY_BYTE_UNITS, BYTE_UNITS, RATE_UNITS, TIME_UNITS},
+};
+
+fn humanize_in(
+    value: f64,
+    unit: &'static dyn DynUnit,
+    units: &[&'static dyn DynUnit],
+    precision: usize,
+) -> String {
+    format!(
+        "{:.*}",
+        precision,
+        humanize(AnyMeasurement::new(value, unit), units)
+    )
+}
+
+/// Parses the input and converts it into the given unit, failing if
+/// it is expressed in a unit of another quantity.
+fn parse_into(input: &str, unit: &'static dyn DynUnit) -> Result<f64, JsError> {
+    let measurement = parse_any(input)?;
+    if measurement.unit().quantity() != unit.quantity() {
+        return Err(JsError::new(&format!(
+            "expected a unit of {}, found {}",
+            unit.quantity(),
+            measurement.unit().symbol()
+        )));
+    }
+
+    Ok(unit.value_from_base(measurement.base_value()))
+}
+
+/// Formats the given amount of bytes using the most readable unit.
+#[wasm_bindgen(js_name = humanizeBytes)]
+pub fn humanize_bytes(bytes: f64, precision: usize, binary: bool) -> String {
+    let units = if binary {
+        BINARY_BYTE_UNITS
+    } else {
+        BYTE_UNITS
+    };
+    humanize_in(bytes, dyn_unit::<Byte>(), units, precision)
+}
+
+/// Formats the given rate in bits per second using the most readable unit.
+#[wasm_bindgen(js_name = humanizeRate)]
+pub fn humanize_rate(bps: f64, precision: usize) -> String {
+    humanize_in(bps, dyn_unit::<Bps>(), RATE_UNITS, precision)
+}
+
+/// Formats the given amount of seconds using the most readable unit.
+#[wasm_bindgen(js_name = humanizeDuration)]
+pub fn humanize_duration(seconds: f64, precision: usize) -> String {
+    humanize_in(seconds, dyn_unit::<Second>(), TIME_UNITS, precision)
+}
+
+/// Parses a size such as "1.5 MiB", returning its amount of bytes.
+#[wasm_bindgen(js_name = parseBytes)]
+pub fn parse_bytes(input: &str) -> Result<f64, JsError> {
+    parse_into(input, dyn_unit::<Byte>())
+}
+
+/// Parses a rate such as "300 Mbps", returning its bits per second.
+#[wasm_bindgen(js_name = parseRate)]
+pub fn parse_rate(input: &str) -> Result<f64, JsError> {
+    parse_into(input, dyn_unit::<Bps>())
+}
+
+/// Parses a duration such as "90 min", returning its amount of seconds.
+#[wasm_bindgen(js_name = parseDuration)]
+pub fn parse_duration(input: &str) -> Result<f64, JsError> {
+    parse_into(input, dyn_unit::<Second>())
+}
+
+/// Converts the given value between the units identified by the
+/// given symbols, which must measure the same quantity.
+#[wasm_bindgen]
+pub fn convert(value: f64, from: &str, to: &str) -> Result<f64, JsError> {
+    let find = |symbol: &str| {
+        find_unit(symbol).ok_or_else(|| JsError::new(&format!("unknown unit: {:?}", symbol)))
+    };
+    let (from, to) = (find(from)?, find(to)?);
+
+    if from.quantity() != to.quantity() {
+        return Err(JsError::new(&format!(
+            "cannot convert {} into {}",
+            from.symbol(),
+            to.symbol()
+        )));
+    }
+
+    Ok(to.value_from_base(from.value_to_base(value)))
+}
@@ -0,0 +1,171 @@
+//! Derive macros of strong-units. Use them through the `derive` feature
+//! of strong-units, which re-exports them.
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericArgument, GenericParam,
+    Ident, Index, PathArguments, Type, WherePredicate,
+};
+
+/// Implements `ConvertUnits` for a struct generic over the units of its
+/// measurements, converting them into any other units of the same
+/// quantities, and adds an `into_units::<Target>()` method to it.
+#[proc_macro_derive(ConvertUnits)]
+pub fn derive_convert_units(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_convert_units(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Returns the type parameter U if the given type is `Measurement<U>`.
+fn measurement_unit(ty: &Type) -> Option<&Ident> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Measurement" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(Type::Path(unit)) if args.args.len() == 1 => unit.path.get_ident(),
+        _ => None,
+    }
+}
+
+/// Returns whether the given tokens mention any of the given idents.
+fn mentions(tokens: TokenStream, idents: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&&ident),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        _ => false,
+    })
+}
+
+fn expand_convert_units(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "ConvertUnits can only be derived for structs",
+        ));
+    };
+
+    let type_params: Vec<&Ident> = input.generics.type_params().map(|p| &p.ident).collect();
+
+    // Type parameters used as the unit of a measurement field, which
+    // are the ones that change in the target of the conversion.
+    let mut units: Vec<&Ident> = Vec::new();
+    for field in data.fields.iter() {
+        if let Some(unit) = measurement_unit(&field.ty).filter(|u| type_params.contains(u)) {
+            if !units.contains(&unit) {
+                units.push(unit);
+            }
+        }
+    }
+
+    for field in data.fields.iter() {
+        let field_ty = &field.ty;
+        if measurement_unit(field_ty).is_none() && mentions(quote!(#field_ty), &units) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "the units converted by ConvertUnits can only be used as the unit of `Measurement` fields",
+            ));
+        }
+    }
+
+    let target = |ident: &Ident| -> Ident {
+        if units.contains(&ident) {
+            format_ident!("__Target{}", ident)
+        } else {
+            ident.clone()
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let target_args = input.generics.params.iter().map(|param| match param {
+        GenericParam::Type(param) => {
+            let ident = target(&param.ident);
+            quote!(#ident)
+        }
+        GenericParam::Lifetime(param) => {
+            let lifetime = &param.lifetime;
+            quote!(#lifetime)
+        }
+        GenericParam::Const(param) => {
+            let ident = &param.ident;
+            quote!(#ident)
+        }
+    });
+    let target_type = quote!(#name<#(#target_args),*>);
+
+    // The generics of the conversion are the ones of the struct, plus
+    // the target units.
+    let mut generics = input.generics.clone();
+    for unit in &units {
+        let target = target(unit);
+        generics.params.push(parse_quote!(#target));
+        generics
+            .make_where_clause()
+            .predicates
+            .extend::<[WherePredicate; 3]>([
+                parse_quote!(#unit: ::strong_units::MeasureUnit),
+                parse_quote!(#target: ::strong_units::MeasureUnit),
+                parse_quote!(::strong_units::Canonical<#target>: ::strong_units::FromUnit<#unit>),
+            ]);
+    }
+    let (convert_generics, _, convert_where_clause) = generics.split_for_impl();
+
+    let convert = |ty: &Type, value: TokenStream| {
+        if measurement_unit(ty).is_some_and(|unit| units.contains(&unit)) {
+            quote!(#value.into_unit())
+        } else {
+            value
+        }
+    };
+    let body = match &data.fields {
+        Fields::Named(fields) => {
+            let fields = fields.named.iter().map(|field| {
+                let ident = &field.ident;
+                let value = convert(&field.ty, quote!(self.#ident));
+                quote!(#ident: #value)
+            });
+            quote!(#name { #(#fields),* })
+        }
+        Fields::Unnamed(fields) => {
+            let fields = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                let index = Index::from(index);
+                convert(&field.ty, quote!(self.#index))
+            });
+            quote!(#name(#(#fields),*))
+        }
+        Fields::Unit => quote!(#name),
+    };
+
+    Ok(quote! {
+        impl #convert_generics ::strong_units::convert::ConvertUnits<#target_type>
+            for #name #ty_generics
+        #convert_where_clause
+        {
+            fn convert_units(self) -> #target_type {
+                #body
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Converts every measurement into the units of the given
+            /// type, which is this same struct with other units.
+            pub fn into_units<__Target>(self) -> __Target
+            where
+                Self: ::strong_units::convert::ConvertUnits<__Target>,
+            {
+                ::strong_units::convert::ConvertUnits::convert_units(self)
+            }
+        }
+    })
+}